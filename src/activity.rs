@@ -0,0 +1,294 @@
+//! When issues were created, updated and closed, for the timeline view
+//!
+//! Timestamps are RFC 3339 strings as bd writes them. Days are local days, found
+//! by shifting times by the local UTC offset.
+
+use crate::bd::Issue;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: i64 = 86_400;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActivityKind {
+    Created,
+    Updated,
+    Closed,
+}
+
+impl ActivityKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ActivityKind::Created => "created",
+            ActivityKind::Updated => "updated",
+            ActivityKind::Closed => "closed",
+        }
+    }
+}
+
+/// The most recent thing that happened to an issue, in seconds since the epoch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Activity {
+    pub kind: ActivityKind,
+    pub at: i64,
+}
+
+impl Activity {
+    /// Latest activity of an issue, or None if its timestamps can't be read
+    pub fn latest(issue: &Issue) -> Option<Activity> {
+        let created = parse_timestamp(&issue.created_at);
+        let updated = parse_timestamp(&issue.updated_at);
+        let closed = issue.closed_at.as_deref().and_then(parse_timestamp);
+
+        if issue.status == "closed" {
+            // Closing updates the issue too; only a later edit counts as an update
+            let at = closed.or(updated)?;
+            return match updated.filter(|u| *u > at) {
+                Some(updated) => Some(Activity { kind: ActivityKind::Updated, at: updated }),
+                None => Some(Activity { kind: ActivityKind::Closed, at }),
+            };
+        }
+        match (created, updated) {
+            (Some(created), Some(updated)) if updated > created => Some(Activity { kind: ActivityKind::Updated, at: updated }),
+            (Some(created), _) => Some(Activity { kind: ActivityKind::Created, at: created }),
+            (None, Some(updated)) => Some(Activity { kind: ActivityKind::Updated, at: updated }),
+            (None, None) => None,
+        }
+    }
+
+    /// Day it happened in a time zone `utc_offset` seconds ahead of UTC
+    pub fn day(&self, utc_offset: i64) -> i64 {
+        day_of(self.at, utc_offset)
+    }
+}
+
+/// Seconds since the epoch
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Offset of the local time zone from UTC at `secs`, in seconds (0 if unknown)
+#[cfg(unix)]
+pub fn local_offset(secs: i64) -> i64 {
+    let time = secs as libc::time_t;
+    // SAFETY: localtime_r only writes to `tm`, which is valid for the call
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    if unsafe { libc::localtime_r(&time, &mut tm) }.is_null() {
+        return 0;
+    }
+    tm.tm_gmtoff as i64
+}
+
+/// Offset of the local time zone from UTC at `secs`, in seconds (0 if unknown)
+#[cfg(not(unix))]
+pub fn local_offset(_secs: i64) -> i64 {
+    0
+}
+
+/// Day of a time in a time zone `utc_offset` seconds ahead of UTC, in days since the epoch
+pub fn day_of(secs: i64, utc_offset: i64) -> i64 {
+    (secs + utc_offset).div_euclid(SECS_PER_DAY)
+}
+
+/// Seconds since the epoch of an RFC 3339 timestamp, e.g. `2025-01-06T09:00:00Z`,
+/// `2025-01-06T10:00:00.123+01:00`, or of midnight for a plain `2025-01-06`
+pub fn parse_timestamp(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (date, time) = match text.find(['T', 't', ' ']) {
+        Some(pos) => (&text[..pos], Some(&text[pos + 1..])),
+        None => (text, None),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut secs = days_from_civil(year, month, day) * SECS_PER_DAY;
+
+    if let Some(time) = time {
+        // Split off the offset: Z, +hh:mm or -hh:mm
+        let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+            Some(pos) => (&time[..pos], &time[pos..]),
+            None => (time, ""),
+        };
+        let mut fields = clock.split(':');
+        let hours: i64 = fields.next()?.parse().ok()?;
+        let minutes: i64 = fields.next()?.parse().ok()?;
+        // Fractions of a second are dropped
+        let seconds: i64 = match fields.next() {
+            Some(s) => s.split('.').next()?.parse().ok()?,
+            None => 0,
+        };
+        secs += hours * 3600 + minutes * 60 + seconds;
+
+        let sign = match offset.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => 0,
+        };
+        if sign != 0 {
+            let (h, m) = offset[1..].split_once(':').unwrap_or((&offset[1..], "0"));
+            secs -= sign * (h.parse::<i64>().ok()? * 3600 + m.parse::<i64>().ok()? * 60);
+        }
+    }
+    Some(secs)
+}
+
+/// How long ago `secs` was, e.g. "3h ago"
+pub fn relative_time(secs: i64, now: i64) -> String {
+    let elapsed = now - secs;
+    if elapsed < 60 {
+        return "just now".to_string();
+    }
+    let (amount, unit) = match elapsed {
+        e if e < 3600 => (e / 60, "m"),
+        e if e < SECS_PER_DAY => (e / 3600, "h"),
+        e if e < 30 * SECS_PER_DAY => (e / SECS_PER_DAY, "d"),
+        e if e < 365 * SECS_PER_DAY => (e / (30 * SECS_PER_DAY), "mo"),
+        e => (e / (365 * SECS_PER_DAY), "y"),
+    };
+    format!("{}{} ago", amount, unit)
+}
+
+/// ISO date of a day, e.g. "2026-10-14"
+pub fn date(day: i64) -> String {
+    let (year, month, day) = civil_from_days(day);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Header for a day of the timeline: "Today", "Yesterday", or e.g. "Wed 14 Oct"
+/// (with the year if it isn't this year's)
+pub fn day_label(day: i64, today: i64) -> String {
+    match today - day {
+        0 => return "Today".to_string(),
+        1 => return "Yesterday".to_string(),
+        _ => {}
+    }
+    let (year, month, date) = civil_from_days(day);
+    let weekday = WEEKDAYS[(day + 4).rem_euclid(7) as usize]; // 1970-01-01 was a Thursday
+    let label = format!("{} {} {}", weekday, date, MONTHS[month as usize - 1]);
+    if year == civil_from_days(today).0 {
+        label
+    } else {
+        format!("{} {}", label, year)
+    }
+}
+
+/// Days since the epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of a day since the epoch
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_issue(status: &str, created: &str, updated: &str, closed: Option<&str>) -> Issue {
+        Issue {
+            id: "bsv-a".to_string(),
+            title: "A".to_string(),
+            status: status.to_string(),
+            priority: 2,
+            issue_type: "task".to_string(),
+            created_at: created.to_string(),
+            updated_at: updated.to_string(),
+            closed_at: closed.map(|c| c.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_timestamp("2025-01-06T09:00:00Z"), Some(1_736_154_000));
+        assert_eq!(parse_timestamp("2025-01-06T10:00:00.5+01:00"), Some(1_736_154_000));
+        assert_eq!(parse_timestamp("2025-01-06T04:30:00-04:30"), Some(1_736_154_000));
+        assert_eq!(parse_timestamp("2025-01-06"), Some(1_736_121_600));
+        assert_eq!(parse_timestamp("2024-02-29T00:00:00Z"), Some(1_709_164_800));
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("yesterday"), None);
+        assert_eq!(parse_timestamp("2025-13-01"), None);
+    }
+
+    #[test]
+    fn test_dates_round_trip() {
+        for day in [-1, 0, 59, 11_016, 19_782, 20_742] {
+            assert_eq!(parse_timestamp(&date(day)), Some(day * SECS_PER_DAY));
+        }
+        assert_eq!(date(20_742), "2026-10-16");
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = 1_000_000_000;
+        assert_eq!(relative_time(now - 5, now), "just now");
+        assert_eq!(relative_time(now + 30, now), "just now");
+        assert_eq!(relative_time(now - 5 * 60, now), "5m ago");
+        assert_eq!(relative_time(now - 3 * 3600 - 59, now), "3h ago");
+        assert_eq!(relative_time(now - 2 * SECS_PER_DAY, now), "2d ago");
+        assert_eq!(relative_time(now - 65 * SECS_PER_DAY, now), "2mo ago");
+        assert_eq!(relative_time(now - 800 * SECS_PER_DAY, now), "2y ago");
+    }
+
+    #[test]
+    fn test_day_label() {
+        let today = 20_742; // Fri 16 Oct 2026
+        assert_eq!(day_label(today, today), "Today");
+        assert_eq!(day_label(today - 1, today), "Yesterday");
+        assert_eq!(day_label(today - 2, today), "Wed 14 Oct");
+        assert_eq!(day_label(today - 365, today), "Thu 16 Oct 2025");
+    }
+
+    #[test]
+    fn test_latest_activity() {
+        let created = make_issue("open", "2025-01-06T09:00:00Z", "2025-01-06T09:00:00Z", None);
+        assert_eq!(Activity::latest(&created).map(|a| a.kind), Some(ActivityKind::Created));
+
+        let updated = make_issue("open", "2025-01-06T09:00:00Z", "2025-01-07T09:00:00Z", None);
+        let activity = Activity::latest(&updated).unwrap();
+        assert_eq!(activity.kind, ActivityKind::Updated);
+        assert_eq!(date(activity.day(0)), "2025-01-07");
+        // 09:00 UTC is already the next day east of +15:00
+        assert_eq!(date(activity.day(15 * 3600)), "2025-01-08");
+        assert_eq!(date(activity.day(-10 * 3600)), "2025-01-06");
+
+        // Closing sets updated_at as well
+        let closed = make_issue("closed", "2025-01-06T09:00:00Z", "2025-01-08T09:00:00Z", Some("2025-01-08T09:00:00Z"));
+        assert_eq!(Activity::latest(&closed).map(|a| a.kind), Some(ActivityKind::Closed));
+        let edited = make_issue("closed", "2025-01-06T09:00:00Z", "2025-01-09T09:00:00Z", Some("2025-01-08T09:00:00Z"));
+        assert_eq!(Activity::latest(&edited).map(|a| a.kind), Some(ActivityKind::Updated));
+        // Exports without closed_at fall back to the last update
+        let no_closed_at = make_issue("closed", "2025-01-06T09:00:00Z", "2025-01-08T09:00:00Z", None);
+        assert_eq!(Activity::latest(&no_closed_at).map(|a| a.kind), Some(ActivityKind::Closed));
+
+        assert_eq!(Activity::latest(&make_issue("open", "", "", None)), None);
+    }
+}
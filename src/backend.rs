@@ -0,0 +1,472 @@
+use crate::bd::{Issue, NewIssue, TextField};
+use anyhow::Result;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A source of issue data that also accepts edits.
+///
+/// Everything that reads or writes issues (`App`, tree loading, `--debug`) goes
+/// through this trait, so the `bd` subprocess is just one implementation.
+pub trait IssueBackend: Send + Sync {
+    /// List all issues (including closed) with full details including dependencies
+    fn list_issues_with_details(&self) -> Result<Vec<Issue>>;
+
+    /// IDs of issues that are ready to work on
+    fn get_ready_ids(&self) -> Result<HashSet<String>>;
+
+    /// Full details for a single issue, or None if it doesn't exist
+    fn get_issue_details(&self, id: &str) -> Result<Option<Issue>>;
+
+    /// Update an issue's title, description, notes, design or acceptance criteria
+    fn update_text_field(&self, id: &str, field: TextField, value: &str) -> Result<()>;
+
+    /// Create an issue, returning its new ID
+    fn create_issue(&self, issue: &NewIssue) -> Result<String>;
+
+    /// Close an issue, with an optional reason
+    fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()>;
+
+    /// Set an issue's status (e.g. "open" to reopen, "in_progress")
+    fn update_issue_status(&self, id: &str, status: &str) -> Result<()>;
+
+    /// Set an issue's priority (0-4)
+    fn update_issue_priority(&self, id: &str, priority: i32) -> Result<()>;
+
+    /// Set an issue's type (task, bug, ...)
+    fn update_issue_type(&self, id: &str, issue_type: &str) -> Result<()>;
+
+    /// Add a label to an issue
+    fn add_label(&self, id: &str, label: &str) -> Result<()>;
+
+    /// Remove a label from an issue
+    fn remove_label(&self, id: &str, label: &str) -> Result<()>;
+
+    /// Make `id` depend on `depends_on` ("blocks", "related", "parent-child", ...)
+    fn add_dependency(&self, id: &str, depends_on: &str, dep_type: &str) -> Result<()>;
+
+    /// Remove the dependency of `id` on `depends_on`
+    fn remove_dependency(&self, id: &str, depends_on: &str) -> Result<()>;
+
+    /// Short warning about the backend's health, shown at the bottom of the tree panel
+    fn status_note(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Reads from `primary` until it fails or turns out to be slow, then switches reads
+/// to `fallback` for the rest of the session. Writes always go to `primary`.
+///
+/// Used to keep bsv usable from `.beads/issues.jsonl` when bd is missing or its daemon hangs.
+pub struct FallbackBackend<P, F> {
+    primary: P,
+    fallback: F,
+    using_fallback: AtomicBool,
+}
+
+impl<P: IssueBackend, F: IssueBackend> FallbackBackend<P, F> {
+    pub fn new(primary: P, fallback: F) -> Self {
+        FallbackBackend {
+            primary,
+            fallback,
+            using_fallback: AtomicBool::new(false),
+        }
+    }
+
+    fn read<T>(&self, f: impl Fn(&dyn IssueBackend) -> Result<T>) -> Result<T> {
+        if self.using_fallback.load(Ordering::Relaxed) {
+            return f(&self.fallback);
+        }
+        let result = f(&self.primary);
+        if result.is_err() || self.primary.status_note().is_some() {
+            self.using_fallback.store(true, Ordering::Relaxed);
+        }
+        match result {
+            Ok(value) => Ok(value),
+            Err(_) => f(&self.fallback),
+        }
+    }
+}
+
+impl<P: IssueBackend, F: IssueBackend> IssueBackend for FallbackBackend<P, F> {
+    fn list_issues_with_details(&self) -> Result<Vec<Issue>> {
+        self.read(|b| b.list_issues_with_details())
+    }
+
+    /// Only bd can answer: the fallback would just repeat the local computation it's
+    /// checked against, and a slow `bd ready` shouldn't switch every read to the export
+    fn get_ready_ids(&self) -> Result<HashSet<String>> {
+        self.primary.get_ready_ids()
+    }
+
+    fn get_issue_details(&self, id: &str) -> Result<Option<Issue>> {
+        self.read(|b| b.get_issue_details(id))
+    }
+
+    fn update_text_field(&self, id: &str, field: TextField, value: &str) -> Result<()> {
+        self.primary.update_text_field(id, field, value)
+    }
+
+    fn create_issue(&self, issue: &NewIssue) -> Result<String> {
+        self.primary.create_issue(issue)
+    }
+
+    fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.primary.close_issue(id, reason)
+    }
+
+    fn update_issue_status(&self, id: &str, status: &str) -> Result<()> {
+        self.primary.update_issue_status(id, status)
+    }
+
+    fn update_issue_priority(&self, id: &str, priority: i32) -> Result<()> {
+        self.primary.update_issue_priority(id, priority)
+    }
+
+    fn update_issue_type(&self, id: &str, issue_type: &str) -> Result<()> {
+        self.primary.update_issue_type(id, issue_type)
+    }
+
+    fn add_label(&self, id: &str, label: &str) -> Result<()> {
+        self.primary.add_label(id, label)
+    }
+
+    fn remove_label(&self, id: &str, label: &str) -> Result<()> {
+        self.primary.remove_label(id, label)
+    }
+
+    fn add_dependency(&self, id: &str, depends_on: &str, dep_type: &str) -> Result<()> {
+        self.primary.add_dependency(id, depends_on, dep_type)
+    }
+
+    fn remove_dependency(&self, id: &str, depends_on: &str) -> Result<()> {
+        self.primary.remove_dependency(id, depends_on)
+    }
+
+    fn status_note(&self) -> Option<String> {
+        if self.using_fallback.load(Ordering::Relaxed) {
+            Some("bd unavailable, reading issues.jsonl".to_string())
+        } else {
+            self.primary.status_note()
+        }
+    }
+}
+
+/// In-memory backend for tests: serves a fixed set of issues and records every update
+#[cfg(test)]
+pub struct MemoryBackend {
+    issues: std::sync::Mutex<Vec<Issue>>,
+    ready_ids: HashSet<String>,
+    /// Log of update calls, e.g. "title bsv-a New title"
+    pub updates: std::sync::Mutex<Vec<String>>,
+}
+
+#[cfg(test)]
+impl MemoryBackend {
+    pub fn new(issues: Vec<Issue>, ready_ids: HashSet<String>) -> Self {
+        MemoryBackend {
+            issues: std::sync::Mutex::new(issues),
+            ready_ids,
+            updates: std::sync::Mutex::new(vec![]),
+        }
+    }
+
+    /// Apply a change to a stored issue, failing if it doesn't exist
+    fn modify(&self, id: &str, log: String, f: impl FnOnce(&mut Issue)) -> Result<()> {
+        let mut issues = self.issues.lock().unwrap();
+        let issue = issues.iter_mut()
+            .find(|i| i.id == id)
+            .ok_or_else(|| anyhow::anyhow!("no issue {}", id))?;
+        f(issue);
+        self.updates.lock().unwrap().push(log);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl IssueBackend for MemoryBackend {
+    fn list_issues_with_details(&self) -> Result<Vec<Issue>> {
+        Ok(self.issues.lock().unwrap().clone())
+    }
+
+    fn get_ready_ids(&self) -> Result<HashSet<String>> {
+        Ok(self.ready_ids.clone())
+    }
+
+    fn get_issue_details(&self, id: &str) -> Result<Option<Issue>> {
+        Ok(self.issues.lock().unwrap().iter().find(|i| i.id == id).cloned())
+    }
+
+    /// Logged under the bd flag's name, e.g. "acceptance bsv-a Done when..."
+    fn update_text_field(&self, id: &str, field: TextField, value: &str) -> Result<()> {
+        let name = field.flag().trim_start_matches('-');
+        self.modify(id, format!("{} {} {}", name, id, value), |i| {
+            let value = value.to_string();
+            match field {
+                TextField::Title => i.title = value,
+                TextField::Description => i.description = Some(value),
+                TextField::Notes => i.notes = Some(value),
+                TextField::Design => i.design = Some(value),
+                TextField::AcceptanceCriteria => i.acceptance_criteria = Some(value),
+            }
+        })
+    }
+
+    /// Children get the next dotted ID under their parent, like bd
+    fn create_issue(&self, new: &NewIssue) -> Result<String> {
+        let mut issues = self.issues.lock().unwrap();
+        let id = match &new.parent {
+            Some(parent) => {
+                let prefix = format!("{}.", parent);
+                let last = issues.iter()
+                    .filter_map(|i| i.id.strip_prefix(&prefix)?.parse::<u32>().ok())
+                    .max()
+                    .unwrap_or(0);
+                format!("{}{}", prefix, last + 1)
+            }
+            None => format!("mem-{}", issues.len() + 1),
+        };
+        issues.push(Issue {
+            id: id.clone(),
+            title: new.title.clone(),
+            description: (!new.description.is_empty()).then(|| new.description.clone()),
+            status: "open".to_string(),
+            priority: new.priority,
+            issue_type: new.issue_type.clone(),
+            labels: (!new.labels.is_empty()).then(|| new.labels.clone()),
+            parent: new.parent.clone(),
+            ..Default::default()
+        });
+        self.updates.lock().unwrap().push(format!("create {} {}", id, new.title));
+        Ok(id)
+    }
+
+    fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.modify(id, format!("close {} {}", id, reason.unwrap_or("")).trim_end().to_string(), |i| {
+            i.status = "closed".to_string()
+        })
+    }
+
+    fn update_issue_status(&self, id: &str, status: &str) -> Result<()> {
+        self.modify(id, format!("status {} {}", id, status), |i| i.status = status.to_string())
+    }
+
+    fn update_issue_priority(&self, id: &str, priority: i32) -> Result<()> {
+        self.modify(id, format!("priority {} {}", id, priority), |i| i.priority = priority)
+    }
+
+    fn update_issue_type(&self, id: &str, issue_type: &str) -> Result<()> {
+        self.modify(id, format!("type {} {}", id, issue_type), |i| i.issue_type = issue_type.to_string())
+    }
+
+    fn add_label(&self, id: &str, label: &str) -> Result<()> {
+        self.modify(id, format!("label+ {} {}", id, label), |i| {
+            let labels = i.labels.get_or_insert_with(Vec::new);
+            if !labels.iter().any(|l| l == label) {
+                labels.push(label.to_string());
+            }
+        })
+    }
+
+    fn remove_label(&self, id: &str, label: &str) -> Result<()> {
+        self.modify(id, format!("label- {} {}", id, label), |i| {
+            if let Some(labels) = i.labels.as_mut() {
+                labels.retain(|l| l != label);
+            }
+        })
+    }
+
+    fn add_dependency(&self, id: &str, depends_on: &str, dep_type: &str) -> Result<()> {
+        let title = self.issues.lock().unwrap().iter()
+            .find(|i| i.id == depends_on)
+            .map(|i| i.title.clone())
+            .ok_or_else(|| anyhow::anyhow!("no issue {}", depends_on))?;
+        self.modify(id, format!("dep+ {} {} {}", id, depends_on, dep_type), |i| {
+            i.dependencies.get_or_insert_with(Vec::new).push(crate::bd::Dependency {
+                id: depends_on.to_string(),
+                title,
+                dependency_type: Some(dep_type.to_string()),
+            })
+        })
+    }
+
+    fn remove_dependency(&self, id: &str, depends_on: &str) -> Result<()> {
+        self.modify(id, format!("dep- {} {}", id, depends_on), |i| {
+            if let Some(deps) = i.dependencies.as_mut() {
+                deps.retain(|d| d.id != depends_on);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            issue_type: "task".to_string(),
+            created_at: "2024-01-01".to_string(),
+            updated_at: "2024-01-01".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_memory_backend_serves_issues() {
+        let backend = MemoryBackend::new(
+            vec![make_issue("bsv-a", "A"), make_issue("bsv-b", "B")],
+            HashSet::from(["bsv-a".to_string()]),
+        );
+
+        assert_eq!(backend.list_issues_with_details().unwrap().len(), 2);
+        assert!(backend.get_ready_ids().unwrap().contains("bsv-a"));
+        assert_eq!(backend.get_issue_details("bsv-b").unwrap().unwrap().title, "B");
+        assert!(backend.get_issue_details("bsv-missing").unwrap().is_none());
+    }
+
+    /// Backend whose reads always fail, standing in for a missing bd
+    struct FailingBackend;
+
+    impl IssueBackend for FailingBackend {
+        fn list_issues_with_details(&self) -> Result<Vec<Issue>> {
+            anyhow::bail!("bd not found")
+        }
+        fn get_ready_ids(&self) -> Result<HashSet<String>> {
+            anyhow::bail!("bd not found")
+        }
+        fn get_issue_details(&self, _id: &str) -> Result<Option<Issue>> {
+            anyhow::bail!("bd not found")
+        }
+        fn update_text_field(&self, _id: &str, _field: TextField, _value: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn create_issue(&self, _issue: &NewIssue) -> Result<String> {
+            anyhow::bail!("bd not found")
+        }
+        fn close_issue(&self, _id: &str, _reason: Option<&str>) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn update_issue_status(&self, _id: &str, _status: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn update_issue_priority(&self, _id: &str, _priority: i32) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn update_issue_type(&self, _id: &str, _issue_type: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn add_label(&self, _id: &str, _label: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn remove_label(&self, _id: &str, _label: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn add_dependency(&self, _id: &str, _depends_on: &str, _dep_type: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+        fn remove_dependency(&self, _id: &str, _depends_on: &str) -> Result<()> {
+            anyhow::bail!("bd not found")
+        }
+    }
+
+    #[test]
+    fn test_fallback_backend_switches_on_failure() {
+        let backend = FallbackBackend::new(
+            FailingBackend,
+            MemoryBackend::new(vec![make_issue("bsv-a", "A")], HashSet::new()),
+        );
+        assert!(backend.status_note().is_none());
+
+        // Reads come from the fallback once the primary fails
+        assert_eq!(backend.list_issues_with_details().unwrap().len(), 1);
+        assert!(backend.status_note().unwrap().contains("issues.jsonl"));
+        assert!(backend.get_issue_details("bsv-a").unwrap().is_some());
+
+        // Writes still go to the primary
+        assert!(backend.update_text_field("bsv-a", TextField::Title, "X").is_err());
+    }
+
+    #[test]
+    fn test_fallback_backend_ready_ids_come_from_primary() {
+        let backend = FallbackBackend::new(
+            FailingBackend,
+            MemoryBackend::new(vec![make_issue("bsv-a", "A")], HashSet::from(["bsv-a".to_string()])),
+        );
+
+        // A failing `bd ready` is reported rather than answered from the export
+        assert!(backend.get_ready_ids().is_err());
+        assert!(backend.status_note().is_none());
+    }
+
+    #[test]
+    fn test_fallback_backend_prefers_healthy_primary() {
+        let backend = FallbackBackend::new(
+            MemoryBackend::new(vec![make_issue("bsv-a", "A"), make_issue("bsv-b", "B")], HashSet::new()),
+            MemoryBackend::new(vec![], HashSet::new()),
+        );
+        assert_eq!(backend.list_issues_with_details().unwrap().len(), 2);
+        assert!(backend.status_note().is_none());
+    }
+
+    #[test]
+    fn test_memory_backend_records_updates() {
+        let backend = MemoryBackend::new(vec![make_issue("bsv-a", "A")], HashSet::new());
+
+        backend.update_text_field("bsv-a", TextField::Title, "Renamed").unwrap();
+        backend.update_text_field("bsv-a", TextField::Description, "Body").unwrap();
+
+        let issue = backend.get_issue_details("bsv-a").unwrap().unwrap();
+        assert_eq!(issue.title, "Renamed");
+        assert_eq!(issue.description.as_deref(), Some("Body"));
+        assert_eq!(
+            *backend.updates.lock().unwrap(),
+            vec!["title bsv-a Renamed".to_string(), "description bsv-a Body".to_string()]
+        );
+
+        // Updating an unknown issue is an error, like bd
+        assert!(backend.update_text_field("bsv-missing", TextField::Title, "X").is_err());
+
+        backend.close_issue("bsv-a", Some("Done")).unwrap();
+        assert_eq!(backend.get_issue_details("bsv-a").unwrap().unwrap().status, "closed");
+        backend.update_issue_status("bsv-a", "in_progress").unwrap();
+        assert_eq!(backend.get_issue_details("bsv-a").unwrap().unwrap().status, "in_progress");
+        assert_eq!(
+            backend.updates.lock().unwrap()[2..],
+            ["close bsv-a Done".to_string(), "status bsv-a in_progress".to_string()]
+        );
+
+        backend.update_text_field("bsv-a", TextField::AcceptanceCriteria, "Tests pass").unwrap();
+        backend.update_text_field("bsv-a", TextField::Notes, "Asked upstream").unwrap();
+        let issue = backend.get_issue_details("bsv-a").unwrap().unwrap();
+        assert_eq!(issue.acceptance_criteria.as_deref(), Some("Tests pass"));
+        assert_eq!(issue.notes.as_deref(), Some("Asked upstream"));
+        assert_eq!(backend.updates.lock().unwrap()[4], "acceptance bsv-a Tests pass");
+    }
+
+    #[test]
+    fn test_memory_backend_creates_dotted_children() {
+        let backend = MemoryBackend::new(
+            vec![make_issue("bsv-a", "A"), make_issue("bsv-a.2", "A2")],
+            HashSet::new(),
+        );
+        let new = NewIssue {
+            title: "Child".to_string(),
+            issue_type: "task".to_string(),
+            priority: 1,
+            description: String::new(),
+            labels: vec!["ui".to_string()],
+            parent: Some("bsv-a".to_string()),
+        };
+
+        assert_eq!(backend.create_issue(&new).unwrap(), "bsv-a.3");
+        let issue = backend.get_issue_details("bsv-a.3").unwrap().unwrap();
+        assert_eq!(issue.title, "Child");
+        assert_eq!(issue.priority, 1);
+        assert_eq!(issue.labels, Some(vec!["ui".to_string()]));
+        assert_eq!(backend.updates.lock().unwrap().last().unwrap(), "create bsv-a.3 Child");
+    }
+}
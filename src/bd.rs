@@ -1,6 +1,9 @@
+use crate::backend::IssueBackend;
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use std::process::Command;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
@@ -15,23 +18,7 @@ pub fn is_daemon_slow() -> bool {
     DAEMON_SLOW.load(Ordering::Relaxed)
 }
 
-/// Run a bd command and track if it's slow
-fn run_bd_command(args: &[&str]) -> Result<std::process::Output> {
-    let start = Instant::now();
-    let output = Command::new("bd")
-        .args(args)
-        .output()
-        .with_context(|| format!("Failed to run bd {}", args.first().unwrap_or(&"")))?;
-
-    let elapsed = start.elapsed();
-    if elapsed > Duration::from_secs(SLOW_THRESHOLD_SECS) {
-        DAEMON_SLOW.store(true, Ordering::Relaxed);
-    }
-
-    Ok(output)
-}
-
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[allow(dead_code)]
 pub struct Issue {
     pub id: String,
@@ -46,6 +33,8 @@ pub struct Issue {
     pub created_by: Option<String>,
     pub updated_at: String,
     #[serde(default)]
+    pub closed_at: Option<String>,
+    #[serde(default)]
     pub labels: Option<Vec<String>>,
     #[serde(default)]
     pub parent: Option<String>,
@@ -59,6 +48,8 @@ pub struct Issue {
     pub design: Option<String>,
     #[serde(default)]
     pub acceptance_criteria: Option<String>,
+    #[serde(default)]
+    pub assignee: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -69,110 +60,347 @@ pub struct Dependency {
     pub dependency_type: Option<String>,
 }
 
-#[allow(dead_code)]
-pub fn list_issues() -> Result<Vec<Issue>> {
-    // Use --status=all to include closed issues, --limit=0 for unlimited
-    let output = run_bd_command(&["list", "--status=all", "--json", "--limit", "0"])?;
+/// Fields for creating an issue with `bd create`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewIssue {
+    pub title: String,
+    pub issue_type: String,
+    pub priority: i32,
+    pub description: String,
+    pub labels: Vec<String>,
+    /// Create as a child of this issue (bd assigns the next dotted ID, e.g. `bsv-abc.3`)
+    pub parent: Option<String>,
+}
+
+/// Text fields of an issue, each set by its own `bd update` flag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextField {
+    Title,
+    Description,
+    Notes,
+    Design,
+    AcceptanceCriteria,
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("bd list failed: {}", stderr);
+impl TextField {
+    /// The `bd update` flag that sets the field
+    pub fn flag(self) -> &'static str {
+        match self {
+            TextField::Title => "--title",
+            TextField::Description => "--description",
+            TextField::Notes => "--notes",
+            TextField::Design => "--design",
+            TextField::AcceptanceCriteria => "--acceptance",
+        }
     }
+}
+
+/// Environment variable overriding the bd executable
+const BD_ENV: &str = "BSV_BD";
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let issues: Vec<Issue> = serde_json::from_str(&stdout)
-        .context("Failed to parse bd list output")?;
+/// How long `bd info` gets at startup before we go on without it
+const INFO_TIMEOUT: Duration = Duration::from_secs(SLOW_THRESHOLD_SECS);
 
-    Ok(issues)
+/// Issue backend that shells out to the `bd` CLI
+#[derive(Debug, Clone)]
+pub struct BdCli {
+    /// The bd executable to run
+    program: String,
+    /// Working directory for bd commands (None = our own cwd)
+    dir: Option<PathBuf>,
 }
 
-pub fn get_ready_ids() -> Result<std::collections::HashSet<String>> {
-    let output = run_bd_command(&["ready", "--json", "--limit", "0"])?;
+impl BdCli {
+    /// `program` defaults to `$BSV_BD`, then `bd` on the PATH
+    pub fn new(program: Option<String>, dir: Option<PathBuf>) -> Self {
+        let program = program
+            .or_else(|| std::env::var(BD_ENV).ok())
+            .unwrap_or_else(|| "bd".to_string());
+        BdCli { program, dir }
+    }
+
+    /// Path of the beads database bd is using (from `bd info --json`), or None if
+    /// bd fails or doesn't answer within `INFO_TIMEOUT`
+    pub fn database_path(&self) -> Option<String> {
+        let mut command = Command::new(&self.program);
+        command.args(["info", "--json"]).stdout(Stdio::piped()).stderr(Stdio::null());
+        if let Some(dir) = &self.dir {
+            command.current_dir(dir);
+        }
+        let mut child = command.spawn().ok()?;
+        let deadline = Instant::now() + INFO_TIMEOUT;
+        while child.try_wait().ok()?.is_none() {
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                DAEMON_SLOW.store(true, Ordering::Relaxed);
+                return None;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        let output = child.wait_with_output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let info: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+        info.get("database_path")?.as_str().map(|s| s.to_string())
+    }
 
-    if !output.status.success() {
-        // If bd ready fails, return empty set (treat all as not ready)
-        return Ok(std::collections::HashSet::new());
+    /// Run a bd command and track if it's slow
+    fn run(&self, args: &[&str]) -> Result<std::process::Output> {
+        let start = Instant::now();
+        let mut command = Command::new(&self.program);
+        command.args(args);
+        if let Some(dir) = &self.dir {
+            command.current_dir(dir);
+        }
+        let output = command
+            .output()
+            .with_context(|| format!("Failed to run bd {}", args.first().unwrap_or(&"")))?;
+
+        let elapsed = start.elapsed();
+        if elapsed > Duration::from_secs(SLOW_THRESHOLD_SECS) {
+            DAEMON_SLOW.store(true, Ordering::Relaxed);
+        }
+
+        Ok(output)
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let issues: Vec<Issue> = serde_json::from_str(&stdout).unwrap_or_default();
+    #[allow(dead_code)]
+    pub fn list_issues(&self) -> Result<Vec<Issue>> {
+        // Use --status=all to include closed issues, --limit=0 for unlimited
+        let output = self.run(&["list", "--status=all", "--json", "--limit", "0"])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd list failed: {}", stderr);
+        }
 
-    Ok(issues.into_iter().map(|i| i.id).collect())
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let issues: Vec<Issue> = serde_json::from_str(&stdout)
+            .context("Failed to parse bd list output")?;
+
+        Ok(issues)
+    }
 }
 
-pub fn get_issue_details(id: &str) -> Result<Option<Issue>> {
-    let output = run_bd_command(&["show", id, "--json"])?;
+impl IssueBackend for BdCli {
+    fn get_ready_ids(&self) -> Result<HashSet<String>> {
+        let output = self.run(&["ready", "--json", "--limit", "0"])?;
 
-    if !output.status.success() {
-        return Ok(None);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd ready failed: {}", stderr);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let issues: Vec<Issue> = serde_json::from_str(&stdout)
+            .context("Failed to parse bd ready output")?;
+
+        Ok(issues.into_iter().map(|i| i.id).collect())
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let issues: Vec<Issue> = serde_json::from_str(&stdout).unwrap_or_default();
+    fn get_issue_details(&self, id: &str) -> Result<Option<Issue>> {
+        let output = self.run(&["show", id, "--json"])?;
 
-    Ok(issues.into_iter().next())
-}
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let issues: Vec<Issue> = serde_json::from_str(&stdout).unwrap_or_default();
+
+        Ok(issues.into_iter().next())
+    }
+
+    /// List all issues with full details including dependencies.
+    /// This calls `bd show` with all issue IDs to get complete data.
+    fn list_issues_with_details(&self) -> Result<Vec<Issue>> {
+        // First get the list of issue IDs (unlimited)
+        let list_output = self.run(&["list", "--status=all", "--json", "--limit", "0"])?;
 
-/// List all issues with full details including dependencies.
-/// This calls `bd show` with all issue IDs to get complete data.
-pub fn list_issues_with_details() -> Result<Vec<Issue>> {
-    // First get the list of issue IDs (unlimited)
-    let list_output = run_bd_command(&["list", "--status=all", "--json", "--limit", "0"])?;
+        if !list_output.status.success() {
+            let stderr = String::from_utf8_lossy(&list_output.stderr);
+            anyhow::bail!("bd list failed: {}", stderr);
+        }
 
-    if !list_output.status.success() {
-        let stderr = String::from_utf8_lossy(&list_output.stderr);
-        anyhow::bail!("bd list failed: {}", stderr);
+        let stdout = String::from_utf8_lossy(&list_output.stdout);
+        let basic_issues: Vec<Issue> = serde_json::from_str(&stdout)
+            .context("Failed to parse bd list output")?;
+
+        if basic_issues.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Get all issue IDs
+        let ids: Vec<String> = basic_issues.iter().map(|i| i.id.clone()).collect();
+
+        // Call bd show with all IDs to get full details including dependencies
+        let mut args: Vec<&str> = vec!["show", "--json"];
+        args.extend(ids.iter().map(|s| s.as_str()));
+
+        let show_output = self.run(&args)?;
+
+        if !show_output.status.success() {
+            // Fall back to basic list if show fails
+            return Ok(basic_issues);
+        }
+
+        let show_stdout = String::from_utf8_lossy(&show_output.stdout);
+        let detailed_issues: Vec<Issue> = serde_json::from_str(&show_stdout)
+            .unwrap_or(basic_issues);
+
+        Ok(detailed_issues)
     }
 
-    let stdout = String::from_utf8_lossy(&list_output.stdout);
-    let basic_issues: Vec<Issue> = serde_json::from_str(&stdout)
-        .context("Failed to parse bd list output")?;
+    /// Update one of an issue's text fields
+    fn update_text_field(&self, id: &str, field: TextField, value: &str) -> Result<()> {
+        let output = self.run(&["update", id, field.flag(), value])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd update failed: {}", stderr);
+        }
 
-    if basic_issues.is_empty() {
-        return Ok(vec![]);
+        Ok(())
     }
 
-    // Get all issue IDs
-    let ids: Vec<String> = basic_issues.iter().map(|i| i.id.clone()).collect();
+    /// Close an issue with `bd close`
+    fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        let mut args = vec!["close", id];
+        if let Some(reason) = reason {
+            args.extend(["--reason", reason]);
+        }
+        let output = self.run(&args)?;
 
-    // Call bd show with all IDs to get full details including dependencies
-    let mut args: Vec<&str> = vec!["show", "--json"];
-    args.extend(ids.iter().map(|s| s.as_str()));
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd close failed: {}", stderr);
+        }
 
-    let show_output = run_bd_command(&args)?;
+        Ok(())
+    }
+
+    /// Update an issue's status
+    fn update_issue_status(&self, id: &str, status: &str) -> Result<()> {
+        let output = self.run(&["update", id, "--status", status])?;
 
-    if !show_output.status.success() {
-        // Fall back to basic list if show fails
-        return Ok(basic_issues);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd update failed: {}", stderr);
+        }
+
+        Ok(())
     }
 
-    let show_stdout = String::from_utf8_lossy(&show_output.stdout);
-    let detailed_issues: Vec<Issue> = serde_json::from_str(&show_stdout)
-        .unwrap_or(basic_issues);
+    /// Update an issue's priority
+    fn update_issue_priority(&self, id: &str, priority: i32) -> Result<()> {
+        let output = self.run(&["update", id, "--priority", &priority.to_string()])?;
 
-    Ok(detailed_issues)
-}
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd update failed: {}", stderr);
+        }
 
-/// Update an issue's title
-pub fn update_issue_title(id: &str, title: &str) -> Result<()> {
-    let output = run_bd_command(&["update", id, "--title", title])?;
+        Ok(())
+    }
+
+    /// Update an issue's type
+    fn update_issue_type(&self, id: &str, issue_type: &str) -> Result<()> {
+        let output = self.run(&["update", id, "--type", issue_type])?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("bd update failed: {}", stderr);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd update failed: {}", stderr);
+        }
+
+        Ok(())
     }
 
-    Ok(())
-}
+    /// Add a label with `bd label add`
+    fn add_label(&self, id: &str, label: &str) -> Result<()> {
+        let output = self.run(&["label", "add", id, label])?;
 
-/// Update an issue's description
-pub fn update_issue_description(id: &str, description: &str) -> Result<()> {
-    let output = run_bd_command(&["update", id, "--description", description])?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd label add failed: {}", stderr);
+        }
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("bd update failed: {}", stderr);
+        Ok(())
     }
 
-    Ok(())
+    /// Remove a label with `bd label remove`
+    fn remove_label(&self, id: &str, label: &str) -> Result<()> {
+        let output = self.run(&["label", "remove", id, label])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd label remove failed: {}", stderr);
+        }
+
+        Ok(())
+    }
+
+    /// Add a dependency with `bd dep add`
+    fn add_dependency(&self, id: &str, depends_on: &str, dep_type: &str) -> Result<()> {
+        let output = self.run(&["dep", "add", id, depends_on, "--type", dep_type])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd dep add failed: {}", stderr);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a dependency with `bd dep remove`
+    fn remove_dependency(&self, id: &str, depends_on: &str) -> Result<()> {
+        let output = self.run(&["dep", "remove", id, depends_on])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd dep remove failed: {}", stderr);
+        }
+
+        Ok(())
+    }
+
+    /// Create an issue and return the ID bd assigned to it
+    fn create_issue(&self, issue: &NewIssue) -> Result<String> {
+        let priority = issue.priority.to_string();
+        let labels = issue.labels.join(",");
+        // As a flag, so titles starting with a dash aren't read as options
+        let mut args = vec![
+            "create", "--title", issue.title.as_str(),
+            "--type", issue.issue_type.as_str(),
+            "--priority", priority.as_str(),
+        ];
+        if !issue.description.is_empty() {
+            args.extend(["--description", issue.description.as_str()]);
+        }
+        if !labels.is_empty() {
+            args.extend(["--labels", labels.as_str()]);
+        }
+        if let Some(parent) = &issue.parent {
+            args.extend(["--parent", parent.as_str()]);
+        }
+        args.push("--json");
+
+        let output = self.run(&args)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd create failed: {}", stderr);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let created: Issue = serde_json::from_str(&stdout)
+            .context("Failed to parse bd create output")?;
+
+        Ok(created.id)
+    }
+
+    fn status_note(&self) -> Option<String> {
+        is_daemon_slow().then(|| "bd daemon slow!".to_string())
+    }
 }
@@ -0,0 +1,227 @@
+//! Filter expressions for the tree view
+//!
+//! A filter is a list of space-separated terms, all of which must match:
+//!
+//! - `status:open`, `type:bug`, `label:backend`: comma-separated values match any (`status:open,in_progress`)
+//! - `priority:1`, `priority:<=1` (also `<`, `>`, `>=`; `P1` works too)
+//! - `id:bsv-abc`: ID prefix, so an epic's children match too
+//! - `is:ready`, `is:blocked`: the tree's computed ready state
+//! - `@name`: the terms of a saved filter
+//! - a bare word: case-insensitive substring of the ID or title
+//!
+//! Prefix a term with `-` to negate it, e.g. `-label:wontfix`.
+
+use crate::bd::Issue;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Compare {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Test {
+    Status(Vec<String>),
+    Type(Vec<String>),
+    Label(Vec<String>),
+    Priority(Compare, i32),
+    IdPrefix(String),
+    Ready(bool),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    negated: bool,
+    test: Test,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    /// The expression as typed, shown in the tree panel title
+    pub source: String,
+    terms: Vec<Term>,
+}
+
+impl Filter {
+    /// Parse an expression, expanding `@name` references from `saved`
+    pub fn parse(source: &str, saved: &BTreeMap<String, String>) -> Result<Filter, String> {
+        let mut terms = Vec::new();
+        for word in source.split_whitespace() {
+            if let Some(name) = word.strip_prefix('@') {
+                let expr = saved.get(name).ok_or_else(|| format!("No saved filter @{}", name))?;
+                // Saved filters can't refer to each other, so there's no recursion to worry about
+                terms.extend(Filter::parse(expr, &BTreeMap::new())?.terms);
+            } else {
+                terms.push(parse_term(word)?);
+            }
+        }
+        Ok(Filter { source: source.trim().to_string(), terms })
+    }
+
+    /// Does the filter ask for particular statuses? Such filters can match closed
+    /// issues, so they override hiding closed issues.
+    pub fn selects_status(&self) -> bool {
+        self.terms.iter().any(|t| matches!(t.test, Test::Status(_)) && !t.negated)
+    }
+
+    pub fn matches(&self, issue: &Issue, is_ready: bool) -> bool {
+        self.terms.iter().all(|term| term.test.matches(issue, is_ready) != term.negated)
+    }
+}
+
+fn parse_term(word: &str) -> Result<Term, String> {
+    let (negated, word) = match word.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, word),
+    };
+
+    let Some((key, value)) = word.split_once(':') else {
+        return Ok(Term { negated, test: Test::Text(word.to_lowercase()) });
+    };
+    if value.is_empty() {
+        return Err(format!("Missing value for {}:", key));
+    }
+    let values = || value.split(',').map(|v| v.to_lowercase()).collect::<Vec<_>>();
+
+    let test = match key.to_lowercase().as_str() {
+        "status" => Test::Status(values()),
+        "type" => Test::Type(values()),
+        "label" => Test::Label(values()),
+        "id" => Test::IdPrefix(value.to_lowercase()),
+        "priority" => parse_priority(value)?,
+        "is" => match value.to_lowercase().as_str() {
+            "ready" => Test::Ready(true),
+            "blocked" => Test::Ready(false),
+            _ => return Err(format!("Unknown is:{} (use is:ready or is:blocked)", value)),
+        },
+        _ => {
+            return Err(format!(
+                "Unknown filter field '{}' (use status, priority, type, label, id, is)",
+                key
+            ))
+        }
+    };
+    Ok(Term { negated, test })
+}
+
+fn parse_priority(value: &str) -> Result<Test, String> {
+    let (compare, number) = if let Some(rest) = value.strip_prefix("<=") {
+        (Compare::Le, rest)
+    } else if let Some(rest) = value.strip_prefix(">=") {
+        (Compare::Ge, rest)
+    } else if let Some(rest) = value.strip_prefix('<') {
+        (Compare::Lt, rest)
+    } else if let Some(rest) = value.strip_prefix('>') {
+        (Compare::Gt, rest)
+    } else {
+        (Compare::Eq, value.strip_prefix('=').unwrap_or(value))
+    };
+    let number = number.strip_prefix(['P', 'p']).unwrap_or(number);
+    number.parse::<i32>()
+        .map(|p| Test::Priority(compare, p))
+        .map_err(|_| format!("Invalid priority '{}'", value))
+}
+
+impl Test {
+    fn matches(&self, issue: &Issue, is_ready: bool) -> bool {
+        match self {
+            Test::Status(values) => values.iter().any(|v| issue.status.eq_ignore_ascii_case(v)),
+            Test::Type(values) => values.iter().any(|v| issue.issue_type.eq_ignore_ascii_case(v)),
+            Test::Label(values) => issue.labels.iter().flatten()
+                .any(|label| values.iter().any(|v| label.eq_ignore_ascii_case(v))),
+            Test::Priority(compare, p) => match compare {
+                Compare::Eq => issue.priority == *p,
+                Compare::Lt => issue.priority < *p,
+                Compare::Le => issue.priority <= *p,
+                Compare::Gt => issue.priority > *p,
+                Compare::Ge => issue.priority >= *p,
+            },
+            Test::IdPrefix(prefix) => issue.id.to_lowercase().starts_with(prefix),
+            Test::Ready(ready) => is_ready == *ready && issue.status != "closed",
+            Test::Text(text) => {
+                issue.id.to_lowercase().contains(text) || issue.title.to_lowercase().contains(text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(status: &str, priority: i32, issue_type: &str, labels: &[&str]) -> Issue {
+        Issue {
+            id: "bsv-a.1".to_string(),
+            title: "Parser rewrite".to_string(),
+            status: status.to_string(),
+            priority,
+            issue_type: issue_type.to_string(),
+            labels: Some(labels.iter().map(|l| l.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    fn parse(source: &str) -> Filter {
+        Filter::parse(source, &BTreeMap::new()).unwrap()
+    }
+
+    #[test]
+    fn test_terms_are_anded() {
+        let bug = issue("open", 1, "bug", &["backend"]);
+        let f = parse("status:open priority:<=1 label:backend type:bug -label:wontfix");
+        assert!(f.matches(&bug, true));
+
+        let wontfix = issue("open", 1, "bug", &["backend", "wontfix"]);
+        assert!(!f.matches(&wontfix, true));
+        assert!(!f.matches(&issue("open", 2, "bug", &["backend"]), true));
+        assert!(!f.matches(&issue("closed", 1, "bug", &["backend"]), true));
+    }
+
+    #[test]
+    fn test_values_and_comparisons() {
+        let task = issue("in_progress", 2, "task", &[]);
+        assert!(parse("status:open,in_progress").matches(&task, false));
+        assert!(parse("priority:P2").matches(&task, false));
+        assert!(parse("priority:>1 priority:<3").matches(&task, false));
+        assert!(!parse("priority:>=3").matches(&task, false));
+        assert!(parse("id:BSV-A").matches(&task, false));
+        assert!(!parse("id:bsv-b").matches(&task, false));
+        assert!(parse("parser").matches(&task, false));
+        assert!(parse("-docs").matches(&task, false));
+        assert!(parse("is:blocked").matches(&task, false));
+        assert!(!parse("is:ready").matches(&task, false));
+        assert!(parse("").matches(&task, false));
+    }
+
+    #[test]
+    fn test_saved_filter_references() {
+        let saved = BTreeMap::from([("hot".to_string(), "priority:<=1 -status:closed".to_string())]);
+        let f = Filter::parse("@hot type:bug", &saved).unwrap();
+        assert_eq!(f.source, "@hot type:bug");
+        assert!(f.matches(&issue("open", 0, "bug", &[]), true));
+        assert!(!f.matches(&issue("open", 3, "bug", &[]), true));
+
+        assert_eq!(Filter::parse("@cold", &saved).unwrap_err(), "No saved filter @cold");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let saved = BTreeMap::new();
+        assert!(Filter::parse("color:red", &saved).unwrap_err().contains("Unknown filter field 'color'"));
+        assert_eq!(Filter::parse("priority:high", &saved).unwrap_err(), "Invalid priority 'high'");
+        assert_eq!(Filter::parse("label:", &saved).unwrap_err(), "Missing value for label:");
+        assert!(Filter::parse("is:done", &saved).is_err());
+    }
+
+    #[test]
+    fn test_selects_status() {
+        assert!(parse("status:closed").selects_status());
+        assert!(!parse("-status:closed").selects_status());
+        assert!(!parse("label:x").selects_status());
+    }
+}
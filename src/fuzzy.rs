@@ -0,0 +1,76 @@
+//! Fuzzy matching for the issue pickers
+
+/// Score how well `query` matches `candidate`, or None if it doesn't.
+///
+/// Every query character must appear in the candidate in order (ignoring case).
+/// Consecutive characters and matches at the start of a word score higher, and
+/// shorter candidates win ties. An empty query matches everything with score 0.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut last_match: Option<usize> = None;
+    for (i, c) in chars.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if *c != query[next] {
+            continue;
+        }
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == i) {
+            score += 5;
+        }
+        if i == 0 || !chars[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last_match = Some(i);
+        next += 1;
+    }
+
+    (next == query.len()).then(|| score * 100 - chars.len() as i64)
+}
+
+/// Indices of the `candidates` matching `query`, best match first
+pub fn rank<S: AsRef<str>>(query: &str, candidates: &[S]) -> Vec<usize> {
+    let mut scored: Vec<(usize, i64)> = candidates.iter()
+        .enumerate()
+        .filter_map(|(i, c)| score(query, c.as_ref()).map(|s| (i, s)))
+        .collect();
+    // Stable sort keeps the candidates' own order for equal scores
+    scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_requires_characters_in_order() {
+        assert!(score("rnk", "Rank results").is_some());
+        assert!(score("RANK", "rank results").is_some());
+        assert!(score("knr", "Rank results").is_none());
+        assert_eq!(score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_rank_prefers_consecutive_and_word_start_matches() {
+        let candidates = [
+            "bsv-ui.3 Theme task 03",
+            "bsv-search.2 Rank results",
+            "bsv-search.1 Tokenize queries",
+        ];
+        assert_eq!(rank("rank", &candidates), vec![1]);
+        // Equally good matches: the shorter candidate comes first
+        assert_eq!(rank("search", &candidates), vec![1, 2]);
+        // A match at a word start beats one inside a word
+        assert!(score("q", "queries") > score("q", "unique"));
+        assert_eq!(rank("zzz", &candidates), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,567 @@
+//! Layered layout of the dependency graph around one issue, for the graph view
+
+use crate::tree::IssueTree;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Width of an issue's box, borders included
+pub const NODE_WIDTH: usize = 28;
+/// Columns between neighbouring boxes in a layer
+const NODE_GAP: usize = 2;
+/// Rows per layer: a three-row box and three rows of edges below it
+const LAYER_HEIGHT: usize = 6;
+/// Barycenter passes (down and up) used to untangle edges
+const ORDERING_PASSES: usize = 4;
+
+pub const DEFAULT_RADIUS: usize = 2;
+pub const MAX_RADIUS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Slot {
+    Issue(String),
+    /// An edge passing through this layer on its way to a later one
+    Pass,
+}
+
+/// The issues within `radius` dependency hops of `center`, in layers: every
+/// blocker sits in an earlier layer than the issues it blocks.
+#[derive(Debug, Clone)]
+pub struct DepGraph {
+    pub center: String,
+    pub radius: usize,
+    pub layers: Vec<Vec<Slot>>,
+    /// Edges between consecutive layers: (layer, slot, slot in the next layer)
+    pub links: Vec<(usize, usize, usize)>,
+    /// Dependencies left out because they close a cycle
+    pub hidden_edges: usize,
+}
+
+/// The graph drawn with box-drawing characters
+#[derive(Debug)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pub rows: Vec<Vec<char>>,
+    /// Top-left corner of each issue's box
+    pub boxes: Vec<(String, usize, usize)>,
+}
+
+impl Canvas {
+    /// Centre of `id`'s box
+    pub fn center_of(&self, id: &str) -> Option<(usize, usize)> {
+        self.boxes.iter()
+            .find(|(box_id, _, _)| box_id == id)
+            .map(|(_, x, y)| (x + NODE_WIDTH / 2, y + 1))
+    }
+}
+
+// Line directions out of a canvas cell, merged into one box-drawing character
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+fn line_char(bits: u8) -> char {
+    match bits {
+        b if b == UP | DOWN | LEFT | RIGHT => '┼',
+        b if b == UP | DOWN | RIGHT => '├',
+        b if b == UP | DOWN | LEFT => '┤',
+        b if b == DOWN | LEFT | RIGHT => '┬',
+        b if b == UP | LEFT | RIGHT => '┴',
+        b if b == DOWN | RIGHT => '┌',
+        b if b == DOWN | LEFT => '┐',
+        b if b == UP | RIGHT => '└',
+        b if b == UP | LEFT => '┘',
+        b if b & (UP | DOWN) != 0 => '│',
+        _ => '─',
+    }
+}
+
+impl DepGraph {
+    /// Lay out the issues within `radius` hops of `center`, following dependencies
+    /// in both directions. `related` links are left out, as in the Deps view, and
+    /// so are closed issues while they're hidden (except `center` itself).
+    pub fn around(tree: &IssueTree, center: &str, radius: usize) -> Self {
+        // Issues in reach, nearest first
+        let mut order = vec![center];
+        let mut distance = HashMap::from([(center, 0)]);
+        let mut pending = VecDeque::from([center]);
+        while let Some(id) = pending.pop_front() {
+            if distance[id] >= radius {
+                continue;
+            }
+            let Some(node) = tree.nodes.get(id) else { continue };
+            let neighbours = tree.dep_blockers(id).into_iter()
+                .chain(node.dep_children.iter().map(|c| c.as_str()));
+            for next in neighbours {
+                let hidden = !tree.show_closed && tree.nodes[next].issue.status == "closed";
+                if !hidden && !distance.contains_key(next) {
+                    distance.insert(next, distance[id] + 1);
+                    order.push(next);
+                    pending.push_back(next);
+                }
+            }
+        }
+
+        // Blocker → dependent edges between issues in reach
+        let mut seen = HashSet::new();
+        let mut edges: Vec<(&str, &str)> = vec![];
+        for &id in &order {
+            for blocker in tree.dep_blockers(id) {
+                if distance.contains_key(blocker) && seen.insert((blocker, id)) {
+                    edges.push((blocker, id));
+                }
+            }
+        }
+
+        // Drop the edges that close a cycle, then place each issue one layer
+        // below its lowest blocker (longest path from the sources)
+        let (topo, back_edges) = topological_order(&order, &edges);
+        let kept: Vec<(&str, &str)> = edges.iter()
+            .filter(|e| !back_edges.contains(*e))
+            .copied()
+            .collect();
+        let mut layer_of: HashMap<&str, usize> = HashMap::new();
+        for &id in &topo {
+            let layer = kept.iter()
+                .filter(|(_, to)| *to == id)
+                .map(|(from, _)| layer_of[from] + 1)
+                .max()
+                .unwrap_or(0);
+            layer_of.insert(id, layer);
+        }
+
+        let layer_count = layer_of.values().max().map_or(1, |max| max + 1);
+        let mut layers: Vec<Vec<Slot>> = vec![vec![]; layer_count];
+        let mut slot_of: HashMap<&str, usize> = HashMap::new();
+        for &id in &order {
+            let layer = &mut layers[layer_of[id]];
+            slot_of.insert(id, layer.len());
+            layer.push(Slot::Issue(id.to_string()));
+        }
+
+        // Edges spanning several layers pass through a slot in each layer between
+        let mut links = vec![];
+        for (from, to) in kept {
+            let (mut layer, mut slot) = (layer_of[from], slot_of[from]);
+            while layer + 1 < layer_of[to] {
+                let pass = layers[layer + 1].len();
+                layers[layer + 1].push(Slot::Pass);
+                links.push((layer, slot, pass));
+                layer += 1;
+                slot = pass;
+            }
+            links.push((layer, slot, slot_of[to]));
+        }
+
+        let mut graph = DepGraph {
+            center: center.to_string(),
+            radius,
+            layers,
+            links,
+            hidden_edges: back_edges.len(),
+        };
+        graph.order_layers();
+        graph
+    }
+
+    /// Reduce edge crossings by repeatedly sorting each layer by the mean position
+    /// of its neighbours in the layer above, then in the layer below
+    fn order_layers(&mut self) {
+        for _ in 0..ORDERING_PASSES {
+            for layer in 1..self.layers.len() {
+                let keys = self.barycenters(layer, true);
+                self.reorder(layer, &keys);
+            }
+            for layer in (0..self.layers.len().saturating_sub(1)).rev() {
+                let keys = self.barycenters(layer, false);
+                self.reorder(layer, &keys);
+            }
+        }
+    }
+
+    /// Mean position of each slot's neighbours in the layer above (or below), or
+    /// its own position if it has none there
+    fn barycenters(&self, layer: usize, above: bool) -> Vec<f64> {
+        (0..self.layers[layer].len())
+            .map(|slot| {
+                let neighbours: Vec<usize> = self.links.iter()
+                    .filter_map(|&(l, from, to)| match above {
+                        true if l + 1 == layer && to == slot => Some(from),
+                        false if l == layer && from == slot => Some(to),
+                        _ => None,
+                    })
+                    .collect();
+                if neighbours.is_empty() {
+                    slot as f64
+                } else {
+                    neighbours.iter().sum::<usize>() as f64 / neighbours.len() as f64
+                }
+            })
+            .collect()
+    }
+
+    /// Sort a layer's slots by `keys`, keeping ties in their current order
+    fn reorder(&mut self, layer: usize, keys: &[f64]) {
+        let mut old_slots: Vec<usize> = (0..keys.len()).collect();
+        old_slots.sort_by(|a, b| keys[*a].total_cmp(&keys[*b]));
+        let mut new_slot = vec![0; keys.len()];
+        for (new, &old) in old_slots.iter().enumerate() {
+            new_slot[old] = new;
+        }
+
+        let slots = std::mem::take(&mut self.layers[layer]);
+        let mut slots: Vec<Option<Slot>> = slots.into_iter().map(Some).collect();
+        self.layers[layer] = old_slots.iter().map(|&old| slots[old].take().unwrap()).collect();
+        for (l, from, to) in &mut self.links {
+            if *l == layer {
+                *from = new_slot[*from];
+            }
+            if *l + 1 == layer {
+                *to = new_slot[*to];
+            }
+        }
+    }
+
+    /// Layer and slot of `id`
+    pub fn position(&self, id: &str) -> Option<(usize, usize)> {
+        self.layers.iter().enumerate().find_map(|(layer, slots)| {
+            slots.iter()
+                .position(|s| matches!(s, Slot::Issue(slot_id) if slot_id == id))
+                .map(|slot| (layer, slot))
+        })
+    }
+
+    /// Issues at the other end of `id`'s edges in the earlier (`upward`) or later
+    /// layers, following edges through the layers they pass, left to right
+    pub fn neighbours(&self, id: &str, upward: bool) -> Vec<&str> {
+        let Some(start) = self.position(id) else { return vec![] };
+        let mut found = vec![];
+        let mut pending = vec![start];
+        while let Some((layer, slot)) = pending.pop() {
+            for &(l, from, to) in &self.links {
+                let next = match upward {
+                    true if l + 1 == layer && to == slot => (l, from),
+                    false if l == layer && from == slot => (l + 1, to),
+                    _ => continue,
+                };
+                match &self.layers[next.0][next.1] {
+                    Slot::Issue(next_id) => found.push((next, next_id.as_str())),
+                    Slot::Pass => pending.push(next),
+                }
+            }
+        }
+        found.sort_by_key(|(position, _)| *position);
+        found.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// The issue nearest to `id` horizontally among `candidates`
+    pub fn nearest<'a>(&self, id: &str, candidates: &[&'a str]) -> Option<&'a str> {
+        let x = self.position(id).map(|(layer, slot)| self.slot_x(layer, slot))?;
+        candidates.iter()
+            .copied()
+            .min_by_key(|candidate| {
+                self.position(candidate)
+                    .map_or(usize::MAX, |(layer, slot)| self.slot_x(layer, slot).abs_diff(x))
+            })
+    }
+
+    /// The next issue to the left (or right) of `id` in its layer
+    pub fn beside(&self, id: &str, right: bool) -> Option<&str> {
+        let (layer, slot) = self.position(id)?;
+        let slots = &self.layers[layer];
+        fn issue(slot: &Slot) -> Option<&str> {
+            match slot {
+                Slot::Issue(id) => Some(id.as_str()),
+                Slot::Pass => None,
+            }
+        }
+        if right {
+            slots[slot + 1..].iter().find_map(issue)
+        } else {
+            slots[..slot].iter().rev().find_map(issue)
+        }
+    }
+
+    fn width(&self) -> usize {
+        let widest = self.layers.iter().map(|l| l.len()).max().unwrap_or(0);
+        (widest * (NODE_WIDTH + NODE_GAP)).saturating_sub(NODE_GAP)
+    }
+
+    /// Left edge of a slot; each layer is centred
+    fn slot_x(&self, layer: usize, slot: usize) -> usize {
+        let used = (self.layers[layer].len() * (NODE_WIDTH + NODE_GAP)).saturating_sub(NODE_GAP);
+        (self.width() - used) / 2 + slot * (NODE_WIDTH + NODE_GAP)
+    }
+
+    /// Draw the graph, labelling each box with `label(id)`. The centre issue gets
+    /// a double border and edges end in an arrow at the blocked issue.
+    pub fn draw(&self, label: impl Fn(&str) -> String) -> Canvas {
+        let width = self.width();
+        let height = (self.layers.len() * LAYER_HEIGHT).saturating_sub(LAYER_HEIGHT - 3);
+        let mut rows = vec![vec![' '; width]; height];
+        let mut lines = vec![vec![0u8; width]; height];
+        let mut boxes = vec![];
+
+        let mid = NODE_WIDTH / 2;
+        for (layer, slots) in self.layers.iter().enumerate() {
+            let y = layer * LAYER_HEIGHT;
+            for (slot, kind) in slots.iter().enumerate() {
+                let x = self.slot_x(layer, slot);
+                let Slot::Issue(id) = kind else {
+                    for row in &mut lines[y..y + 3] {
+                        row[x + mid] |= UP | DOWN;
+                    }
+                    continue;
+                };
+                let [h, v, tl, tr, bl, br] = if *id == self.center {
+                    ['═', '║', '╔', '╗', '╚', '╝']
+                } else {
+                    ['─', '│', '┌', '┐', '└', '┘']
+                };
+                let inner = NODE_WIDTH - 4;
+                let mut text: String = label(id).chars().take(inner).collect();
+                if label(id).chars().count() > inner {
+                    text.pop();
+                    text.push('…');
+                }
+                let text: Vec<char> = format!("{} {:<inner$} {}", v, text, v).chars().collect();
+                rows[y][x..x + NODE_WIDTH].fill(h);
+                rows[y][x] = tl;
+                rows[y][x + NODE_WIDTH - 1] = tr;
+                rows[y + 1][x..x + NODE_WIDTH].copy_from_slice(&text);
+                rows[y + 2][x..x + NODE_WIDTH].fill(h);
+                rows[y + 2][x] = bl;
+                rows[y + 2][x + NODE_WIDTH - 1] = br;
+                boxes.push((id.clone(), x, y));
+            }
+        }
+
+        let mut arrows = vec![];
+        for &(layer, from, to) in &self.links {
+            let sx = self.slot_x(layer, from) + mid;
+            let tx = self.slot_x(layer + 1, to) + mid;
+            let y = layer * LAYER_HEIGHT + 3;
+            if matches!(self.layers[layer][from], Slot::Issue(ref id) if *id == self.center) {
+                rows[y - 1][sx] = '╤';
+            } else if matches!(self.layers[layer][from], Slot::Issue(_)) {
+                rows[y - 1][sx] = '┬';
+            }
+
+            lines[y][sx] |= UP | DOWN;
+            if sx == tx {
+                lines[y + 1][sx] |= UP | DOWN;
+            } else {
+                let (toward, back) = if tx > sx { (RIGHT, LEFT) } else { (LEFT, RIGHT) };
+                lines[y + 1][sx] |= UP | toward;
+                for cell in &mut lines[y + 1][sx.min(tx) + 1..sx.max(tx)] {
+                    *cell |= LEFT | RIGHT;
+                }
+                lines[y + 1][tx] |= back | DOWN;
+            }
+            lines[y + 2][tx] |= UP | DOWN;
+            if matches!(self.layers[layer + 1][to], Slot::Issue(_)) {
+                arrows.push((tx, y + 2));
+            }
+        }
+
+        for (y, row) in lines.iter().enumerate() {
+            for (x, &bits) in row.iter().enumerate() {
+                if bits != 0 {
+                    rows[y][x] = line_char(bits);
+                }
+            }
+        }
+        for (x, y) in arrows {
+            rows[y][x] = '▼';
+        }
+
+        Canvas { width, height, rows, boxes }
+    }
+}
+
+/// Issues ordered so that blockers come before what they block, and the edges
+/// that had to be ignored to get there because they close a cycle
+fn topological_order<'a>(
+    ids: &[&'a str],
+    edges: &[(&'a str, &'a str)],
+) -> (Vec<&'a str>, HashSet<(&'a str, &'a str)>) {
+    fn visit<'a>(
+        id: &'a str,
+        edges: &[(&'a str, &'a str)],
+        on_path: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+        back_edges: &mut HashSet<(&'a str, &'a str)>,
+    ) {
+        on_path.insert(id);
+        for &(from, to) in edges.iter().filter(|(from, _)| *from == id) {
+            if on_path.contains(to) {
+                back_edges.insert((from, to));
+            } else if !done.contains(to) {
+                visit(to, edges, on_path, done, order, back_edges);
+            }
+        }
+        on_path.remove(id);
+        done.insert(id);
+        order.push(id);
+    }
+
+    let mut order = vec![];
+    let mut done = HashSet::new();
+    let mut back_edges = HashSet::new();
+    for &id in ids {
+        if !done.contains(id) {
+            visit(id, edges, &mut HashSet::new(), &mut done, &mut order, &mut back_edges);
+        }
+    }
+    order.reverse();
+    (order, back_edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bd::{Dependency, Issue};
+    use crate::HierarchyMode;
+
+    fn issue(id: &str, blockers: &[&str]) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Title {}", id),
+            status: "open".to_string(),
+            priority: 2,
+            issue_type: "task".to_string(),
+            created_at: "2024-01-01".to_string(),
+            updated_at: "2024-01-01".to_string(),
+            dependencies: Some(blockers.iter()
+                .map(|b| Dependency {
+                    id: b.to_string(),
+                    title: format!("Title {}", b),
+                    dependency_type: Some("blocks".to_string()),
+                })
+                .collect()),
+            ..Default::default()
+        }
+    }
+
+    fn tree(issues: Vec<Issue>) -> IssueTree {
+        IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::DependencyBased)
+    }
+
+    fn layer_ids(graph: &DepGraph, layer: usize) -> Vec<&str> {
+        graph.layers[layer].iter()
+            .map(|s| match s {
+                Slot::Issue(id) => id.as_str(),
+                Slot::Pass => "|",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layers_put_blockers_above() {
+        // a blocks b and c; b and c block d
+        let tree = tree(vec![
+            issue("a", &[]),
+            issue("b", &["a"]),
+            issue("c", &["a"]),
+            issue("d", &["b", "c"]),
+        ]);
+        let graph = DepGraph::around(&tree, "b", 2);
+        assert_eq!(graph.layers.len(), 3);
+        assert_eq!(layer_ids(&graph, 0), vec!["a"]);
+        assert_eq!(layer_ids(&graph, 2), vec!["d"]);
+        let mut middle = layer_ids(&graph, 1);
+        middle.sort();
+        assert_eq!(middle, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_radius_limits_reach() {
+        let tree = tree(vec![
+            issue("a", &[]),
+            issue("b", &["a"]),
+            issue("c", &["b"]),
+            issue("d", &["c"]),
+        ]);
+        let graph = DepGraph::around(&tree, "b", 1);
+        let ids: Vec<&str> = (0..graph.layers.len()).flat_map(|l| layer_ids(&graph, l)).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_long_edges_pass_through_layers() {
+        // a → b → c, and a → c directly
+        let tree = tree(vec![
+            issue("a", &[]),
+            issue("b", &["a"]),
+            issue("c", &["a", "b"]),
+        ]);
+        let graph = DepGraph::around(&tree, "a", 2);
+        assert_eq!(graph.layers.len(), 3);
+        assert!(graph.layers[1].contains(&Slot::Pass));
+        let mut blockers = graph.neighbours("c", true);
+        blockers.sort();
+        assert_eq!(blockers, vec!["a", "b"]);
+        assert_eq!(graph.neighbours("a", false).len(), 2);
+    }
+
+    #[test]
+    fn test_cycles_are_laid_out_without_their_closing_edge() {
+        let tree = tree(vec![
+            issue("a", &["b"]),
+            issue("b", &["a"]),
+        ]);
+        let graph = DepGraph::around(&tree, "a", 2);
+        assert_eq!(graph.hidden_edges, 1);
+        assert_eq!(graph.layers.len(), 2);
+    }
+
+    #[test]
+    fn test_navigation_follows_edges() {
+        let tree = tree(vec![
+            issue("a", &[]),
+            issue("b", &["a"]),
+            issue("c", &["a"]),
+            issue("d", &["b"]),
+        ]);
+        let graph = DepGraph::around(&tree, "a", 2);
+        assert!(graph.neighbours("a", true).is_empty());
+        assert_eq!(graph.neighbours("d", true), vec!["b"]);
+        let mut below = graph.neighbours("a", false);
+        below.sort();
+        assert_eq!(below, vec!["b", "c"]);
+        // d sits under b, so b is its nearest neighbour in the layer above
+        assert_eq!(graph.nearest("d", &["b", "c"]), Some("b"));
+        let right = graph.beside("b", true).or(graph.beside("b", false));
+        assert_eq!(right, Some("c"));
+    }
+
+    #[test]
+    fn test_draw_connects_boxes() {
+        let tree = tree(vec![
+            issue("a", &[]),
+            issue("b", &["a"]),
+        ]);
+        let graph = DepGraph::around(&tree, "a", 1);
+        let canvas = graph.draw(|id| id.to_string());
+        let text: Vec<String> = canvas.rows.iter().map(|r| r.iter().collect()).collect();
+        assert_eq!(canvas.height, 9);
+        assert!(text[0].starts_with('╔'));
+        assert!(text[1].starts_with("║ a "));
+        assert_eq!(text[2].chars().nth(NODE_WIDTH / 2), Some('╤'));
+        assert_eq!(text[3].chars().nth(NODE_WIDTH / 2), Some('│'));
+        assert_eq!(text[5].chars().nth(NODE_WIDTH / 2), Some('▼'));
+        assert!(text[7].starts_with("│ b "));
+        assert_eq!(canvas.center_of("b"), Some((NODE_WIDTH / 2, 7)));
+    }
+
+    #[test]
+    fn test_draw_truncates_long_labels() {
+        let tree = tree(vec![issue("a", &[])]);
+        let graph = DepGraph::around(&tree, "a", 1);
+        let canvas = graph.draw(|_| "x".repeat(50));
+        let middle: String = canvas.rows[1].iter().collect();
+        assert_eq!(middle.chars().count(), NODE_WIDTH);
+        assert!(middle.ends_with("x… ║"));
+    }
+}
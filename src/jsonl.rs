@@ -0,0 +1,254 @@
+use crate::backend::IssueBackend;
+use crate::bd::{Dependency, Issue, NewIssue, TextField};
+use crate::tree::IssueTree;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// One line of `.beads/issues.jsonl`.
+///
+/// The export stores dependencies as edges (`issue_id` depends on `depends_on_id`)
+/// rather than the denormalized id/title pairs that `bd show --json` returns.
+#[derive(Debug, Deserialize)]
+struct RawIssue {
+    id: String,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    status: String,
+    #[serde(default)]
+    priority: i32,
+    #[serde(default)]
+    issue_type: String,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    created_by: Option<String>,
+    #[serde(default)]
+    updated_at: String,
+    #[serde(default)]
+    closed_at: Option<String>,
+    #[serde(default)]
+    labels: Option<Vec<String>>,
+    #[serde(default)]
+    parent: Option<String>,
+    #[serde(default)]
+    dependencies: Option<Vec<RawDependency>>,
+    #[serde(default)]
+    notes: Option<String>,
+    #[serde(default)]
+    design: Option<String>,
+    #[serde(default)]
+    acceptance_criteria: Option<String>,
+    #[serde(default)]
+    assignee: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDependency {
+    depends_on_id: String,
+    #[serde(default, rename = "type")]
+    dep_type: Option<String>,
+}
+
+/// Read-only backend that parses the beads JSONL export directly, without bd
+#[derive(Debug, Clone)]
+pub struct JsonlBackend {
+    path: PathBuf,
+}
+
+impl JsonlBackend {
+    pub fn new(path: PathBuf) -> Self {
+        JsonlBackend { path }
+    }
+
+    /// `.beads/issues.jsonl` inside a beads directory, if it exists
+    pub fn in_beads_dir(beads_dir: &std::path::Path) -> Option<Self> {
+        let path = beads_dir.join("issues.jsonl");
+        path.is_file().then(|| Self::new(path))
+    }
+
+    fn read_issues(&self) -> Result<Vec<Issue>> {
+        let contents = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        parse_issues(&contents)
+    }
+}
+
+/// Parse JSONL export contents into issues with dependencies and dependents filled in
+fn parse_issues(contents: &str) -> Result<Vec<Issue>> {
+    let mut raw_issues = Vec::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let raw: RawIssue = serde_json::from_str(line)
+            .with_context(|| format!("Failed to parse issues.jsonl line {}", line_no + 1))?;
+        // Deleted issues are kept in the export as tombstones
+        if raw.status != "tombstone" {
+            raw_issues.push(raw);
+        }
+    }
+
+    let titles: HashMap<&str, &str> = raw_issues.iter()
+        .map(|r| (r.id.as_str(), r.title.as_str()))
+        .collect();
+    let title_of = |id: &str| titles.get(id).map(|t| t.to_string()).unwrap_or_default();
+
+    // Reverse the edges to get dependents: if A depends on B, A is a dependent of B
+    let mut dependents: HashMap<String, Vec<Dependency>> = HashMap::new();
+    for raw in &raw_issues {
+        for dep in raw.dependencies.iter().flatten() {
+            dependents.entry(dep.depends_on_id.clone()).or_default().push(Dependency {
+                id: raw.id.clone(),
+                title: raw.title.clone(),
+                dependency_type: dep.dep_type.clone(),
+            });
+        }
+    }
+
+    let issues = raw_issues.iter()
+        .map(|raw| {
+            let dependencies: Vec<Dependency> = raw.dependencies.iter().flatten()
+                .map(|dep| Dependency {
+                    id: dep.depends_on_id.clone(),
+                    title: title_of(&dep.depends_on_id),
+                    dependency_type: dep.dep_type.clone(),
+                })
+                .collect();
+            Issue {
+                id: raw.id.clone(),
+                title: raw.title.clone(),
+                description: raw.description.clone(),
+                status: raw.status.clone(),
+                priority: raw.priority,
+                issue_type: raw.issue_type.clone(),
+                created_at: raw.created_at.clone(),
+                created_by: raw.created_by.clone(),
+                updated_at: raw.updated_at.clone(),
+                closed_at: raw.closed_at.clone(),
+                labels: raw.labels.clone(),
+                parent: raw.parent.clone(),
+                dependencies: (!dependencies.is_empty()).then_some(dependencies),
+                dependents: dependents.remove(&raw.id),
+                notes: raw.notes.clone(),
+                design: raw.design.clone(),
+                acceptance_criteria: raw.acceptance_criteria.clone(),
+                assignee: raw.assignee.clone(),
+            }
+        })
+        .collect();
+
+    Ok(issues)
+}
+
+impl IssueBackend for JsonlBackend {
+    fn list_issues_with_details(&self) -> Result<Vec<Issue>> {
+        self.read_issues()
+    }
+
+    fn get_ready_ids(&self) -> Result<HashSet<String>> {
+        Ok(IssueTree::compute_ready_ids(&self.read_issues()?))
+    }
+
+    fn get_issue_details(&self, id: &str) -> Result<Option<Issue>> {
+        Ok(self.read_issues()?.into_iter().find(|i| i.id == id))
+    }
+
+    fn update_text_field(&self, _id: &str, _field: TextField, _value: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn create_issue(&self, _issue: &NewIssue) -> Result<String> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn close_issue(&self, _id: &str, _reason: Option<&str>) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn update_issue_status(&self, _id: &str, _status: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn update_issue_priority(&self, _id: &str, _priority: i32) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn update_issue_type(&self, _id: &str, _issue_type: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn add_label(&self, _id: &str, _label: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn remove_label(&self, _id: &str, _label: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn add_dependency(&self, _id: &str, _depends_on: &str, _dep_type: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+
+    fn remove_dependency(&self, _id: &str, _depends_on: &str) -> Result<()> {
+        anyhow::bail!("issues.jsonl is read-only; editing requires bd")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{"id":"bsv-a","title":"Blocker","status":"open","priority":1,"issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-02T00:00:00Z","labels":["backend"]}
+{"id":"bsv-b","title":"Blocked","status":"open","priority":2,"issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","dependencies":[{"issue_id":"bsv-b","depends_on_id":"bsv-a","type":"blocks","created_at":"2024-01-01T00:00:00Z","created_by":"me"}]}
+{"id":"bsv-c","title":"Related only","status":"open","priority":2,"issue_type":"bug","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","dependencies":[{"issue_id":"bsv-c","depends_on_id":"bsv-a","type":"related"}]}
+
+{"id":"bsv-d","title":"Done","status":"closed","priority":2,"issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}
+{"id":"bsv-e","title":"Deleted","status":"tombstone","priority":2,"issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}
+"#;
+
+    fn find<'a>(issues: &'a [Issue], id: &str) -> &'a Issue {
+        issues.iter().find(|i| i.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_parse_issues_jsonl() {
+        let issues = parse_issues(EXPORT).unwrap();
+
+        // Blank lines and tombstones are skipped
+        assert_eq!(issues.len(), 4);
+        assert!(!issues.iter().any(|i| i.id == "bsv-e"));
+
+        let a = find(&issues, "bsv-a");
+        assert_eq!(a.priority, 1);
+        assert_eq!(a.labels, Some(vec!["backend".to_string()]));
+        assert!(a.dependencies.is_none());
+
+        // Dependency edges become id/title pairs like `bd show` returns
+        let b = find(&issues, "bsv-b");
+        let deps = b.dependencies.as_ref().unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].id, "bsv-a");
+        assert_eq!(deps[0].title, "Blocker");
+        assert_eq!(deps[0].dependency_type.as_deref(), Some("blocks"));
+    }
+
+    #[test]
+    fn test_parse_issues_computes_dependents() {
+        let issues = parse_issues(EXPORT).unwrap();
+
+        let dependents = find(&issues, "bsv-a").dependents.as_ref().unwrap();
+        let ids: Vec<&str> = dependents.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["bsv-b", "bsv-c"]);
+        assert!(find(&issues, "bsv-b").dependents.is_none());
+    }
+
+    #[test]
+    fn test_parse_issues_reports_bad_line() {
+        let err = parse_issues("{\"id\": \"bsv-a\"}\nnot json").unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+    }
+}
@@ -1,5 +1,12 @@
+mod activity;
+mod backend;
 mod bd;
+mod filter;
+mod fuzzy;
+mod graph;
+mod jsonl;
 mod state;
+mod stats;
 mod tree;
 mod ui;
 
@@ -10,7 +17,8 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::sync::Arc;
 use std::thread;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -25,6 +33,68 @@ pub enum HierarchyMode {
     #[default]
     IdBased,        // Current: dotted ID hierarchy (bsv-epic.1 is child of bsv-epic)
     DependencyBased, // New: dependency chain hierarchy (blocked issues are children)
+    Grouped(GroupBy), // Flat lists under one header per status, type, label, ...
+    ReadyQueue,       // Flat list of ready issues, in the order to work on them
+    Timeline,         // Issues by latest activity, under a header per day
+}
+
+/// What the group headers of `HierarchyMode::Grouped` are made from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GroupBy {
+    Status,
+    Type,
+    /// One header per label; issues with several labels appear under each
+    Label,
+    Priority,
+    Assignee,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 5] = [GroupBy::Status, GroupBy::Type, GroupBy::Label, GroupBy::Priority, GroupBy::Assignee];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GroupBy::Status => "status",
+            GroupBy::Type => "type",
+            GroupBy::Label => "label",
+            GroupBy::Priority => "priority",
+            GroupBy::Assignee => "assignee",
+        }
+    }
+}
+
+/// Order of roots and children in the tree
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum SortMode {
+    #[default]
+    Priority,
+    /// Most recently updated first
+    Updated,
+    /// Oldest first
+    Created,
+    /// Natural order, so `x.2` comes before `x.10`
+    Id,
+    /// In progress, open, blocked, deferred, then closed
+    Status,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 5] = [SortMode::Priority, SortMode::Updated, SortMode::Created, SortMode::Id, SortMode::Status];
+
+    pub fn next(self) -> SortMode {
+        let pos = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SortMode::Priority => "priority",
+            SortMode::Updated => "updated",
+            SortMode::Created => "created",
+            SortMode::Id => "id",
+            SortMode::Status => "status",
+        }
+    }
 }
 
 /// Which field is currently being edited
@@ -32,18 +102,65 @@ pub enum HierarchyMode {
 pub enum EditField {
     Title,
     Description,
+    Design,
+    AcceptanceCriteria,
+    Notes,
 }
 
-/// State for inline editing of an issue
-#[derive(Debug, Clone)]
-pub struct EditState {
-    /// The issue ID being edited
-    pub issue_id: String,
-    /// Which field is being edited
-    pub field: EditField,
-    /// The original value (for cancel/revert)
-    pub original: String,
-    /// The current edited value
+impl EditField {
+    /// Fields edited from the details panel, in Tab order
+    const DETAILS: [EditField; 5] = [
+        EditField::Title,
+        EditField::Description,
+        EditField::Design,
+        EditField::AcceptanceCriteria,
+        EditField::Notes,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EditField::Title => "Title",
+            EditField::Description => "Description",
+            EditField::Design => "Design",
+            EditField::AcceptanceCriteria => "Acceptance Criteria",
+            EditField::Notes => "Notes",
+        }
+    }
+
+    /// Long-form markdown fields, where Enter inserts a newline
+    pub fn is_multiline(self) -> bool {
+        matches!(
+            self,
+            EditField::Description | EditField::Design | EditField::AcceptanceCriteria | EditField::Notes
+        )
+    }
+
+    /// The next (or previous) details field in Tab order, wrapping around
+    fn cycle(self, forward: bool) -> EditField {
+        let len = Self::DETAILS.len();
+        let pos = Self::DETAILS.iter().position(|&f| f == self).unwrap_or(0);
+        let next = if forward { pos + 1 } else { pos + len - 1 };
+        Self::DETAILS[next % len]
+    }
+}
+
+impl From<EditField> for TextField {
+    fn from(field: EditField) -> Self {
+        match field {
+            EditField::Title => TextField::Title,
+            EditField::Description => TextField::Description,
+            EditField::Design => TextField::Design,
+            EditField::AcceptanceCriteria => TextField::AcceptanceCriteria,
+            EditField::Notes => TextField::Notes,
+        }
+    }
+}
+
+/// Editable text with a cursor, shared by the edit panel, the new issue form, prompts
+/// and the popups' search boxes
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    /// The current text
     pub buffer: String,
     /// Cursor position within the buffer (byte offset)
     pub cursor: usize,
@@ -53,15 +170,12 @@ pub struct EditState {
     pub cursor_col: usize,
 }
 
-impl EditState {
-    /// Create a new edit state for a field
-    pub fn new(issue_id: String, field: EditField, value: String) -> Self {
+impl TextInput {
+    /// Input holding `value`, with the cursor at its end
+    pub fn new(value: String) -> Self {
         let cursor = value.len();
         let (cursor_line, cursor_col) = Self::compute_line_col(&value, cursor);
-        EditState {
-            issue_id,
-            field,
-            original: value.clone(),
+        TextInput {
             buffer: value,
             cursor,
             cursor_line,
@@ -221,23 +335,691 @@ impl EditState {
         }
     }
 
+    /// Apply a plain text-editing key (typing, deletion, cursor movement).
+    /// Returns false if the key isn't one of them.
+    pub fn handle_text_key(&mut self, code: KeyCode, modifiers: KeyModifiers, multiline: bool) -> bool {
+        match (code, modifiers) {
+            (KeyCode::Char(c), KeyModifiers::NONE) |
+            (KeyCode::Char(c), KeyModifiers::SHIFT) => self.insert_char(c),
+            (KeyCode::Backspace, KeyModifiers::NONE) => self.delete_char_before(),
+            (KeyCode::Delete, KeyModifiers::NONE) => self.delete_char_at(),
+            (KeyCode::Left, KeyModifiers::NONE) => self.move_left(),
+            (KeyCode::Right, KeyModifiers::NONE) => self.move_right(),
+            (KeyCode::Up, KeyModifiers::NONE) if multiline => self.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) if multiline => self.move_down(),
+            (KeyCode::Home, KeyModifiers::NONE) => self.move_to_line_start(),
+            (KeyCode::End, KeyModifiers::NONE) => self.move_to_line_end(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// State for inline editing of an issue
+#[derive(Debug, Clone)]
+pub struct EditState {
+    /// The issue ID being edited
+    pub issue_id: String,
+    /// Which field is being edited
+    pub field: EditField,
+    /// The original value (for cancel/revert)
+    pub original: String,
+    /// The current edited value
+    pub input: TextInput,
+}
+
+impl EditState {
+    /// Create a new edit state for a field
+    pub fn new(issue_id: String, field: EditField, value: String) -> Self {
+        EditState {
+            issue_id,
+            field,
+            original: value.clone(),
+            input: TextInput::new(value),
+        }
+    }
+
     /// Check if the buffer has been modified from the original
     pub fn is_modified(&self) -> bool {
-        self.buffer != self.original
+        self.input.buffer != self.original
     }
 
     /// Revert to the original value
     pub fn revert(&mut self) {
-        self.buffer = self.original.clone();
-        self.cursor = self.buffer.len();
-        self.update_cursor_position();
+        self.input = TextInput::new(self.original.clone());
+    }
+}
+
+/// What a one-line prompt is asking for
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptKind {
+    /// Optional reason for closing an issue
+    CloseReason { issue_id: String },
+    /// `/` search, remembering the issue selected before it started
+    Search { origin: Option<String> },
+    /// Filter expression for the tree, listing the saved filters' names
+    Filter { saved: Vec<String> },
+    /// Name to save the active filter under
+    SaveFilter,
+}
+
+/// A one-line text prompt shown at the bottom of the screen
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    pub kind: PromptKind,
+    pub input: TextInput,
+    /// Why the last submitted text was rejected
+    pub error: Option<String>,
+}
+
+impl Prompt {
+    pub fn new(kind: PromptKind) -> Self {
+        Self::with_text(kind, "")
+    }
+
+    /// Prompt with its input already filled in
+    pub fn with_text(kind: PromptKind, text: &str) -> Self {
+        Prompt {
+            kind,
+            input: TextInput::new(text.to_string()),
+            error: None,
+        }
+    }
+
+    /// Question shown as the prompt's title
+    pub fn label(&self) -> String {
+        match &self.kind {
+            PromptKind::CloseReason { issue_id } => {
+                format!("Close {} - reason (optional, Enter=close, Esc=cancel)", issue_id)
+            }
+            PromptKind::Search { .. } => "Search / (Enter=keep, Esc=cancel)".to_string(),
+            PromptKind::Filter { saved } if saved.is_empty() => {
+                "Filter (e.g. status:open priority:<=1 -label:wontfix; empty=clear)".to_string()
+            }
+            PromptKind::Filter { saved } => {
+                let names: Vec<String> = saved.iter().map(|name| format!("@{}", name)).collect();
+                format!("Filter (empty=clear; saved: {})", names.join(" "))
+            }
+            PromptKind::SaveFilter => "Save filter as (name; -name deletes a saved filter)".to_string(),
+        }
+    }
+}
+
+/// What a picker popup edits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PickerKind {
+    Priority,
+    IssueType,
+    Labels,
+}
+
+/// Names shown next to P0-P4 in the priority picker
+pub const PRIORITY_NAMES: [&str; 5] = ["Critical", "High", "Medium", "Low", "Backlog"];
+
+/// Popup for choosing an issue's priority or type, or editing its labels.
+///
+/// Priority and type pick one option. Labels are a checklist of every label on the
+/// loaded issues; typing filters the list, and Enter on a new name adds it.
+#[derive(Debug, Clone)]
+pub struct Picker {
+    pub kind: PickerKind,
+    pub issue_id: String,
+    /// All choices (priorities as "0".."4", type names, or label names)
+    pub options: Vec<String>,
+    /// Index into `visible_options()`
+    pub cursor: usize,
+    /// Checked labels (label editor only)
+    pub checked: BTreeSet<String>,
+    /// Labels the issue had when the editor opened
+    pub original: BTreeSet<String>,
+    /// Filter / new label text (label editor only)
+    pub input: TextInput,
+}
+
+impl Picker {
+    fn new(kind: PickerKind, issue: &bd::Issue, options: Vec<String>, current: &str) -> Self {
+        let cursor = options.iter().position(|o| o == current).unwrap_or(0);
+        Picker {
+            kind,
+            issue_id: issue.id.clone(),
+            options,
+            cursor,
+            checked: BTreeSet::new(),
+            original: BTreeSet::new(),
+            input: TextInput::default(),
+        }
+    }
+
+    pub fn priority(issue: &bd::Issue) -> Self {
+        let options = (0..PRIORITY_NAMES.len()).map(|p| p.to_string()).collect();
+        Self::new(PickerKind::Priority, issue, options, &issue.priority.to_string())
+    }
+
+    pub fn issue_type(issue: &bd::Issue) -> Self {
+        let mut options: Vec<String> = ISSUE_TYPES.iter().map(|t| t.to_string()).collect();
+        // Keep custom types bd may know about selectable
+        if !options.contains(&issue.issue_type) && !issue.issue_type.is_empty() {
+            options.push(issue.issue_type.clone());
+        }
+        Self::new(PickerKind::IssueType, issue, options, &issue.issue_type)
+    }
+
+    /// Label editor offering `known_labels` (from all loaded issues) plus the issue's own
+    pub fn labels(issue: &bd::Issue, known_labels: impl IntoIterator<Item = String>) -> Self {
+        let current: BTreeSet<String> = issue.labels.iter().flatten().cloned().collect();
+        let options: BTreeSet<String> = known_labels.into_iter().chain(current.iter().cloned()).collect();
+        let mut picker = Self::new(PickerKind::Labels, issue, options.into_iter().collect(), "");
+        picker.checked = current.clone();
+        picker.original = current;
+        picker
+    }
+
+    /// Options matching the typed filter (all of them, except in the label editor)
+    pub fn visible_options(&self) -> Vec<&String> {
+        let filter = self.input.buffer.trim().to_lowercase();
+        self.options.iter()
+            .filter(|o| filter.is_empty() || o.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    pub fn current_option(&self) -> Option<&String> {
+        self.visible_options().get(self.cursor).copied()
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.visible_options().len() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Check or uncheck the highlighted label
+    pub fn toggle_current(&mut self) {
+        if let Some(label) = self.current_option().cloned() {
+            if !self.checked.remove(&label) {
+                self.checked.insert(label);
+            }
+        }
+    }
+
+    /// Autocomplete the typed text to the highlighted label
+    pub fn complete(&mut self) {
+        if let Some(label) = self.current_option().cloned() {
+            self.input = TextInput::new(label);
+        }
+    }
+
+    /// Toggle the label named by the typed text, adding it as a new option if needed
+    pub fn submit_input(&mut self) {
+        let label = self.input.buffer.trim().to_string();
+        if label.is_empty() {
+            return;
+        }
+        if !self.options.contains(&label) {
+            self.options.push(label.clone());
+            self.options.sort();
+        }
+        if !self.checked.remove(&label) {
+            self.checked.insert(label);
+        }
+        self.input = TextInput::default();
+        self.cursor = 0;
+    }
+
+    /// Keep the cursor within the options left after the typed filter changes
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.visible_options().len().saturating_sub(1));
+    }
+
+    pub fn title(&self) -> String {
+        match self.kind {
+            PickerKind::Priority => format!(" Priority for {} ", self.issue_id),
+            PickerKind::IssueType => format!(" Type for {} ", self.issue_id),
+            PickerKind::Labels => format!(" Labels for {} ", self.issue_id),
+        }
+    }
+}
+
+/// Fuzzy search for an issue by "id title", used by the Ctrl+P finder and the
+/// dependency editor. Matches are ranked when the query changes, not on every draw.
+#[derive(Debug, Clone)]
+pub struct IssueSearch {
+    pub input: TextInput,
+    /// (ID, "id title") for every issue that can be picked, in ID order
+    candidates: Vec<(String, String)>,
+    /// Indices into `candidates` matching the input, best first
+    matches: Vec<usize>,
+    /// Index into the matches
+    pub cursor: usize,
+}
+
+impl IssueSearch {
+    pub fn new<'a>(issues: impl IntoIterator<Item = &'a bd::Issue>) -> Self {
+        let mut candidates: Vec<(String, String)> = issues.into_iter()
+            .map(|i| (i.id.clone(), format!("{} {}", i.id, i.title)))
+            .collect();
+        candidates.sort();
+        let mut search = IssueSearch {
+            input: TextInput::default(),
+            candidates,
+            matches: Vec::new(),
+            cursor: 0,
+        };
+        search.rank();
+        search
+    }
+
+    /// Drop the candidates whose ID fails `keep`
+    pub fn retain(&mut self, keep: impl Fn(&str) -> bool) {
+        self.candidates.retain(|(id, _)| keep(id));
+        self.rank();
+    }
+
+    /// Rank the candidates against the input, highlighting the best match
+    fn rank(&mut self) {
+        self.matches = fuzzy::rank(&self.input.buffer, &self.candidates.iter().map(|(_, label)| label).collect::<Vec<_>>());
+        self.cursor = 0;
+    }
+
+    /// Apply a text-editing key to the input, re-ranking if it changed.
+    /// Returns false if the key isn't one.
+    pub fn handle_text_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        let handled = self.input.handle_text_key(code, modifiers, false);
+        if handled {
+            self.rank();
+        }
+        handled
+    }
+
+    /// Replace the query
+    pub fn set_query(&mut self, query: &str) {
+        self.input = TextInput::new(query.to_string());
+        self.rank();
+    }
+
+    /// "id title" of the matches, best first
+    pub fn match_labels(&self) -> impl Iterator<Item = &str> {
+        self.matches.iter().map(|&i| self.candidates[i].1.as_str())
+    }
+
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// ID of the highlighted match
+    pub fn current(&self) -> Option<&str> {
+        self.matches.get(self.cursor).map(|&i| self.candidates[i].0.as_str())
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.matches.len() {
+            self.cursor += 1;
+        }
+    }
+}
+
+/// Dependency types offered when adding a dependency
+pub const DEPENDENCY_TYPES: [&str; 3] = ["blocks", "related", "parent-child"];
+
+/// Popup listing an issue's dependencies, with a fuzzy search for adding new ones.
+///
+/// Opens on the list of existing dependencies; `a` switches to searching the other
+/// issues, and Enter adds the highlighted one with the chosen dependency type.
+#[derive(Debug, Clone)]
+pub struct DepEditor {
+    pub issue_id: String,
+    /// What the issue currently depends on
+    pub deps: Vec<bd::Dependency>,
+    /// Every other issue, searched when adding a dependency
+    others: IssueSearch,
+    /// Search for an issue to add, minus existing dependencies (None while browsing the list)
+    pub search: Option<IssueSearch>,
+    /// Index into DEPENDENCY_TYPES for the dependency being added
+    pub dep_type: usize,
+    /// Index into `deps`
+    pub cursor: usize,
+    /// Why the last change was refused or failed
+    pub error: Option<String>,
+}
+
+impl DepEditor {
+    /// Editor for `issue`, offering every other issue in `all` as a new dependency
+    pub fn new<'a>(issue: &bd::Issue, all: impl IntoIterator<Item = &'a bd::Issue>) -> Self {
+        DepEditor {
+            issue_id: issue.id.clone(),
+            deps: issue.dependencies.clone().unwrap_or_default(),
+            others: IssueSearch::new(all.into_iter().filter(|i| i.id != issue.id)),
+            search: None,
+            dep_type: 0,
+            cursor: 0,
+            error: None,
+        }
+    }
+
+    /// ID of the highlighted search result
+    pub fn current_match(&self) -> Option<String> {
+        self.search.as_ref()?.current().map(|id| id.to_string())
+    }
+
+    pub fn current_dep(&self) -> Option<&bd::Dependency> {
+        self.deps.get(self.cursor)
+    }
+
+    pub fn move_up(&mut self) {
+        match self.search.as_mut() {
+            Some(search) => search.move_up(),
+            None => self.cursor = self.cursor.saturating_sub(1),
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        match self.search.as_mut() {
+            Some(search) => search.move_down(),
+            None if self.cursor + 1 < self.deps.len() => self.cursor += 1,
+            None => {}
+        }
+    }
+
+    pub fn start_search(&mut self) {
+        let mut search = self.others.clone();
+        search.retain(|id| !self.deps.iter().any(|d| d.id == id));
+        self.search = Some(search);
+        self.error = None;
+    }
+
+    pub fn stop_search(&mut self) {
+        self.search = None;
+        self.cursor = 0;
+        self.error = None;
+    }
+
+    pub fn cycle_type(&mut self) {
+        self.dep_type = (self.dep_type + 1) % DEPENDENCY_TYPES.len();
+    }
+
+    pub fn dep_type_name(&self) -> &'static str {
+        DEPENDENCY_TYPES[self.dep_type]
+    }
+
+    /// Keep the cursor within the list after it shrinks
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.deps.len().saturating_sub(1));
+    }
+}
+
+/// `I` popup listing the open issues that hold up the most downstream work
+#[derive(Debug, Clone)]
+pub struct ImpactList {
+    /// (ID, "id title", open issues waiting on it), most first
+    pub items: Vec<(String, String, usize)>,
+    pub cursor: usize,
+}
+
+impl ImpactList {
+    pub fn new(tree: &IssueTree) -> Self {
+        let items = tree.blocking_impact().into_iter()
+            .map(|(id, count)| {
+                let label = format!("{} {}", id, tree.nodes[&id].issue.title);
+                (id, label, count)
+            })
+            .collect();
+        ImpactList { items, cursor: 0 }
+    }
+
+    pub fn current(&self) -> Option<&str> {
+        self.items.get(self.cursor).map(|(id, _, _)| id.as_str())
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.items.len() {
+            self.cursor += 1;
+        }
+    }
+}
+
+/// Kanban board (`b`): a column of cards per status, shown instead of the tree and
+/// details panels. The cards come from the tree, so they follow its filter and sort order.
+#[derive(Debug, Clone, Default)]
+pub struct Board {
+    /// Selected column, an index into `tree::BOARD_STATUSES`
+    pub column: usize,
+    /// Selected card in each column
+    pub rows: [usize; BOARD_STATUSES.len()],
+}
+
+impl Board {
+    /// Open the board with `id`'s card selected, if it's on the board
+    fn new(columns: &[Vec<String>], id: Option<&str>) -> Self {
+        let mut board = Board::default();
+        if let Some(id) = id {
+            board.select(columns, id);
+        }
+        board
+    }
+
+    /// Select `id`'s card, if it's on the board
+    fn select(&mut self, columns: &[Vec<String>], id: &str) {
+        for (column, ids) in columns.iter().enumerate() {
+            if let Some(row) = ids.iter().position(|x| x == id) {
+                self.column = column;
+                self.rows[column] = row;
+            }
+        }
+    }
+
+    /// The selected card's row in `column`, kept within the cards it has
+    pub fn row(&self, columns: &[Vec<String>], column: usize) -> usize {
+        self.rows[column].min(columns[column].len().saturating_sub(1))
+    }
+
+    /// The selected card
+    pub fn current<'a>(&self, columns: &'a [Vec<String>]) -> Option<&'a str> {
+        columns[self.column].get(self.row(columns, self.column)).map(|s| s.as_str())
+    }
+
+    fn move_up(&mut self, columns: &[Vec<String>]) {
+        self.rows[self.column] = self.row(columns, self.column).saturating_sub(1);
+    }
+
+    fn move_down(&mut self, columns: &[Vec<String>]) {
+        let row = self.row(columns, self.column);
+        if row + 1 < columns[self.column].len() {
+            self.rows[self.column] = row + 1;
+        }
+    }
+}
+
+/// `C` popup listing every dependency cycle with the dependencies that form it,
+/// so the one to remove can be found
+#[derive(Debug, Clone)]
+pub struct CycleList {
+    /// Members of each cycle
+    pub cycles: Vec<Vec<String>>,
+    /// (cycle index, dependent, blocker, dependency type) for every edge in a cycle
+    pub edges: Vec<(usize, String, String, String)>,
+    /// Index into `edges`
+    pub cursor: usize,
+}
+
+impl CycleList {
+    pub fn new(tree: &IssueTree) -> Self {
+        let edges = tree.dep_cycles.iter().enumerate()
+            .flat_map(|(i, cycle)| {
+                tree.cycle_edges(cycle).into_iter().map(move |(from, to, dep_type)| (i, from, to, dep_type))
+            })
+            .collect();
+        CycleList { cycles: tree.dep_cycles.clone(), edges, cursor: 0 }
+    }
+
+    /// The dependent issue of the highlighted edge, where the dependency can be removed
+    pub fn current(&self) -> Option<&str> {
+        self.edges.get(self.cursor).map(|(_, from, _, _)| from.as_str())
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.edges.len() {
+            self.cursor += 1;
+        }
+    }
+}
+
+/// Issue types accepted by `bd create`
+pub const ISSUE_TYPES: [&str; 5] = ["task", "bug", "feature", "epic", "chore"];
+
+/// Fields of the new issue form, in Tab order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CreateField {
+    Title,
+    IssueType,
+    Priority,
+    Description,
+    /// Comma-separated
+    Labels,
+}
+
+impl CreateField {
+    pub const ALL: [CreateField; 5] = [
+        CreateField::Title,
+        CreateField::IssueType,
+        CreateField::Priority,
+        CreateField::Description,
+        CreateField::Labels,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CreateField::Title => "Title",
+            CreateField::IssueType => "Type",
+            CreateField::Priority => "Priority",
+            CreateField::Description => "Description",
+            CreateField::Labels => "Labels",
+        }
+    }
+
+    /// Only the description takes several lines
+    pub fn is_multiline(self) -> bool {
+        self == CreateField::Description
+    }
+
+    fn default_value(self) -> &'static str {
+        match self {
+            CreateField::IssueType => "task",
+            CreateField::Priority => "2",
+            CreateField::Title | CreateField::Description | CreateField::Labels => "",
+        }
+    }
+}
+
+/// State for the new issue form (`a` / `A`)
+#[derive(Debug, Clone)]
+pub struct CreateForm {
+    /// Parent ID when creating a child issue
+    pub parent: Option<String>,
+    /// One input per field of `CreateField::ALL`
+    pub inputs: Vec<TextInput>,
+    /// Index of the focused field
+    pub current: usize,
+}
+
+impl CreateForm {
+    pub fn new(parent: Option<String>) -> Self {
+        let inputs = CreateField::ALL.iter()
+            .map(|field| TextInput::new(field.default_value().to_string()))
+            .collect();
+        CreateForm { parent, inputs, current: 0 }
+    }
+
+    pub fn current_field(&self) -> CreateField {
+        CreateField::ALL[self.current]
+    }
+
+    pub fn current_input_mut(&mut self) -> &mut TextInput {
+        &mut self.inputs[self.current]
+    }
+
+    pub fn next_field(&mut self) {
+        self.current = (self.current + 1) % self.inputs.len();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.current = (self.current + self.inputs.len() - 1) % self.inputs.len();
+    }
+
+    fn value(&self, field: CreateField) -> &str {
+        let pos = CreateField::ALL.iter().position(|&f| f == field).unwrap_or(0);
+        self.inputs[pos].buffer.trim()
+    }
+
+    /// Validate the form, returning a message for the first invalid field
+    pub fn to_new_issue(&self) -> Result<bd::NewIssue, String> {
+        let title = self.value(CreateField::Title);
+        if title.is_empty() {
+            return Err("Title is required".to_string());
+        }
+
+        let issue_type = self.value(CreateField::IssueType).to_lowercase();
+        if !ISSUE_TYPES.contains(&issue_type.as_str()) {
+            return Err(format!("Type must be one of: {}", ISSUE_TYPES.join(", ")));
+        }
+
+        let priority = self.value(CreateField::Priority);
+        let priority = priority.strip_prefix(['P', 'p']).unwrap_or(priority);
+        let priority = match priority.parse::<i32>() {
+            Ok(p) if (0..=4).contains(&p) => p,
+            _ => return Err("Priority must be 0-4".to_string()),
+        };
+
+        Ok(bd::NewIssue {
+            title: title.to_string(),
+            issue_type,
+            priority,
+            description: self.value(CreateField::Description).to_string(),
+            labels: self.value(CreateField::Labels)
+                .split(',')
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect(),
+            parent: self.parent.clone(),
+        })
     }
 }
 
 /// Result of background data loading
 struct DataLoadResult {
-    issues: Vec<bd::Issue>,
-    ready_ids: HashSet<String>,
+    /// The loaded issues, or the error message if loading failed
+    issues: Result<Vec<bd::Issue>, String>,
+    /// `bd ready` output or its error, only fetched when cross-checking is enabled
+    bd_ready_ids: Option<Result<HashSet<String>, String>>,
+}
+
+/// Load issues (and optionally `bd ready` for cross-checking) on a background thread
+fn spawn_data_load(backend: Arc<dyn IssueBackend>, check_ready: bool) -> mpsc::Receiver<DataLoadResult> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let issues = backend.list_issues_with_details().map_err(|e| e.to_string());
+        let bd_ready_ids = if check_ready {
+            Some(backend.get_ready_ids().map_err(|e| e.to_string()))
+        } else {
+            None
+        };
+        let _ = tx.send(DataLoadResult { issues, bd_ready_ids });
+    });
+    rx
 }
 
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
@@ -247,14 +1029,25 @@ use std::path::PathBuf;
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
+use backend::IssueBackend;
+use bd::TextField;
+use filter::Filter;
+use graph::DepGraph;
 use state::save_expanded;
-use tree::IssueTree;
+use stats::ProjectStats;
+use tree::{IssueTree, BOARD_STATUSES};
 
 struct App {
+    /// Where issues are loaded from and edits are written to
+    backend: Arc<dyn IssueBackend>,
+    /// Cross-check locally computed ready state against `bd ready` on each load
+    check_ready: bool,
     tree: IssueTree,
     should_quit: bool,
     show_help: bool,
     selected_details: Option<bd::Issue>,
+    /// The selected issue's place in the dependency graph, while in Deps mode
+    dep_summary: Option<ui::DepSummary>,
     last_selected_id: Option<String>,
     focus: Focus,
     detail_scroll: u16,
@@ -272,30 +1065,65 @@ struct App {
     is_loading: bool,
     /// Channel receiver for async data loading
     data_rx: Option<mpsc::Receiver<DataLoadResult>>,
+    /// Error from the most recent data load, shown until a load succeeds
+    load_error: Option<String>,
+    /// One-off feedback (e.g. a failed save), cleared on the next key press
+    status_message: Option<String>,
+    /// Active new issue form (None when not creating)
+    create_form: Option<CreateForm>,
+    /// Issue to select once the next load completes (e.g. one we just created)
+    pending_select: Option<String>,
+    /// Active one-line prompt (e.g. close reason)
+    prompt: Option<Prompt>,
+    /// Active priority/type/label picker
+    picker: Option<Picker>,
+    /// Active dependency manager
+    dep_editor: Option<DepEditor>,
+    /// Active Ctrl+P issue finder
+    finder: Option<IssueSearch>,
+    /// Active blocking-impact list
+    impact_list: Option<ImpactList>,
+    /// Active dependency cycle list
+    cycle_list: Option<CycleList>,
+    /// Kanban board, shown instead of the tree and details panels
+    board: Option<Board>,
+    /// Active dependency graph view
+    graph_view: Option<DepGraph>,
+    /// Dependency hops the graph view reaches from its centre
+    graph_radius: usize,
+    /// Active project statistics dashboard
+    stats: Option<ProjectStats>,
+    /// Named filters for this project, usable as `@name`
+    saved_filters: BTreeMap<String, String>,
 }
 
 impl App {
     /// Create app with async data loading - returns immediately with loading state
-    fn new_async() -> Self {
+    fn new_async(backend: Arc<dyn IssueBackend>, check_ready: bool) -> Self {
         let (expanded, dep_expanded, hierarchy_mode) = state::load_tree_state();
         let panel_ratio = state::load_panel_ratio();
 
+        let (filter, saved_filters) = state::load_filters();
+
         // Create empty tree initially
-        let tree = IssueTree::from_issues(vec![], expanded.clone(), dep_expanded.clone(), HashSet::new(), hierarchy_mode);
+        let mut tree = IssueTree::from_issues(vec![], expanded.clone(), dep_expanded.clone(), hierarchy_mode);
+        tree.filter = filter.and_then(|f| Filter::parse(&f, &saved_filters).ok());
+        let (sort_mode, sort_reverse) = state::load_sort();
+        tree.set_sort(sort_mode, sort_reverse);
+        tree.group_expanded = state::load_group_expanded();
+        tree.show_dep_refs = state::load_show_dep_refs();
 
         // Spawn background thread to load data
-        let (tx, rx) = mpsc::channel();
-        thread::spawn(move || {
-            let issues = bd::list_issues_with_details().unwrap_or_default();
-            let ready_ids = bd::get_ready_ids().unwrap_or_default();
-            let _ = tx.send(DataLoadResult { issues, ready_ids });
-        });
+        let rx = spawn_data_load(Arc::clone(&backend), check_ready);
 
         App {
+            backend,
+            check_ready,
             tree,
             should_quit: false,
             show_help: false,
             selected_details: None,
+            dep_summary: None,
             last_selected_id: None,
             focus: Focus::Tree,
             detail_scroll: 0,
@@ -306,6 +1134,21 @@ impl App {
             tree_scroll: 0,
             is_loading: true,
             data_rx: Some(rx),
+            load_error: None,
+            status_message: None,
+            create_form: None,
+            pending_select: None,
+            prompt: None,
+            picker: None,
+            dep_editor: None,
+            finder: None,
+            impact_list: None,
+            cycle_list: None,
+            board: None,
+            graph_view: None,
+            graph_radius: state::load_graph_radius(),
+            stats: None,
+            saved_filters,
         }
     }
 
@@ -313,9 +1156,27 @@ impl App {
     fn check_data_loaded(&mut self) {
         if let Some(rx) = &self.data_rx {
             if let Ok(result) = rx.try_recv() {
+                self.is_loading = false;
+                self.data_rx = None;
+
+                // Keep showing the previous data if loading failed
+                let issues = match result.issues {
+                    Ok(issues) => issues,
+                    Err(e) => {
+                        self.load_error = Some(e.trim().to_string());
+                        return;
+                    }
+                };
+                self.load_error = None;
+
                 // Preserve current state for refresh
-                let selected_id = self.tree.selected_id().map(|s| s.to_string());
+                let selected_id = self.tree.selected_item().map(|s| s.to_string());
                 let show_closed = self.tree.show_closed;
+                let show_dep_refs = self.tree.show_dep_refs;
+                let search = self.tree.search.take();
+                let filter = self.tree.filter.take();
+                let (sort_mode, sort_reverse) = (self.tree.sort_mode, self.tree.sort_reverse);
+                let group_expanded = std::mem::take(&mut self.tree.group_expanded);
                 let has_existing_tree = !self.tree.visible_items.is_empty();
 
                 // Use current expanded state if we have an existing tree (refresh),
@@ -328,14 +1189,27 @@ impl App {
                 };
 
                 self.tree = IssueTree::from_issues(
-                    result.issues,
+                    issues,
                     expanded,
                     dep_expanded,
-                    result.ready_ids,
                     self.hierarchy_mode,
                 );
+                match &result.bd_ready_ids {
+                    Some(Ok(bd_ready_ids)) => self.tree.cross_check_ready(bd_ready_ids),
+                    Some(Err(e)) => {
+                        self.status_message = Some(format!("bd ready failed: {}", e.trim()));
+                    }
+                    None => {}
+                }
                 self.tree.show_closed = show_closed;
-                self.tree.rebuild_visible();
+                self.tree.show_dep_refs = show_dep_refs;
+                self.tree.group_expanded = group_expanded;
+                self.tree.search = search;
+                self.tree.filter = filter;
+                self.tree.set_sort(sort_mode, sort_reverse);
+                if !has_existing_tree && self.hierarchy_mode == HierarchyMode::Timeline {
+                    self.tree.expand_recent_days();
+                }
 
                 // Restore cursor to previously selected item if it still exists
                 if let Some(id) = selected_id {
@@ -344,12 +1218,24 @@ impl App {
                     }
                 }
 
+                // Select a newly created issue, revealing it if it's nested
+                if let Some(id) = self.pending_select.take() {
+                    if self.tree.select_issue(&id) {
+                        self.save_expanded();
+                    }
+                }
+
+                // Redraw an open graph view from the new data
+                if let Some(center) = self.graph_view.take().map(|g| g.center) {
+                    self.open_graph_view(&center);
+                }
+                if self.stats.is_some() {
+                    self.open_stats();
+                }
+
                 // Force refresh of selected details
                 self.last_selected_id = None;
                 self.update_selected_details();
-
-                self.is_loading = false;
-                self.data_rx = None;
             }
         }
     }
@@ -358,9 +1244,13 @@ impl App {
         let current_id = self.tree.selected_id().map(|s| s.to_string());
         if current_id != self.last_selected_id {
             self.selected_details = current_id.as_ref()
-                .and_then(|id| bd::get_issue_details(id).ok().flatten());
+                .and_then(|id| self.backend.get_issue_details(id).ok().flatten());
             self.last_selected_id = current_id;
             self.detail_scroll = 0; // Reset scroll when selection changes
+            self.dep_summary = None;
+        }
+        if self.dep_summary.is_none() && self.hierarchy_mode == HierarchyMode::DependencyBased {
+            self.dep_summary = self.last_selected_id.as_deref().map(|id| ui::DepSummary::new(&self.tree, id));
         }
     }
 
@@ -377,15 +1267,8 @@ impl App {
         }
 
         // Spawn background thread to load data
-        let (tx, rx) = mpsc::channel();
-        thread::spawn(move || {
-            let issues = bd::list_issues_with_details().unwrap_or_default();
-            let ready_ids = bd::get_ready_ids().unwrap_or_default();
-            let _ = tx.send(DataLoadResult { issues, ready_ids });
-        });
-
         self.is_loading = true;
-        self.data_rx = Some(rx);
+        self.data_rx = Some(spawn_data_load(Arc::clone(&self.backend), self.check_ready));
     }
 
     /// Check if we're currently in edit mode
@@ -394,12 +1277,61 @@ impl App {
     }
 
     /// Toggle between ID-based and Dependency-based hierarchy views
+    /// (leaving a grouped view goes back to the Epics view)
     fn toggle_hierarchy_mode(&mut self) {
-        self.hierarchy_mode = match self.hierarchy_mode {
+        let mode = match self.hierarchy_mode {
             HierarchyMode::IdBased => HierarchyMode::DependencyBased,
-            HierarchyMode::DependencyBased => HierarchyMode::IdBased,
+            HierarchyMode::DependencyBased | HierarchyMode::Grouped(_) | HierarchyMode::ReadyQueue | HierarchyMode::Timeline => HierarchyMode::IdBased,
+        };
+        self.set_hierarchy_mode(mode);
+    }
+
+    /// Toggle the ready queue, going back to the Epics view when leaving it
+    fn toggle_ready_queue(&mut self) {
+        let mode = match self.hierarchy_mode {
+            HierarchyMode::ReadyQueue => HierarchyMode::IdBased,
+            _ => HierarchyMode::ReadyQueue,
+        };
+        self.set_hierarchy_mode(mode);
+    }
+
+    /// Toggle the activity timeline, opening it on today's and yesterday's activity
+    fn toggle_timeline(&mut self) {
+        if self.hierarchy_mode == HierarchyMode::Timeline {
+            self.set_hierarchy_mode(HierarchyMode::IdBased);
+            return;
+        }
+        self.tree.set_now(activity::now());
+        self.set_hierarchy_mode(HierarchyMode::Timeline);
+        self.tree.expand_recent_days();
+        self.save_expanded();
+    }
+
+    /// Save the expansion state changed from the tree panel
+    fn save_expanded(&self) {
+        let _ = match self.hierarchy_mode {
+            HierarchyMode::Grouped(_) | HierarchyMode::Timeline => state::save_group_expanded(&self.tree.group_expanded),
+            _ => save_expanded(&self.tree.expanded),
+        };
+    }
+
+    /// Cycle through the grouped views: status, type, label, priority, assignee,
+    /// then back to the Epics view
+    fn cycle_group_by(&mut self) {
+        let mode = match self.hierarchy_mode {
+            HierarchyMode::Grouped(group_by) => {
+                let pos = GroupBy::ALL.iter().position(|g| *g == group_by).unwrap_or(0);
+                GroupBy::ALL.get(pos + 1).map_or(HierarchyMode::IdBased, |g| HierarchyMode::Grouped(*g))
+            }
+            _ => HierarchyMode::Grouped(GroupBy::ALL[0]),
         };
-        self.tree.set_hierarchy_mode(self.hierarchy_mode);
+        self.set_hierarchy_mode(mode);
+    }
+
+    fn set_hierarchy_mode(&mut self, mode: HierarchyMode) {
+        self.hierarchy_mode = mode;
+        self.tree.set_hierarchy_mode(mode);
+        self.dep_summary = None;
         // Save the updated mode
         let _ = state::save_tree_state(
             &self.tree.expanded,
@@ -408,6 +1340,13 @@ impl App {
         );
     }
 
+    /// Change the tree's sort order and remember it
+    fn set_sort(&mut self, mode: SortMode, reverse: bool) {
+        self.tree.set_sort(mode, reverse);
+        let _ = state::save_sort(mode, reverse);
+        self.status_message = Some(format!("Sorted by {}{}", mode.name(), if reverse { ", reversed" } else { "" }));
+    }
+
     /// Copy the current issue to clipboard
     fn copy_issue_to_clipboard(&self) -> Result<(), String> {
         if let Some(issue) = &self.selected_details {
@@ -431,6 +1370,9 @@ impl App {
             let value = match field {
                 EditField::Title => issue.title.clone(),
                 EditField::Description => issue.description.clone().unwrap_or_default(),
+                EditField::Design => issue.design.clone().unwrap_or_default(),
+                EditField::AcceptanceCriteria => issue.acceptance_criteria.clone().unwrap_or_default(),
+                EditField::Notes => issue.notes.clone().unwrap_or_default(),
             };
             self.edit_state = Some(EditState::new(
                 issue.id.clone(),
@@ -441,39 +1383,436 @@ impl App {
         }
     }
 
-    /// Cancel editing and discard changes
-    fn cancel_edit(&mut self) {
-        self.edit_state = None;
+    /// Cancel editing and discard changes
+    fn cancel_edit(&mut self) {
+        self.edit_state = None;
+    }
+
+    /// Save the current edit using bd update
+    fn save_edit(&mut self) -> Result<()> {
+        if let Some(ref edit) = self.edit_state {
+            if edit.is_modified() {
+                self.backend.update_text_field(&edit.issue_id, edit.field.into(), &edit.input.buffer)?;
+                // Refresh to pick up the changes
+                self.last_selected_id = None; // Force refresh of details
+                self.update_selected_details();
+            }
+        }
+        self.edit_state = None;
+        Ok(())
+    }
+
+    /// Open the new issue form, as a child of the selected issue if `child` is set
+    fn start_create(&mut self, child: bool) {
+        let parent = if child {
+            match self.tree.selected_id() {
+                Some(id) => Some(id.to_string()),
+                None => return,
+            }
+        } else {
+            None
+        };
+        self.create_form = Some(CreateForm::new(parent));
+        self.focus = Focus::Details;
+    }
+
+    /// Create the issue described by the form, then select it after the refresh
+    fn submit_create(&mut self) {
+        let Some(form) = &self.create_form else { return };
+        let new_issue = match form.to_new_issue() {
+            Ok(new_issue) => new_issue,
+            Err(e) => {
+                self.status_message = Some(e);
+                return;
+            }
+        };
+        match self.backend.create_issue(&new_issue) {
+            Ok(id) => {
+                self.status_message = Some(format!("Created {}", id));
+                self.create_form = None;
+                self.focus = Focus::Tree;
+                self.pending_select = Some(id);
+                self.refresh();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Create failed: {}", e.to_string().trim()));
+            }
+        }
+    }
+
+    /// Ask for a close reason, then close the selected issue
+    fn start_close(&mut self) {
+        if let Some(id) = self.tree.selected_id() {
+            self.prompt = Some(Prompt::new(PromptKind::CloseReason { issue_id: id.to_string() }));
+        }
+    }
+
+    /// Set the selected issue's status (close, reopen, in progress) through the backend,
+    /// then apply it to the tree right away so colors and closed-issue hiding update
+    /// without waiting for the refresh
+    fn change_status(&mut self, id: &str, status: &str, reason: Option<&str>) {
+        let result = if status == "closed" {
+            self.backend.close_issue(id, reason)
+        } else {
+            self.backend.update_issue_status(id, status)
+        };
+        if let Err(e) = result {
+            self.status_message = Some(format!("Status change failed: {}", e.to_string().trim()));
+            return;
+        }
+
+        self.apply_local_update(id, |issue| issue.status = status.to_string());
+        self.status_message = Some(match status {
+            "closed" => format!("Closed {}", id),
+            "open" => format!("Reopened {}", id),
+            _ => format!("{} is now {}", id, status),
+        });
+        self.refresh();
+    }
+
+    /// Apply a change bd has accepted to the tree and the details panel right away,
+    /// rather than waiting for the refresh
+    fn apply_local_update(&mut self, id: &str, f: impl Fn(&mut bd::Issue)) {
+        self.tree.update_issue(id, &f);
+        self.dep_summary = None;
+        if let Some(details) = self.selected_details.as_mut().filter(|d| d.id == id) {
+            f(details);
+        }
+    }
+
+    /// Open a picker for the selected issue
+    fn open_picker(&mut self, kind: PickerKind) {
+        let Some(issue) = self.tree.selected_node().map(|n| &n.issue) else { return };
+        self.picker = Some(match kind {
+            PickerKind::Priority => Picker::priority(issue),
+            PickerKind::IssueType => Picker::issue_type(issue),
+            PickerKind::Labels => {
+                let known = self.tree.nodes.values()
+                    .flat_map(|n| n.issue.labels.iter().flatten().cloned());
+                Picker::labels(issue, known)
+            }
+        });
+    }
+
+    /// Write the picker's choice back through the backend
+    fn apply_picker(&mut self) {
+        let Some(picker) = self.picker.take() else { return };
+        let id = picker.issue_id.clone();
+        let result = match picker.kind {
+            PickerKind::Priority => {
+                let priority = picker.cursor as i32;
+                self.backend.update_issue_priority(&id, priority)
+                    .map(|_| self.apply_local_update(&id, |i| i.priority = priority))
+            }
+            PickerKind::IssueType => {
+                let issue_type = picker.options[picker.cursor].clone();
+                self.backend.update_issue_type(&id, &issue_type)
+                    .map(|_| self.apply_local_update(&id, |i| i.issue_type = issue_type.clone()))
+            }
+            PickerKind::Labels => {
+                // One bd call per label; keep the ones that went through if a later one fails
+                let mut labels = picker.original.clone();
+                let mut result = Ok(());
+                for label in picker.checked.symmetric_difference(&picker.original) {
+                    let adding = picker.checked.contains(label);
+                    let change = if adding {
+                        self.backend.add_label(&id, label)
+                    } else {
+                        self.backend.remove_label(&id, label)
+                    };
+                    if let Err(e) = change {
+                        result = Err(e);
+                        break;
+                    }
+                    if adding {
+                        labels.insert(label.clone());
+                    } else {
+                        labels.remove(label);
+                    }
+                }
+                let labels: Vec<String> = labels.into_iter().collect();
+                self.apply_local_update(&id, |i| {
+                    i.labels = (!labels.is_empty()).then(|| labels.clone())
+                });
+                result
+            }
+        };
+        if let Err(e) = result {
+            self.status_message = Some(format!("Update failed: {}", e.to_string().trim()));
+        }
+        // Reload even after a failure, in case some of the changes were made
+        self.refresh();
+    }
+
+    /// Open the dependency manager for the selected issue
+    fn open_dep_editor(&mut self) {
+        let Some(id) = self.tree.selected_id() else { return };
+        // Prefer the loaded details, which are fresher after an edit
+        let issue = match self.selected_details.as_ref().filter(|d| d.id == id) {
+            Some(details) => details,
+            None => &self.tree.nodes[id].issue,
+        };
+        self.dep_editor = Some(DepEditor::new(issue, self.tree.nodes.values().map(|n| &n.issue)));
+    }
+
+    /// Select `id` in the tree, expanding its ancestors. Issues hidden by the closed
+    /// toggle or the filter are revealed by showing closed issues or clearing the filter
+    /// for this session; the saved filter is left alone.
+    fn go_to_issue(&mut self, id: &str) {
+        let mut found = self.tree.select_issue(id);
+        let mut revealed = Vec::new();
+        let is_closed = self.tree.nodes.get(id).is_some_and(|n| n.issue.status == "closed");
+        if !found && is_closed && !self.tree.show_closed {
+            self.tree.toggle_show_closed();
+            found = self.tree.select_issue(id);
+            revealed.push("closed shown");
+        }
+        if !found && self.tree.filter.is_some() {
+            self.tree.filter = None;
+            found = self.tree.select_issue(id);
+            revealed.push("filter off for now");
+        }
+        if found {
+            let _ = state::save_tree_state(&self.tree.expanded, &self.tree.dep_expanded, self.hierarchy_mode);
+            if !revealed.is_empty() {
+                self.status_message = Some(format!("Revealed {}: {}", id, revealed.join(", ")));
+            }
+        } else {
+            self.status_message = Some(format!("Can't show {} in this view", id));
+        }
+    }
+
+    /// Add the highlighted search result as a dependency, refusing edges that would
+    /// create a cycle (the Deps view would have to drop them)
+    fn add_dependency(&mut self) {
+        let Some(editor) = self.dep_editor.as_mut() else { return };
+        let Some(target) = editor.current_match() else { return };
+        let id = editor.issue_id.clone();
+        let dep_type = editor.dep_type_name();
+
+        if let Some(cycle) = self.tree.dependency_cycle(&id, &target, dep_type) {
+            editor.error = Some(format!("Would create a cycle: {}", cycle.join(" → ")));
+            return;
+        }
+        if let Err(e) = self.backend.add_dependency(&id, &target, dep_type) {
+            editor.error = Some(format!("Add failed: {}", e.to_string().trim()));
+            return;
+        }
+
+        let dep = bd::Dependency {
+            title: self.tree.nodes.get(&target).map(|n| n.issue.title.clone()).unwrap_or_default(),
+            id: target.clone(),
+            dependency_type: Some(dep_type.to_string()),
+        };
+        editor.deps.push(dep.clone());
+        editor.stop_search();
+        editor.cursor = editor.deps.len() - 1;
+        self.apply_local_update(&id, |i| i.dependencies.get_or_insert_with(Vec::new).push(dep.clone()));
+        self.status_message = Some(format!("Added dependency on {} ({})", target, dep_type));
+        self.refresh();
+    }
+
+    /// Remove the highlighted dependency
+    fn remove_dependency(&mut self) {
+        let Some(editor) = self.dep_editor.as_mut() else { return };
+        let Some(target) = editor.current_dep().map(|d| d.id.clone()) else { return };
+        let id = editor.issue_id.clone();
+
+        if let Err(e) = self.backend.remove_dependency(&id, &target) {
+            editor.error = Some(format!("Remove failed: {}", e.to_string().trim()));
+            return;
+        }
+
+        editor.deps.retain(|d| d.id != target);
+        editor.error = None;
+        editor.clamp_cursor();
+        self.apply_local_update(&id, |i| {
+            if let Some(deps) = i.dependencies.as_mut() {
+                deps.retain(|d| d.id != target);
+            }
+        });
+        self.status_message = Some(format!("Removed dependency on {}", target));
+        self.refresh();
+    }
+
+    /// Set the selected issue's status, if anything is selected
+    fn change_selected_status(&mut self, status: &str) {
+        if let Some(id) = self.tree.selected_id().map(|s| s.to_string()) {
+            self.change_status(&id, status, None);
+        }
+    }
+
+    /// Act on the submitted prompt text
+    fn submit_prompt(&mut self) {
+        let Some(prompt) = self.prompt.take() else { return };
+        let text = prompt.input.buffer.trim();
+        match &prompt.kind {
+            PromptKind::CloseReason { issue_id } => {
+                let reason = (!text.is_empty()).then_some(text);
+                self.change_status(issue_id, "closed", reason);
+            }
+            // Keep the search active for n/N, unless it was left empty
+            PromptKind::Search { .. } if text.is_empty() => self.tree.search = None,
+            PromptKind::Search { .. } => {}
+            PromptKind::Filter { .. } => {
+                if let Err(e) = self.set_filter(text) {
+                    // Leave the prompt open to fix the expression
+                    self.prompt = Some(Prompt { error: Some(e), ..prompt });
+                }
+            }
+            PromptKind::SaveFilter => self.save_filter_as(text),
+        }
+    }
+
+    /// Apply a filter expression to the tree (empty clears it) and remember it
+    fn set_filter(&mut self, text: &str) -> Result<(), String> {
+        let filter = if text.is_empty() {
+            None
+        } else {
+            Some(Filter::parse(text, &self.saved_filters)?)
+        };
+        let selected = self.tree.selected_id().map(|s| s.to_string());
+        self.tree.filter = filter;
+        self.tree.rebuild_visible();
+        if let Some(pos) = selected.and_then(|id| self.tree.visible_items.iter().position(|x| *x == id)) {
+            self.tree.cursor = pos;
+        }
+        let _ = state::save_filter(self.tree.filter.as_ref().map(|f| f.source.as_str()));
+        Ok(())
+    }
+
+    /// Save the active filter as `@name`, or delete a saved filter with `-name`
+    fn save_filter_as(&mut self, name: &str) {
+        if let Some(name) = name.strip_prefix('-') {
+            self.status_message = Some(match self.saved_filters.remove(name) {
+                Some(_) => format!("Deleted filter @{}", name),
+                None => format!("No saved filter @{}", name),
+            });
+        } else if name.is_empty() || name.contains(char::is_whitespace) || name.contains('@') {
+            self.status_message = Some("Filter names can't be empty or contain spaces or @".to_string());
+            return;
+        } else if let Some(filter) = &self.tree.filter {
+            self.saved_filters.insert(name.to_string(), filter.source.clone());
+            self.status_message = Some(format!("Saved filter @{}", name));
+        } else {
+            self.status_message = Some("No active filter to save (f sets one)".to_string());
+            return;
+        }
+        let _ = state::save_saved_filters(&self.saved_filters);
+    }
+
+    /// Start a `/` search from the selected issue
+    fn start_search(&mut self) {
+        let origin = self.tree.selected_id().map(|s| s.to_string());
+        self.prompt = Some(Prompt::new(PromptKind::Search { origin }));
+    }
+
+    /// Search as you type: jump to the first match at or after where the search began
+    fn update_search(&mut self) {
+        let Some(Prompt { kind: PromptKind::Search { origin }, input, .. }) = &self.prompt else { return };
+        let text = input.buffer.trim().to_string();
+        let origin = origin.clone();
+
+        self.tree.search = (!text.is_empty()).then(|| text.to_lowercase());
+        if let Some(origin) = &origin {
+            self.tree.select_issue(origin);
+        }
+        if self.tree.search.is_some() && !self.jump_to_match(true, true) {
+            self.status_message = Some(format!("No matches for {}", text));
+        }
+    }
+
+    /// Abandon a `/` search, returning to the issue selected before it
+    fn cancel_search(&mut self) {
+        if let Some(Prompt { kind: PromptKind::Search { origin: Some(origin) }, .. }) = &self.prompt {
+            let origin = origin.clone();
+            self.tree.select_issue(&origin);
+        }
+        self.tree.search = None;
+        self.prompt = None;
     }
 
-    /// Save the current edit using bd update
-    fn save_edit(&mut self) -> Result<()> {
-        if let Some(ref edit) = self.edit_state {
-            if edit.is_modified() {
-                match edit.field {
-                    EditField::Title => {
-                        bd::update_issue_title(&edit.issue_id, &edit.buffer)?;
-                    }
-                    EditField::Description => {
-                        bd::update_issue_description(&edit.issue_id, &edit.buffer)?;
-                    }
-                }
-                // Refresh to pick up the changes
-                self.last_selected_id = None; // Force refresh of details
-                self.update_selected_details();
-            }
+    /// Jump to the next/previous search match, saving any ancestors expanded to reveal it
+    fn jump_to_match(&mut self, forward: bool, include_current: bool) -> bool {
+        let found = self.tree.jump_to_match(forward, include_current);
+        if found {
+            let _ = state::save_tree_state(&self.tree.expanded, &self.tree.dep_expanded, self.hierarchy_mode);
+        }
+        found
+    }
+
+    /// Warnings and messages for the bottom of the tree panel
+    fn status_note(&self) -> Option<String> {
+        let notes: Vec<String> = [
+            self.load_error.clone(),
+            self.status_message.clone(),
+            self.backend.status_note(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!notes.is_empty()).then(|| notes.join("  "))
+    }
+
+    /// Save the current edit, reporting failures in the status line
+    fn save_edit_or_report(&mut self) {
+        if let Err(e) = self.save_edit() {
+            self.status_message = Some(format!("Save failed: {}", e.to_string().trim()));
         }
-        self.edit_state = None;
-        Ok(())
     }
 
     fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        self.status_message = None;
+
+        // Pickers, prompts and the new issue form capture all keys while open
+        if self.finder.is_some() {
+            self.handle_finder_key(code, modifiers);
+            return;
+        }
+        if self.impact_list.is_some() {
+            self.handle_impact_key(code, modifiers);
+            return;
+        }
+        if self.cycle_list.is_some() {
+            self.handle_cycle_key(code, modifiers);
+            return;
+        }
+        if self.graph_view.is_some() {
+            self.handle_graph_key(code, modifiers);
+            return;
+        }
+        if self.stats.is_some() {
+            self.handle_stats_key(code, modifiers);
+            return;
+        }
+        if self.dep_editor.is_some() {
+            self.handle_dep_key(code, modifiers);
+            return;
+        }
+        if self.picker.is_some() {
+            self.handle_picker_key(code, modifiers);
+            return;
+        }
+        if self.prompt.is_some() {
+            self.handle_prompt_key(code, modifiers);
+            return;
+        }
+        if self.create_form.is_some() {
+            self.handle_create_key(code, modifiers);
+            return;
+        }
+
         // If in edit mode, handle edit keys first
         if self.is_editing() {
             self.handle_edit_key(code, modifiers);
             return;
         }
 
+        // The board has its own movement keys; a few global keys still work
+        if self.board.is_some() && self.handle_board_key(code, modifiers) {
+            return;
+        }
+
         // Handle focus-independent keys first
         match (code, modifiers) {
             // Quit
@@ -490,10 +1829,12 @@ impl App {
                 return;
             }
 
-            // Escape - close help or return to tree
+            // Escape - close help, clear the search, or return to tree
             (KeyCode::Esc, KeyModifiers::NONE) => {
                 if self.show_help {
                     self.show_help = false;
+                } else if self.tree.search.is_some() {
+                    self.tree.search = None;
                 } else {
                     self.focus = Focus::Tree;
                 }
@@ -506,18 +1847,182 @@ impl App {
                 return;
             }
 
+            // Go to any issue by fuzzy search
+            (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.finder = Some(IssueSearch::new(self.tree.nodes.values().map(|n| &n.issue)));
+                return;
+            }
+
             // Toggle show/hide closed (works from either panel)
             (KeyCode::Char('c'), KeyModifiers::NONE) => {
                 self.tree.toggle_show_closed();
                 return;
             }
 
+            // Cycle the sort order / reverse it
+            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+                self.set_sort(self.tree.sort_mode.next(), self.tree.sort_reverse);
+                return;
+            }
+            (KeyCode::Char('S'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('S'), KeyModifiers::NONE) => {
+                self.set_sort(self.tree.sort_mode, !self.tree.sort_reverse);
+                return;
+            }
+
+            // Cycle the grouped views
+            (KeyCode::Char('v'), KeyModifiers::NONE) => {
+                self.cycle_group_by();
+                return;
+            }
+
             // Toggle hierarchy mode (ID-based vs Dependency-based)
             (KeyCode::Char('d'), KeyModifiers::NONE) => {
                 self.toggle_hierarchy_mode();
                 return;
             }
 
+            // Repeat multi-parent issues under each blocker in the Deps view
+            (KeyCode::Char('m'), KeyModifiers::NONE) => {
+                self.tree.toggle_dep_refs();
+                let _ = state::save_show_dep_refs(self.tree.show_dep_refs);
+                self.status_message = Some(match (self.tree.show_dep_refs, self.hierarchy_mode) {
+                    (true, HierarchyMode::DependencyBased) => "Showing every occurrence of multi-parent issues",
+                    (true, _) => "Multi-parent issues will repeat in the Deps view (d)",
+                    (false, _) => "Showing multi-parent issues once",
+                }.to_string());
+                return;
+            }
+
+            // Kanban board
+            (KeyCode::Char('b'), KeyModifiers::NONE) => {
+                self.board = Some(Board::new(&self.tree.board_columns(), self.tree.selected_id()));
+                return;
+            }
+
+            // Toggle the ready queue
+            (KeyCode::Char('R'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('R'), KeyModifiers::NONE) => {
+                self.toggle_ready_queue();
+                return;
+            }
+
+            // Toggle the activity timeline
+            (KeyCode::Char('T'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('T'), KeyModifiers::NONE) => {
+                self.toggle_timeline();
+                return;
+            }
+
+            // Status changes: close (asks for a reason), reopen, in progress
+            (KeyCode::Char('x'), KeyModifiers::NONE) => {
+                self.start_close();
+                return;
+            }
+            (KeyCode::Char('o'), KeyModifiers::NONE) => {
+                self.change_selected_status("open");
+                return;
+            }
+            (KeyCode::Char('w'), KeyModifiers::NONE) => {
+                self.change_selected_status("in_progress");
+                return;
+            }
+
+            // Pickers: priority, type, labels
+            (KeyCode::Char('P'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('P'), KeyModifiers::NONE) => {
+                self.open_picker(PickerKind::Priority);
+                return;
+            }
+            (KeyCode::Char('t'), KeyModifiers::NONE) => {
+                self.open_picker(PickerKind::IssueType);
+                return;
+            }
+            (KeyCode::Char('L'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('L'), KeyModifiers::NONE) => {
+                self.open_picker(PickerKind::Labels);
+                return;
+            }
+
+            // Dependency manager
+            (KeyCode::Char('D'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('D'), KeyModifiers::NONE) => {
+                self.open_dep_editor();
+                return;
+            }
+
+            // Issues that unblock the most downstream work
+            (KeyCode::Char('I'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('I'), KeyModifiers::NONE) => {
+                self.impact_list = Some(ImpactList::new(&self.tree));
+                return;
+            }
+
+            // Dependency cycles
+            (KeyCode::Char('C'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('C'), KeyModifiers::NONE) => {
+                self.cycle_list = Some(CycleList::new(&self.tree));
+                return;
+            }
+
+            // Project statistics
+            (KeyCode::Char('#'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('#'), KeyModifiers::NONE) => {
+                self.open_stats();
+                return;
+            }
+
+            // Dependency graph around the selected issue
+            (KeyCode::Char('V'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('V'), KeyModifiers::NONE) => {
+                if let Some(id) = self.tree.selected_id().map(|s| s.to_string()) {
+                    self.open_graph_view(&id);
+                }
+                return;
+            }
+
+            // Search
+            (KeyCode::Char('/'), KeyModifiers::NONE) => {
+                self.start_search();
+                return;
+            }
+
+            // Filter the tree / save the filter by name
+            (KeyCode::Char('f'), KeyModifiers::NONE) => {
+                let current = self.tree.filter.as_ref().map(|f| f.source.clone()).unwrap_or_default();
+                let saved = self.saved_filters.keys().cloned().collect();
+                self.prompt = Some(Prompt::with_text(PromptKind::Filter { saved }, &current));
+                return;
+            }
+            (KeyCode::Char('F'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('F'), KeyModifiers::NONE) => {
+                self.prompt = Some(Prompt::new(PromptKind::SaveFilter));
+                return;
+            }
+
+            // n/N jump to the next/previous search match
+            (KeyCode::Char('n'), KeyModifiers::NONE) |
+            (KeyCode::Char('N'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('N'), KeyModifiers::NONE) => {
+                if self.tree.search.is_none() {
+                    self.status_message = Some("No active search (/ starts one)".to_string());
+                } else if !self.jump_to_match(code == KeyCode::Char('n'), false) {
+                    self.status_message = Some("No matches (Esc clears the search)".to_string());
+                }
+                return;
+            }
+
+            // Add an issue / a child of the selected issue
+            (KeyCode::Char('a'), KeyModifiers::NONE) => {
+                self.start_create(false);
+                return;
+            }
+            (KeyCode::Char('A'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('A'), KeyModifiers::NONE) => {
+                self.start_create(true);
+                return;
+            }
+
             _ => {}
         }
 
@@ -538,119 +2043,367 @@ impl App {
             // Ctrl+S or Ctrl+Enter saves
             (KeyCode::Char('s'), KeyModifiers::CONTROL) |
             (KeyCode::Enter, KeyModifiers::CONTROL) => {
-                let _ = self.save_edit();
+                self.save_edit_or_report();
             }
 
             // Enter in title field saves and moves to description
-            // Enter in description field inserts newline
+            // Enter in the long-form fields inserts newline
             (KeyCode::Enter, KeyModifiers::NONE) => {
                 if let Some(ref mut edit) = self.edit_state {
-                    match edit.field {
-                        EditField::Title => {
-                            // Save title and start editing description
-                            let _ = self.save_edit();
-                            self.start_edit(EditField::Description);
-                        }
-                        EditField::Description => {
-                            // Insert newline in description
-                            edit.insert_char('\n');
-                        }
+                    if edit.field.is_multiline() {
+                        edit.input.insert_char('\n');
+                    } else if edit.field == EditField::Title {
+                        // Save title and start editing description
+                        self.save_edit_or_report();
+                        self.start_edit(EditField::Description);
                     }
                 }
             }
 
-            // Backspace deletes character before cursor
-            (KeyCode::Backspace, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.delete_char_before();
+            // Tab / Shift+Tab: save and move to the next / previous field
+            // (title -> description -> design -> acceptance criteria -> notes)
+            (KeyCode::Tab, KeyModifiers::NONE) |
+            (KeyCode::BackTab, KeyModifiers::SHIFT) |
+            (KeyCode::BackTab, KeyModifiers::NONE) => {
+                if let Some(field) = self.edit_state.as_ref().map(|e| e.field) {
+                    self.save_edit_or_report();
+                    // Stay put if the save failed, so the edit isn't lost
+                    if self.edit_state.is_none() {
+                        self.start_edit(field.cycle(code == KeyCode::Tab));
+                    }
                 }
             }
 
-            // Delete key deletes character at cursor
-            (KeyCode::Delete, KeyModifiers::NONE) => {
+            // Typing, deletion and cursor movement
+            _ => {
                 if let Some(ref mut edit) = self.edit_state {
-                    edit.delete_char_at();
+                    edit.input.handle_text_key(code, modifiers, edit.field.is_multiline());
                 }
             }
+        }
+    }
 
-            // Arrow keys for cursor movement
-            (KeyCode::Left, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.move_left();
-                }
+    fn handle_picker_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(picker) = self.picker.as_mut() else { return };
+        let is_labels = picker.kind == PickerKind::Labels;
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) => self.picker = None,
+            (KeyCode::Up, KeyModifiers::NONE) => picker.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) => picker.move_down(),
+
+            // Single choice: j/k move, 0-4 picks a priority directly, Enter applies
+            (KeyCode::Char('k'), KeyModifiers::NONE) if !is_labels => picker.move_up(),
+            (KeyCode::Char('j'), KeyModifiers::NONE) if !is_labels => picker.move_down(),
+            (KeyCode::Char(c @ '0'..='4'), KeyModifiers::NONE) if picker.kind == PickerKind::Priority => {
+                picker.cursor = c.to_digit(10).unwrap_or(0) as usize;
+                self.apply_picker();
             }
-            (KeyCode::Right, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.move_right();
+            (KeyCode::Enter, KeyModifiers::NONE) if !is_labels => self.apply_picker(),
+
+            // Label editor: Space toggles, Tab completes, Enter adds/toggles the typed
+            // label or applies the changes when nothing is typed
+            (KeyCode::Char(' '), KeyModifiers::NONE) if is_labels => picker.toggle_current(),
+            (KeyCode::Tab, KeyModifiers::NONE) if is_labels => picker.complete(),
+            (KeyCode::Enter, KeyModifiers::NONE) if is_labels => {
+                if picker.input.buffer.trim().is_empty() {
+                    self.apply_picker();
+                } else {
+                    picker.submit_input();
                 }
             }
-            (KeyCode::Up, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    if edit.field == EditField::Description {
-                        edit.move_up();
+            _ if is_labels => {
+                picker.input.handle_text_key(code, modifiers, false);
+                picker.clamp_cursor();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_dep_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(editor) = self.dep_editor.as_mut() else { return };
+        match (code, modifiers) {
+            (KeyCode::Up, KeyModifiers::NONE) => editor.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) => editor.move_down(),
+            _ if editor.search.is_some() => match (code, modifiers) {
+                // Searching: type to filter, Tab picks the dependency type, Enter adds
+                (KeyCode::Esc, KeyModifiers::NONE) => editor.stop_search(),
+                (KeyCode::Tab, KeyModifiers::NONE) => editor.cycle_type(),
+                (KeyCode::Enter, KeyModifiers::NONE) => self.add_dependency(),
+                _ => {
+                    if let Some(search) = editor.search.as_mut() {
+                        search.handle_text_key(code, modifiers);
                     }
                 }
+            },
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('q'), KeyModifiers::NONE) => self.dep_editor = None,
+            (KeyCode::Char('k'), KeyModifiers::NONE) => editor.move_up(),
+            (KeyCode::Char('j'), KeyModifiers::NONE) => editor.move_down(),
+            (KeyCode::Char('a'), KeyModifiers::NONE) => editor.start_search(),
+            (KeyCode::Char('x'), KeyModifiers::NONE) |
+            (KeyCode::Delete, KeyModifiers::NONE) => self.remove_dependency(),
+            _ => {}
+        }
+    }
+
+    fn handle_finder_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(finder) = self.finder.as_mut() else { return };
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) => self.finder = None,
+            (KeyCode::Up, KeyModifiers::NONE) |
+            (KeyCode::Char('p'), KeyModifiers::CONTROL) => finder.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) |
+            (KeyCode::Char('n'), KeyModifiers::CONTROL) => finder.move_down(),
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                let target = finder.current().map(|id| id.to_string());
+                self.finder = None;
+                if let Some(id) = target {
+                    self.go_to_issue(&id);
+                    self.focus = Focus::Tree;
+                }
             }
-            (KeyCode::Down, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    if edit.field == EditField::Description {
-                        edit.move_down();
-                    }
+            _ => {
+                finder.handle_text_key(code, modifiers);
+            }
+        }
+    }
+
+    fn handle_impact_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(list) = self.impact_list.as_mut() else { return };
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('q'), KeyModifiers::NONE) => self.impact_list = None,
+            (KeyCode::Up, KeyModifiers::NONE) |
+            (KeyCode::Char('k'), KeyModifiers::NONE) => list.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) |
+            (KeyCode::Char('j'), KeyModifiers::NONE) => list.move_down(),
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                let target = list.current().map(|id| id.to_string());
+                self.impact_list = None;
+                if let Some(id) = target {
+                    self.go_to_issue(&id);
+                    self.focus = Focus::Tree;
                 }
             }
+            _ => {}
+        }
+    }
 
-            // Home/End for line navigation
-            (KeyCode::Home, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.move_to_line_start();
+    fn handle_cycle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(list) = self.cycle_list.as_mut() else { return };
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('q'), KeyModifiers::NONE) => self.cycle_list = None,
+            (KeyCode::Up, KeyModifiers::NONE) |
+            (KeyCode::Char('k'), KeyModifiers::NONE) => list.move_up(),
+            (KeyCode::Down, KeyModifiers::NONE) |
+            (KeyCode::Char('j'), KeyModifiers::NONE) => list.move_down(),
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                let target = list.current().map(|id| id.to_string());
+                self.cycle_list = None;
+                if let Some(id) = target {
+                    self.go_to_issue(&id);
+                    self.focus = Focus::Tree;
+                    if self.status_message.is_none() {
+                        self.status_message = Some("D manages its dependencies".to_string());
+                    }
                 }
             }
-            (KeyCode::End, KeyModifiers::NONE) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.move_to_line_end();
+            _ => {}
+        }
+    }
+
+    /// Keys for the board. Returns false for the global keys it leaves to
+    /// `handle_key`: quit, help, refresh, show closed, sort and filter.
+    fn handle_board_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        let columns = self.tree.board_columns();
+        let Some(board) = self.board.as_mut() else { return false };
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) if self.show_help => return false,
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('b'), KeyModifiers::NONE) => self.board = None,
+            (KeyCode::Left, KeyModifiers::NONE) |
+            (KeyCode::Char('h'), KeyModifiers::NONE) => board.column = board.column.saturating_sub(1),
+            (KeyCode::Right, KeyModifiers::NONE) |
+            (KeyCode::Char('l'), KeyModifiers::NONE) => board.column = (board.column + 1).min(BOARD_STATUSES.len() - 1),
+            (KeyCode::Up, KeyModifiers::NONE) |
+            (KeyCode::Char('k'), KeyModifiers::NONE) => board.move_up(&columns),
+            (KeyCode::Down, KeyModifiers::NONE) |
+            (KeyCode::Char('j'), KeyModifiers::NONE) => board.move_down(&columns),
+            (KeyCode::Home, KeyModifiers::NONE) |
+            (KeyCode::Char('g'), KeyModifiers::NONE) => board.rows[board.column] = 0,
+            (KeyCode::End, KeyModifiers::NONE) |
+            (KeyCode::Char('G'), _) => board.rows[board.column] = columns[board.column].len().saturating_sub(1),
+
+            // Move the card to the next column, changing its status
+            (KeyCode::Left, KeyModifiers::SHIFT) |
+            (KeyCode::Char('H'), _) => self.move_board_card(&columns, false),
+            (KeyCode::Right, KeyModifiers::SHIFT) |
+            (KeyCode::Char('L'), _) => self.move_board_card(&columns, true),
+
+            // Leave the board with the card selected in the tree
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                let target = board.current(&columns).map(|id| id.to_string());
+                self.board = None;
+                if let Some(id) = target {
+                    self.go_to_issue(&id);
+                    self.focus = Focus::Tree;
                 }
             }
+            (KeyCode::Char('q' | '?' | 'r' | 'c' | 's' | 'S' | 'f' | 'F'), _) => return false,
+            _ => {}
+        }
+        true
+    }
 
-            // Regular character input
-            (KeyCode::Char(c), KeyModifiers::NONE) |
-            (KeyCode::Char(c), KeyModifiers::SHIFT) => {
-                if let Some(ref mut edit) = self.edit_state {
-                    edit.insert_char(c);
+    /// Move the selected card one column right (or left) by setting its status through
+    /// bd. Closing asks for a reason first, as `x` does.
+    fn move_board_card(&mut self, columns: &[Vec<String>], right: bool) {
+        let Some(board) = self.board.as_ref() else { return };
+        let Some(id) = board.current(columns).map(|s| s.to_string()) else { return };
+        let target = match right {
+            true if board.column + 1 < BOARD_STATUSES.len() => board.column + 1,
+            false if board.column > 0 => board.column - 1,
+            _ => return,
+        };
+        let status = BOARD_STATUSES[target];
+        if status == "closed" {
+            self.prompt = Some(Prompt::new(PromptKind::CloseReason { issue_id: id }));
+            return;
+        }
+
+        self.change_status(&id, status, None);
+        // Follow the card unless bd refused the change
+        let columns = self.tree.board_columns();
+        if let Some(board) = self.board.as_mut().filter(|_| columns[target].contains(&id)) {
+            board.select(&columns, &id);
+        }
+    }
+
+    /// Show the statistics dashboard, as of now
+    fn open_stats(&mut self) {
+        self.tree.set_now(activity::now());
+        self.stats = Some(ProjectStats::new(&self.tree));
+    }
+
+    fn handle_stats_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('q'), KeyModifiers::NONE) |
+            (KeyCode::Char('#'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('#'), KeyModifiers::NONE) => self.stats = None,
+            _ => {}
+        }
+    }
+
+    /// Show the dependency graph centred on `id`, if it still exists
+    fn open_graph_view(&mut self, id: &str) {
+        if self.tree.nodes.contains_key(id) {
+            self.graph_view = Some(DepGraph::around(&self.tree, id, self.graph_radius));
+        }
+    }
+
+    fn handle_graph_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(view) = self.graph_view.as_ref() else { return };
+        let center = view.center.clone();
+        // Moving along an edge re-centres the graph on the issue at its other end
+        let target = match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) |
+            (KeyCode::Char('q'), KeyModifiers::NONE) |
+            (KeyCode::Char('V'), KeyModifiers::SHIFT) |
+            (KeyCode::Char('V'), KeyModifiers::NONE) => {
+                self.graph_view = None;
+                return;
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.graph_view = None;
+                self.go_to_issue(&center);
+                self.focus = Focus::Tree;
+                return;
+            }
+            (KeyCode::Char('+'), _) | (KeyCode::Char('='), KeyModifiers::NONE) |
+            (KeyCode::Char('-'), KeyModifiers::NONE) => {
+                let radius = if code == KeyCode::Char('-') {
+                    self.graph_radius.saturating_sub(1).max(1)
+                } else {
+                    (self.graph_radius + 1).min(graph::MAX_RADIUS)
+                };
+                if radius != self.graph_radius {
+                    self.graph_radius = radius;
+                    let _ = state::save_graph_radius(radius);
                 }
+                Some(center)
+            }
+            (KeyCode::Up, KeyModifiers::NONE) |
+            (KeyCode::Char('k'), KeyModifiers::NONE) => {
+                view.nearest(&center, &view.neighbours(&center, true)).map(|s| s.to_string())
+            }
+            (KeyCode::Down, KeyModifiers::NONE) |
+            (KeyCode::Char('j'), KeyModifiers::NONE) => {
+                view.nearest(&center, &view.neighbours(&center, false)).map(|s| s.to_string())
             }
+            (KeyCode::Left, KeyModifiers::NONE) |
+            (KeyCode::Char('h'), KeyModifiers::NONE) => view.beside(&center, false).map(|s| s.to_string()),
+            (KeyCode::Right, KeyModifiers::NONE) |
+            (KeyCode::Char('l'), KeyModifiers::NONE) => view.beside(&center, true).map(|s| s.to_string()),
+            _ => None,
+        };
+        if let Some(id) = target {
+            self.open_graph_view(&id);
+        }
+    }
 
-            // Tab: in title mode, move to description; in description, insert spaces
-            (KeyCode::Tab, KeyModifiers::NONE) => {
-                if let Some(ref edit) = self.edit_state {
-                    match edit.field {
-                        EditField::Title => {
-                            // Save title and move to description
-                            let _ = self.save_edit();
-                            self.start_edit(EditField::Description);
-                        }
-                        EditField::Description => {
-                            // Insert spaces
-                            if let Some(ref mut edit) = self.edit_state {
-                                edit.insert_str("    ");
-                            }
-                        }
-                    }
+    fn handle_prompt_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(prompt) = self.prompt.as_mut() else { return };
+        let is_search = matches!(prompt.kind, PromptKind::Search { .. });
+        match (code, modifiers) {
+            (KeyCode::Esc, KeyModifiers::NONE) if is_search => self.cancel_search(),
+            (KeyCode::Esc, KeyModifiers::NONE) => self.prompt = None,
+            (KeyCode::Enter, KeyModifiers::NONE) => self.submit_prompt(),
+            _ => {
+                if prompt.input.handle_text_key(code, modifiers, false) && is_search {
+                    self.update_search();
                 }
             }
+        }
+    }
+
+    fn handle_create_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let Some(form) = self.create_form.as_mut() else { return };
+        match (code, modifiers) {
+            // Escape discards the form
+            (KeyCode::Esc, KeyModifiers::NONE) => {
+                self.create_form = None;
+                self.focus = Focus::Tree;
+            }
+
+            // Ctrl+S or Ctrl+Enter creates the issue
+            (KeyCode::Char('s'), KeyModifiers::CONTROL) |
+            (KeyCode::Enter, KeyModifiers::CONTROL) => {
+                self.submit_create();
+            }
 
-            // Shift+Tab: go back to title from description
+            // Tab/Shift+Tab move between fields
+            (KeyCode::Tab, KeyModifiers::NONE) => form.next_field(),
             (KeyCode::BackTab, KeyModifiers::SHIFT) |
-            (KeyCode::BackTab, KeyModifiers::NONE) => {
-                if let Some(ref edit) = self.edit_state {
-                    if edit.field == EditField::Description {
-                        // Save description and move back to title
-                        let _ = self.save_edit();
-                        self.start_edit(EditField::Title);
-                    }
+            (KeyCode::BackTab, KeyModifiers::NONE) => form.prev_field(),
+
+            // Enter inserts a newline in the description, moves on from other fields,
+            // and creates the issue from the last field
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                if form.current_field().is_multiline() {
+                    form.current_input_mut().insert_char('\n');
+                } else if form.current + 1 == form.inputs.len() {
+                    self.submit_create();
+                } else {
+                    form.next_field();
                 }
             }
 
-            _ => {}
+            _ => {
+                let multiline = form.current_field().is_multiline();
+                form.current_input_mut().handle_text_key(code, modifiers, multiline);
+            }
         }
     }
 
@@ -685,27 +2438,31 @@ impl App {
             (KeyCode::Char('l'), KeyModifiers::NONE) |
             (KeyCode::Right, KeyModifiers::NONE) => {
                 self.tree.expand();
-                let _ = save_expanded(&self.tree.expanded);
+                self.save_expanded();
             }
             (KeyCode::Char('h'), KeyModifiers::NONE) |
             (KeyCode::Left, KeyModifiers::NONE) => {
                 self.tree.collapse();
-                let _ = save_expanded(&self.tree.expanded);
+                self.save_expanded();
             }
             (KeyCode::Char(' '), KeyModifiers::NONE) => {
                 self.tree.toggle_expand();
-                let _ = save_expanded(&self.tree.expanded);
+                self.save_expanded();
             }
 
-            // Enter focuses details panel
+            // Enter focuses details panel, or leaves a reference row for the issue's
+            // first occurrence
             (KeyCode::Enter, KeyModifiers::NONE) => {
-                self.focus = Focus::Details;
+                let left_reference = self.tree.jump_to_primary();
+                if !left_reference {
+                    self.focus = Focus::Details;
+                }
             }
 
             // Toggle expand/collapse all
             (KeyCode::Tab, KeyModifiers::NONE) => {
                 self.tree.toggle_expand_all();
-                let _ = save_expanded(&self.tree.expanded);
+                self.save_expanded();
             }
 
             _ => {}
@@ -781,6 +2538,7 @@ impl App {
                 let clicked_index = self.tree_scroll + visual_index;
                 if clicked_index < self.tree.visible_items.len() {
                     self.tree.cursor = clicked_index;
+                    self.tree.jump_to_primary();
                     // Update scroll to keep new position valid
                     self.update_tree_scroll(screen_height);
                 }
@@ -818,6 +2576,10 @@ fn print_help() {
     println!("OPTIONS:");
     println!("    --help     Print this help message");
     println!("    --debug    Dump tree structure and exit");
+    println!("    --jsonl    Read .beads/issues.jsonl directly instead of running bd (read-only)");
+    println!("    --check-ready  Cross-check ready/blocked status against `bd ready`");
+    println!("    --bd <path>    bd executable to run (default: $BSV_BD, then bd on PATH)");
+    println!("    --dir <path>   Project directory to open (default: current directory)");
     println!();
     println!("TREE PANEL:");
     println!("    j/↓        Move cursor down");
@@ -840,13 +2602,44 @@ fn print_help() {
     println!("EDIT MODE:");
     println!("    Esc        Cancel editing");
     println!("    Ctrl+S     Save changes");
-    println!("    Tab        Move to description (from title)");
-    println!("    Shift+Tab  Move to title (from description)");
-    println!("    Enter      Newline (description) / Save & next (title)");
+    println!("    Tab        Save & next field (title, description, design, acceptance, notes)");
+    println!("    Shift+Tab  Save & previous field");
+    println!("    Enter      Newline (long-form fields) / Save & next (title)");
+    println!();
+    println!("NEW ISSUE FORM:");
+    println!("    Tab/S-Tab  Next/previous field");
+    println!("    Enter      Next field (newline in description, create from labels)");
+    println!("    Ctrl+S     Create issue");
+    println!("    Esc        Discard");
     println!();
     println!("GLOBAL:");
     println!("    c          Toggle show/hide closed");
     println!("    d          Toggle Epics/Deps view");
+    println!("    m          Repeat multi-parent issues under every blocker (Deps view)");
+    println!("    v          Group by status, type, label, priority, assignee (cycles)");
+    println!("    b          Kanban board by status (h/l columns, H/L move card, Enter opens in tree)");
+    println!("    R          Toggle ready queue (ready issues by priority, then work unblocked)");
+    println!("    T          Toggle timeline (issues by latest activity, under a header per day)");
+    println!("    s          Cycle sort: priority, updated, created, id, status");
+    println!("    S          Reverse sort order");
+    println!("    x          Close issue (asks for optional reason)");
+    println!("    o          Reopen issue");
+    println!("    w          Mark issue in progress");
+    println!("    P          Set priority (picker; 0-4 picks directly)");
+    println!("    t          Set type (picker)");
+    println!("    L          Edit labels (Space toggles, type to filter/add)");
+    println!("    D          Manage dependencies (a=add, x=remove, Tab=type)");
+    println!("    I          Issues unblocking the most work (Enter jumps to one)");
+    println!("    C          Dependency cycles and the dependencies forming them");
+    println!("    V          Dependency graph around issue (hjkl follow edges, +/- radius)");
+    println!("    #          Project statistics (counts, epics, oldest open, weekly throughput)");
+    println!("    /          Search ID, title, description and labels");
+    println!("    f          Filter tree (e.g. status:open priority:<=1 label:x -type:epic @saved)");
+    println!("    F          Save active filter as @name (-name deletes)");
+    println!("    Ctrl+P     Go to issue (fuzzy search over all issues)");
+    println!("    n / N      Next / previous search match (Esc clears the search)");
+    println!("    a          Add a new issue");
+    println!("    A          Add a child of the selected issue");
     println!("    r          Refresh data from bd");
     println!("    ?          Show help overlay");
     println!("    q/Ctrl+C   Quit");
@@ -862,8 +2655,9 @@ fn print_help() {
     println!("    Gray       Closed");
 }
 
-fn find_beads_dir() -> Option<PathBuf> {
-    let mut dir = std::env::current_dir().ok()?;
+/// Find the nearest `.beads` directory at or above `start`
+fn find_beads_dir(start: &std::path::Path) -> Option<PathBuf> {
+    let mut dir = start.to_path_buf();
     loop {
         let beads_dir = dir.join(".beads");
         if beads_dir.is_dir() {
@@ -875,6 +2669,30 @@ fn find_beads_dir() -> Option<PathBuf> {
     }
 }
 
+/// Value of an option given as `--name value` or `--name=value`
+fn option_value(args: &[String], name: &str) -> Option<String> {
+    let prefix = format!("{}=", name);
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == name {
+            args.get(i + 1).cloned()
+        } else {
+            arg.strip_prefix(&prefix).map(|v| v.to_string())
+        }
+    })
+}
+
+/// Pick the issue backend: bd by default, falling back to reading `.beads/issues.jsonl`
+/// when bd is missing or slow, or the JSONL file only when `--jsonl` is given.
+fn select_backend(bd: bd::BdCli, project_dir: &std::path::Path, jsonl_only: bool) -> Result<Arc<dyn IssueBackend>> {
+    let jsonl = find_beads_dir(project_dir).and_then(|dir| jsonl::JsonlBackend::in_beads_dir(&dir));
+    match (jsonl, jsonl_only) {
+        (Some(jsonl), true) => Ok(Arc::new(jsonl)),
+        (None, true) => anyhow::bail!("--jsonl: no .beads/issues.jsonl found"),
+        (Some(jsonl), false) => Ok(Arc::new(backend::FallbackBackend::new(bd, jsonl))),
+        (None, false) => Ok(Arc::new(bd)),
+    }
+}
+
 fn main() -> Result<()> {
     let args: Vec<String> = std::env::args().collect();
 
@@ -884,12 +2702,41 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    let project_dir = match option_value(&args, "--dir") {
+        Some(dir) => match std::fs::canonicalize(&dir) {
+            Ok(path) if path.is_dir() => path,
+            _ => {
+                eprintln!("bsv: --dir: {} is not a directory", dir);
+                std::process::exit(1);
+            }
+        },
+        None => std::env::current_dir()?,
+    };
+    let bd_cli = bd::BdCli::new(option_value(&args, "--bd"), Some(project_dir.clone()));
+    let jsonl_only = args.iter().any(|a| a == "--jsonl");
+    // State is keyed by bd's database; --jsonl never runs bd, so use the directory
+    let database_path = if jsonl_only { None } else { bd_cli.database_path() };
+    state::set_project_key(database_path.unwrap_or_else(|| project_dir.to_string_lossy().to_string()));
+
+    let check_ready = args.iter().any(|a| a == "--check-ready");
+    let issue_backend = match select_backend(bd_cli, &project_dir, jsonl_only) {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("bsv: {}", e);
+            std::process::exit(1);
+        }
+    };
+
     // Debug mode: dump tree and exit
     if args.iter().any(|a| a == "--debug") {
-        let issues = bd::list_issues_with_details()?;
+        let issues = issue_backend.list_issues_with_details()?;
         let (expanded, dep_expanded, hierarchy_mode) = state::load_tree_state();
-        let ready_ids = bd::get_ready_ids().unwrap_or_default();
-        let tree = IssueTree::from_issues(issues, expanded, dep_expanded, ready_ids, hierarchy_mode);
+        let mut tree = IssueTree::from_issues(issues, expanded, dep_expanded, hierarchy_mode);
+        let (sort_mode, sort_reverse) = state::load_sort();
+        tree.set_sort(sort_mode, sort_reverse);
+        if check_ready {
+            tree.cross_check_ready(&issue_backend.get_ready_ids()?);
+        }
         tree.debug_dump();
         return Ok(());
     }
@@ -898,7 +2745,7 @@ fn main() -> Result<()> {
     let (fs_tx, fs_rx) = mpsc::channel();
     let mut _watcher: Option<RecommendedWatcher> = None;
 
-    if let Some(beads_dir) = find_beads_dir() {
+    if let Some(beads_dir) = find_beads_dir(&project_dir) {
         let watcher_result = RecommendedWatcher::new(
             move |res: Result<notify::Event, notify::Error>| {
                 if res.is_ok() {
@@ -922,7 +2769,7 @@ fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app with async loading
-    let mut app = App::new_async();
+    let mut app = App::new_async(issue_backend, check_ready);
     let mut last_refresh = Instant::now();
     let refresh_cooldown = Duration::from_millis(500);
 
@@ -934,8 +2781,20 @@ fn main() -> Result<()> {
         let size = terminal.size()?;
         // Update tree scroll to keep cursor visible
         app.update_tree_scroll(size.height);
+        let status_note = app.status_note();
         terminal.draw(|frame| {
-            ui::render(frame, &app.tree, app.selected_details.as_ref(), app.show_help, app.focus, app.detail_scroll, app.edit_state.as_ref(), app.panel_ratio, app.tree_scroll, bd::is_daemon_slow(), app.is_loading);
+            ui::render(frame, &app.tree, app.selected_details.as_ref(), app.dep_summary.as_ref(), app.show_help, app.focus, app.detail_scroll, app.edit_state.as_ref(), app.panel_ratio, app.tree_scroll, status_note.as_deref(), app.is_loading, ui::Overlays {
+                create_form: app.create_form.as_ref(),
+                prompt: app.prompt.as_ref(),
+                picker: app.picker.as_ref(),
+                dep_editor: app.dep_editor.as_ref(),
+                finder: app.finder.as_ref(),
+                impact_list: app.impact_list.as_ref(),
+                cycle_list: app.cycle_list.as_ref(),
+                graph_view: app.graph_view.as_ref(),
+                stats: app.stats.as_ref(),
+                board: app.board.as_ref(),
+            });
         })?;
 
         // Check for file changes (non-blocking) with debounce
@@ -957,6 +2816,8 @@ fn main() -> Result<()> {
                     app.handle_key(key.code, key.modifiers);
                     app.update_selected_details();
                 }
+                // The board is keyboard-only
+                Event::Mouse(_) if app.board.is_some() => {}
                 Event::Mouse(mouse) => {
                     match mouse.kind {
                         MouseEventKind::Down(MouseButton::Left) => {
@@ -969,12 +2830,10 @@ fn main() -> Result<()> {
                                 app.update_selected_details();
                             }
                         }
-                        MouseEventKind::Drag(MouseButton::Left) => {
-                            if app.dragging_divider {
-                                // Update panel ratio based on mouse position
-                                let new_ratio = mouse.column as f32 / size.width as f32;
-                                app.panel_ratio = new_ratio.clamp(0.15, 0.85);
-                            }
+                        MouseEventKind::Drag(MouseButton::Left) if app.dragging_divider => {
+                            // Update panel ratio based on mouse position
+                            let new_ratio = mouse.column as f32 / size.width as f32;
+                            app.panel_ratio = new_ratio.clamp(0.15, 0.85);
                         }
                         MouseEventKind::Up(MouseButton::Left) => {
                             if app.dragging_divider {
@@ -986,15 +2845,11 @@ fn main() -> Result<()> {
                                 app.update_selected_details();
                             }
                         }
-                        MouseEventKind::ScrollDown => {
-                            if app.focus == Focus::Details {
-                                app.scroll_details(3);
-                            }
+                        MouseEventKind::ScrollDown if app.focus == Focus::Details => {
+                            app.scroll_details(3);
                         }
-                        MouseEventKind::ScrollUp => {
-                            if app.focus == Focus::Details {
-                                app.scroll_details(-3);
-                            }
+                        MouseEventKind::ScrollUp if app.focus == Focus::Details => {
+                            app.scroll_details(-3);
                         }
                         _ => {}
                     }
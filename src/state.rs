@@ -1,9 +1,10 @@
-use crate::HierarchyMode;
+use crate::{graph, HierarchyMode, SortMode};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct AppState {
@@ -19,6 +20,25 @@ pub struct ProjectState {
     pub hierarchy_mode: Option<HierarchyMode>,
     #[serde(default)]
     pub panel_ratio: Option<f32>,
+    /// Active tree filter expression
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// Named filters, referenced as `@name` in filter expressions
+    #[serde(default)]
+    pub saved_filters: BTreeMap<String, String>,
+    #[serde(default)]
+    pub sort_mode: Option<SortMode>,
+    #[serde(default)]
+    pub sort_reverse: bool,
+    /// Expanded group headers in the grouped views
+    #[serde(default)]
+    pub group_expanded: HashSet<String>,
+    /// Repeat multi-parent issues as reference rows in the Deps view
+    #[serde(default)]
+    pub show_dep_refs: bool,
+    /// How many dependency hops the graph view reaches from its centre
+    #[serde(default)]
+    pub graph_radius: Option<usize>,
 }
 
 fn state_file_path() -> Option<PathBuf> {
@@ -43,24 +63,21 @@ pub fn save_state(state: &AppState) -> Result<()> {
     Ok(())
 }
 
+/// Key for this project's entry in the state file, set once at startup
+static PROJECT_KEY: OnceLock<String> = OnceLock::new();
+
+/// Set the project key. Use the beads database path (from `bd info`) when available,
+/// so the same state is used regardless of which subdirectory you run from.
+pub fn set_project_key(key: String) {
+    let _ = PROJECT_KEY.set(key);
+}
+
 pub fn get_project_key() -> String {
-    // Use beads database path as key (from bd info --json)
-    // This ensures same expand state regardless of which subdirectory you run from
-    use std::process::Command;
-
-    if let Ok(output) = Command::new("bd").args(["info", "--json"]).output() {
-        if output.status.success() {
-            if let Ok(stdout) = String::from_utf8(output.stdout) {
-                if let Ok(info) = serde_json::from_str::<serde_json::Value>(&stdout) {
-                    if let Some(db_path) = info.get("database_path").and_then(|v| v.as_str()) {
-                        return db_path.to_string();
-                    }
-                }
-            }
-        }
+    if let Some(key) = PROJECT_KEY.get() {
+        return key.clone();
     }
 
-    // Fallback to current directory if bd info fails
+    // Fallback to current directory if no key was set
     std::env::current_dir()
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| "default".to_string())
@@ -96,9 +113,7 @@ pub fn save_expanded(expanded: &HashSet<String>) -> Result<()> {
     let existing = state.projects.get(&key).cloned().unwrap_or_default();
     state.projects.insert(key, ProjectState {
         expanded: expanded.clone(),
-        dep_expanded: existing.dep_expanded,
-        hierarchy_mode: existing.hierarchy_mode,
-        panel_ratio: existing.panel_ratio,
+        ..existing
     });
     save_state(&state)
 }
@@ -116,7 +131,7 @@ pub fn save_tree_state(
         expanded: expanded.clone(),
         dep_expanded: dep_expanded.clone(),
         hierarchy_mode: Some(hierarchy_mode),
-        panel_ratio: existing.panel_ratio,
+        ..existing
     });
     save_state(&state)
 }
@@ -138,10 +153,126 @@ pub fn save_panel_ratio(ratio: f32) -> Result<()> {
     let key = get_project_key();
     let existing = state.projects.get(&key).cloned().unwrap_or_default();
     state.projects.insert(key, ProjectState {
-        expanded: existing.expanded,
-        dep_expanded: existing.dep_expanded,
-        hierarchy_mode: existing.hierarchy_mode,
         panel_ratio: Some(ratio),
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Load the active filter expression and the saved filters
+pub fn load_filters() -> (Option<String>, BTreeMap<String, String>) {
+    let state = load_state();
+    let key = get_project_key();
+    state.projects.get(&key)
+        .map(|p| (p.filter.clone(), p.saved_filters.clone()))
+        .unwrap_or_default()
+}
+
+/// Save the active filter expression (None = no filter)
+pub fn save_filter(filter: Option<&str>) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        filter: filter.map(|f| f.to_string()),
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Save the named filters
+pub fn save_saved_filters(saved_filters: &BTreeMap<String, String>) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        saved_filters: saved_filters.clone(),
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Load the tree's sort order and whether it's reversed
+pub fn load_sort() -> (SortMode, bool) {
+    let state = load_state();
+    let key = get_project_key();
+    state.projects.get(&key)
+        .map(|p| (p.sort_mode.unwrap_or_default(), p.sort_reverse))
+        .unwrap_or_default()
+}
+
+/// Save the tree's sort order
+pub fn save_sort(sort_mode: SortMode, sort_reverse: bool) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        sort_mode: Some(sort_mode),
+        sort_reverse,
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Load the expanded group headers of the grouped views
+pub fn load_group_expanded() -> HashSet<String> {
+    let state = load_state();
+    let key = get_project_key();
+    state.projects.get(&key)
+        .map(|p| p.group_expanded.clone())
+        .unwrap_or_default()
+}
+
+/// Save the expanded group headers
+pub fn save_group_expanded(group_expanded: &HashSet<String>) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        group_expanded: group_expanded.clone(),
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Load whether the Deps view repeats multi-parent issues as reference rows
+pub fn load_show_dep_refs() -> bool {
+    let state = load_state();
+    let key = get_project_key();
+    state.projects.get(&key)
+        .map(|p| p.show_dep_refs)
+        .unwrap_or_default()
+}
+
+/// Save whether the Deps view repeats multi-parent issues as reference rows
+pub fn save_show_dep_refs(show_dep_refs: bool) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        show_dep_refs,
+        ..existing
+    });
+    save_state(&state)
+}
+
+/// Load the graph view's radius
+pub fn load_graph_radius() -> usize {
+    let state = load_state();
+    let key = get_project_key();
+    state.projects.get(&key)
+        .and_then(|p| p.graph_radius)
+        .unwrap_or(graph::DEFAULT_RADIUS)
+}
+
+/// Save the graph view's radius
+pub fn save_graph_radius(radius: usize) -> Result<()> {
+    let mut state = load_state();
+    let key = get_project_key();
+    let existing = state.projects.get(&key).cloned().unwrap_or_default();
+    state.projects.insert(key, ProjectState {
+        graph_radius: Some(radius),
+        ..existing
     });
     save_state(&state)
 }
@@ -0,0 +1,227 @@
+//! Project statistics for the `#` dashboard, over every issue regardless of
+//! the filter or whether closed issues are shown
+
+use crate::activity;
+use crate::tree::{natural_cmp, status_rank, IssueTree, Rollup};
+use std::collections::HashMap;
+
+/// Weeks of throughput shown, the latest last
+pub const THROUGHPUT_WEEKS: usize = 12;
+/// Issues listed as the oldest open ones
+const OLDEST_OPEN: usize = 5;
+
+const SECS_PER_WEEK: i64 = 7 * 86_400;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectStats {
+    pub total: usize,
+    /// Issues per status, in workflow order
+    pub by_status: Vec<(String, usize)>,
+    /// Issues per priority, P0 first
+    pub by_priority: Vec<(i32, usize)>,
+    /// Issues per type, most common first
+    pub by_type: Vec<(String, usize)>,
+    /// Open issues with nothing blocking them
+    pub ready: usize,
+    /// Open issues that aren't ready
+    pub blocked: usize,
+    /// Issues of type epic, wherever they sit in the tree, with the progress of
+    /// their descendants (0/0 for epics without children)
+    pub epics: Vec<(String, Rollup)>,
+    /// Open issues created longest ago, oldest first, with when they were created
+    pub oldest_open: Vec<(String, i64)>,
+    /// Open issues without a description
+    pub no_description: Vec<String>,
+    /// Issues closed in each of the last `THROUGHPUT_WEEKS` seven-day spans, the latest last
+    pub closed_per_week: Vec<u64>,
+}
+
+impl ProjectStats {
+    /// Statistics as of `tree.now`
+    pub fn new(tree: &IssueTree) -> Self {
+        let mut stats = ProjectStats {
+            total: tree.nodes.len(),
+            closed_per_week: vec![0; THROUGHPUT_WEEKS],
+            ..Default::default()
+        };
+
+        let mut by_status: HashMap<&str, usize> = HashMap::new();
+        let mut by_priority: HashMap<i32, usize> = HashMap::new();
+        let mut by_type: HashMap<&str, usize> = HashMap::new();
+        for node in tree.nodes.values() {
+            let issue = &node.issue;
+            *by_status.entry(issue.status.as_str()).or_default() += 1;
+            *by_priority.entry(issue.priority).or_default() += 1;
+            *by_type.entry(issue.issue_type.as_str()).or_default() += 1;
+
+            if issue.status == "closed" {
+                // Exports without closed_at were last updated when they were closed
+                let closed_at = issue.closed_at.as_deref().unwrap_or(&issue.updated_at);
+                if let Some(at) = activity::parse_timestamp(closed_at) {
+                    let weeks_ago = (tree.now - at).div_euclid(SECS_PER_WEEK).max(0) as usize;
+                    if weeks_ago < THROUGHPUT_WEEKS {
+                        stats.closed_per_week[THROUGHPUT_WEEKS - 1 - weeks_ago] += 1;
+                    }
+                }
+                continue;
+            }
+
+            if tree.ready_ids.contains(&issue.id) {
+                stats.ready += 1;
+            } else {
+                stats.blocked += 1;
+            }
+            if let Some(created) = activity::parse_timestamp(&issue.created_at) {
+                stats.oldest_open.push((issue.id.clone(), created));
+            }
+            if issue.description.as_deref().is_none_or(|d| d.trim().is_empty()) {
+                stats.no_description.push(issue.id.clone());
+            }
+        }
+
+        stats.by_status = by_status.into_iter().map(|(s, n)| (s.to_string(), n)).collect();
+        stats.by_status.sort_by(|(a, _), (b, _)| status_rank(a).cmp(&status_rank(b)).then_with(|| a.cmp(b)));
+        stats.by_priority = by_priority.into_iter().collect();
+        stats.by_priority.sort();
+        stats.by_type = by_type.into_iter().map(|(t, n)| (t.to_string(), n)).collect();
+        stats.by_type.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+
+        stats.epics = tree.nodes.values()
+            .filter(|node| node.issue.issue_type == "epic")
+            .map(|node| (node.issue.id.clone(), tree.rollup(&node.issue.id).cloned().unwrap_or_default()))
+            .collect();
+        stats.epics.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+
+        stats.oldest_open.sort_by(|(a, a_created), (b, b_created)| a_created.cmp(b_created).then_with(|| natural_cmp(a, b)));
+        stats.oldest_open.truncate(OLDEST_OPEN);
+        stats.no_description.sort_by(|a, b| natural_cmp(a, b));
+        stats
+    }
+
+    /// Epics with children, all of them closed
+    pub fn epics_done(&self) -> usize {
+        self.epics.iter().filter(|(_, rollup)| rollup.total > 0 && rollup.closed == rollup.total).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bd::Issue;
+    use crate::HierarchyMode;
+    use std::collections::HashSet;
+
+    fn make_issue(id: &str, status: &str, priority: i32, issue_type: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: id.to_uppercase(),
+            description: Some("Details".to_string()),
+            status: status.to_string(),
+            priority,
+            issue_type: issue_type.to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn make_stats(issues: Vec<Issue>) -> ProjectStats {
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
+        // Friday 16 Oct 2026, noon
+        tree.now = activity::parse_timestamp("2026-10-16T12:00:00Z").unwrap();
+        ProjectStats::new(&tree)
+    }
+
+    #[test]
+    fn test_counts_by_status_priority_and_type() {
+        let stats = make_stats(vec![
+            make_issue("a", "open", 2, "task"),
+            make_issue("b", "closed", 1, "bug"),
+            make_issue("c", "in_progress", 2, "task"),
+            make_issue("d", "open", 0, "feature"),
+        ]);
+
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.by_status, vec![
+            ("in_progress".to_string(), 1), ("open".to_string(), 2), ("closed".to_string(), 1),
+        ]);
+        assert_eq!(stats.by_priority, vec![(0, 1), (1, 1), (2, 2)]);
+        assert_eq!(stats.by_type, vec![("task".to_string(), 2), ("bug".to_string(), 1), ("feature".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_ready_blocked_and_epics() {
+        let mut blocked = make_issue("epic.2", "open", 2, "task");
+        blocked.dependencies = Some(vec![crate::bd::Dependency {
+            id: "epic.1".to_string(),
+            title: "EPIC.1".to_string(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let stats = make_stats(vec![
+            make_issue("epic", "open", 1, "epic"),
+            make_issue("epic.1", "open", 2, "task"),
+            blocked,
+            make_issue("epic.3", "closed", 2, "task"),
+            make_issue("done", "open", 1, "epic"),
+            make_issue("done.1", "closed", 2, "task"),
+            make_issue("empty", "open", 2, "epic"),
+            make_issue("feature", "open", 2, "feature"),
+            make_issue("feature.1", "open", 2, "epic"),
+        ]);
+
+        // The epics themselves have nothing blocking them
+        assert_eq!((stats.ready, stats.blocked), (6, 1));
+        // Counted by type: the nested and childless epics are in, the feature isn't
+        let epics: Vec<(&str, usize, usize)> = stats.epics.iter()
+            .map(|(id, rollup)| (id.as_str(), rollup.closed, rollup.total))
+            .collect();
+        assert_eq!(epics, vec![("done", 1, 1), ("empty", 0, 0), ("epic", 1, 3), ("feature.1", 0, 0)]);
+        assert_eq!(stats.epics_done(), 1);
+    }
+
+    #[test]
+    fn test_oldest_open_and_missing_descriptions() {
+        let mut issues: Vec<Issue> = (1..=7)
+            .map(|n| {
+                let mut issue = make_issue(&format!("x.{}", n), "open", 2, "task");
+                issue.created_at = format!("2026-0{}-01T00:00:00Z", 8 - n);
+                issue
+            })
+            .collect();
+        issues[0].description = None;
+        issues[1].description = Some("  ".to_string());
+        issues[6].status = "closed".to_string();
+        issues[6].description = None;
+        let stats = make_stats(issues);
+
+        // x.7 is the oldest, but closed
+        let oldest: Vec<&str> = stats.oldest_open.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(oldest, vec!["x.6", "x.5", "x.4", "x.3", "x.2"]);
+        assert_eq!(stats.oldest_open[0].1, activity::parse_timestamp("2026-02-01").unwrap());
+        assert_eq!(stats.no_description, vec!["x.1", "x.2"]);
+    }
+
+    #[test]
+    fn test_closed_per_week() {
+        let closed = |id: &str, closed_at: Option<&str>, updated_at: &str| {
+            let mut issue = make_issue(id, "closed", 2, "task");
+            issue.closed_at = closed_at.map(|c| c.to_string());
+            issue.updated_at = updated_at.to_string();
+            issue
+        };
+        let stats = make_stats(vec![
+            closed("a", Some("2026-10-16T09:00:00Z"), "2026-10-16T09:00:00Z"),
+            closed("b", Some("2026-10-08T09:00:00Z"), "2026-10-16T10:00:00Z"),
+            // Without closed_at, the last update
+            closed("c", None, "2026-10-12T09:00:00Z"),
+            // Too long ago to show
+            closed("d", Some("2026-01-01T00:00:00Z"), "2026-01-01T00:00:00Z"),
+            make_issue("e", "open", 2, "task"),
+        ]);
+
+        assert_eq!(stats.closed_per_week.len(), THROUGHPUT_WEEKS);
+        assert_eq!(stats.closed_per_week[THROUGHPUT_WEEKS - 1], 2);
+        assert_eq!(stats.closed_per_week[THROUGHPUT_WEEKS - 2], 1);
+        assert_eq!(stats.closed_per_week.iter().sum::<u64>(), 3);
+    }
+}
@@ -1,15 +1,61 @@
-use crate::bd::Issue;
-use crate::HierarchyMode;
+use crate::activity::{self, Activity};
+use crate::bd::{Dependency, Issue};
+use crate::filter::Filter;
+use crate::{GroupBy, HierarchyMode, SortMode};
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 
+/// Prefix of the synthetic IDs of group headers in `visible_items`
+const GROUP_PREFIX: &str = "group:";
+/// Prefix of the visible items that repeat an issue under another blocker
+const REF_PREFIX: &str = "ref:";
+
+/// Statuses with a column on the board, left to right
+pub const BOARD_STATUSES: [&str; 4] = ["open", "in_progress", "blocked", "closed"];
+
+/// Header for issues without a label / assignee
+const NO_LABEL: &str = "(no label)";
+const UNASSIGNED: &str = "(unassigned)";
+
 #[derive(Debug, Clone)]
 pub struct TreeNode {
     pub issue: Issue,
-    pub children: Vec<String>,        // ID-based children (from dotted IDs)
+    pub children: Vec<String>,        // ID-based children (parent field, parent-child deps, dotted IDs)
     pub dep_children: Vec<String>,    // Dependency-based children (issues that depend on this)
     pub depth: usize,
 }
 
+/// A synthetic header row in the grouped views
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupHeader {
+    pub name: String,
+    /// Issues shown under the header (whether or not it's expanded)
+    pub count: usize,
+}
+
+/// Extra occurrence of an issue in the Deps view, under a blocker other than
+/// the one it's first shown under
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepRef {
+    pub id: String,
+    /// The row it's shown under
+    pub parent: String,
+    pub depth: usize,
+}
+
+/// Progress of everything below a parent in the ID view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rollup {
+    pub total: usize,
+    pub closed: usize,
+    /// Open issues with nothing blocking them
+    pub ready: usize,
+    /// Open issues that aren't ready
+    pub blocked: usize,
+    /// Descendants per status, in workflow order
+    pub by_status: Vec<(String, usize)>,
+}
+
 #[derive(Debug)]
 pub struct IssueTree {
     pub nodes: HashMap<String, TreeNode>,
@@ -17,12 +63,29 @@ pub struct IssueTree {
     pub dep_root_ids: Vec<String>,          // Dependency-based roots (no dependencies)
     pub expanded: HashSet<String>,          // Expansion state for ID-based view
     pub dep_expanded: HashSet<String>,      // Expansion state for dependency view
+    pub group_expanded: HashSet<String>,    // Expanded group headers in the grouped views
+    pub groups: HashMap<String, GroupHeader>, // Headers of the current grouped view, by ID
+    pub dep_refs: HashMap<String, DepRef>,  // Reference rows of the Deps view, by row ID
+    pub show_dep_refs: bool,                // Repeat multi-parent issues under every blocker
+    pub id_parents: HashMap<String, String>, // Parent of each child in ID view
+    pub parent_conflicts: HashMap<String, String>, // Issues whose parent sources disagree, and how
     pub multi_parent_ids: HashSet<String>,  // Issues with multiple parents in dep view
-    pub ready_ids: HashSet<String>,
+    pub dep_cycles: Vec<Vec<String>>,       // Strongly connected components of the dep graph
+    pub ready_ids: HashSet<String>,         // Computed locally from dependencies
+    pub ready_mismatch_ids: HashSet<String>, // Issues where `bd ready` disagrees with ready_ids
+    pub rollups: HashMap<String, Rollup>,   // Progress below each ID view parent, kept in step with ready_ids
     pub visible_items: Vec<String>,
     pub cursor: usize,
     pub show_closed: bool,
     pub hierarchy_mode: HierarchyMode,
+    pub search: Option<String>,             // Active `/` search (lowercase)
+    pub filter: Option<Filter>,             // Active filter expression
+    pub sort_mode: SortMode,                // Order of roots and children
+    pub sort_reverse: bool,                 // Reverse `sort_mode`
+    pub now: i64,                           // The timeline's clock, in seconds since the epoch
+    pub utc_offset: i64,                    // Local time zone's offset from UTC at `now`, for the timeline's days
+    filter_matches: HashSet<String>,        // Issues matching `filter`
+    filter_context: HashSet<String>,        // Ancestors of matches, shown for context
 }
 
 impl IssueTree {
@@ -30,9 +93,9 @@ impl IssueTree {
         issues: Vec<Issue>,
         expanded: HashSet<String>,
         dep_expanded: HashSet<String>,
-        ready_ids: HashSet<String>,
         hierarchy_mode: HierarchyMode,
     ) -> Self {
+        let ready_ids = Self::compute_ready_ids(&issues);
         let mut nodes: HashMap<String, TreeNode> = HashMap::new();
         let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
         let mut dep_children_map: HashMap<String, Vec<String>> = HashMap::new();
@@ -48,13 +111,12 @@ impl IssueTree {
             });
         }
 
-        // Second pass: build parent-child relationships from DOTTED IDs ONLY
-        // e.g., "bsv-abc.1" is child of "bsv-abc"
+        // Second pass: build ID-based parent-child relationships from the explicit parent,
+        // parent-child dependencies and dotted IDs, e.g. "bsv-abc.1" is child of "bsv-abc"
+        let (id_parents, parent_conflicts) = Self::resolve_id_parents(&nodes);
         for issue in &issues {
-            if let Some(parent_id) = Self::parent_from_dotted_id(&issue.id) {
-                if nodes.contains_key(&parent_id) {
-                    children_map.entry(parent_id).or_default().push(issue.id.clone());
-                }
+            if let Some(parent_id) = id_parents.get(&issue.id) {
+                children_map.entry(parent_id.clone()).or_default().push(issue.id.clone());
             }
         }
 
@@ -99,14 +161,9 @@ impl IssueTree {
             }
         }
 
-        // Find ID-based root nodes: no dot in ID, OR parent from dotted ID doesn't exist
+        // Find ID-based root nodes: no parent, or a parent that doesn't exist
         let mut root_ids: Vec<String> = nodes.keys()
-            .filter(|id| {
-                match Self::parent_from_dotted_id(id) {
-                    Some(parent_id) => !nodes.contains_key(&parent_id),
-                    None => true, // no dot = root
-                }
-            })
+            .filter(|id| !id_parents.contains_key(*id))
             .cloned()
             .collect();
 
@@ -126,15 +183,20 @@ impl IssueTree {
             .cloned()
             .collect();
 
-        // Sort roots by priority then by title
-        let sort_fn = |a: &String, b: &String| {
-            let node_a = nodes.get(a).unwrap();
-            let node_b = nodes.get(b).unwrap();
-            node_a.issue.priority.cmp(&node_b.issue.priority)
-                .then_with(|| node_a.issue.title.cmp(&node_b.issue.title))
-        };
-        root_ids.sort_by(sort_fn);
-        dep_root_ids.sort_by(sort_fn);
+        // Find dependency cycles. A cycle that nothing outside it blocks has no root
+        // to hang from in the dep view, so its first member becomes one.
+        let dep_cycles = Self::find_dep_cycles(&nodes);
+        for cycle in &dep_cycles {
+            let blocked_from_outside = cycle.iter()
+                .flat_map(|id| Self::blocking_dep_ids(&nodes, id))
+                .any(|dep| !cycle.iter().any(|member| member == dep));
+            if !blocked_from_outside {
+                dep_root_ids.push(cycle[0].clone());
+            }
+        }
+
+        Self::sort_ids(&nodes, &mut root_ids, SortMode::default(), false);
+        Self::sort_ids(&nodes, &mut dep_root_ids, SortMode::default(), false);
 
         // Identify multi-parent issues
         let multi_parent_ids: HashSet<String> = parent_count.into_iter()
@@ -148,25 +210,279 @@ impl IssueTree {
             dep_root_ids,
             expanded,
             dep_expanded,
+            group_expanded: HashSet::new(),
+            groups: HashMap::new(),
+            dep_refs: HashMap::new(),
+            show_dep_refs: false,
+            id_parents,
+            parent_conflicts,
             multi_parent_ids,
+            dep_cycles,
             ready_ids,
+            ready_mismatch_ids: HashSet::new(),
+            rollups: HashMap::new(),
             visible_items: vec![],
             cursor: 0,
             show_closed: false,
             hierarchy_mode,
+            search: None,
+            filter: None,
+            sort_mode: SortMode::default(),
+            sort_reverse: false,
+            now: 0,
+            utc_offset: 0,
+            filter_matches: HashSet::new(),
+            filter_context: HashSet::new(),
         };
 
+        tree.rollups = tree.compute_rollups();
+        tree.set_now(activity::now());
         tree.rebuild_visible();
         tree
     }
 
+    /// Sort IDs by `mode`, falling back to priority, title and ID for ties.
+    /// IDs without a node go last.
+    fn sort_ids(nodes: &HashMap<String, TreeNode>, ids: &mut [String], mode: SortMode, reverse: bool) {
+        ids.sort_by(|a, b| match (nodes.get(a), nodes.get(b)) {
+            (Some(na), Some(nb)) => {
+                let order = compare_issues(&na.issue, &nb.issue, mode);
+                if reverse { order.reverse() } else { order }
+            }
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        });
+    }
+
+    /// Change the sort order, keeping the selected issue selected
+    pub fn set_sort(&mut self, mode: SortMode, reverse: bool) {
+        self.sort_mode = mode;
+        self.sort_reverse = reverse;
+        Self::sort_ids(&self.nodes, &mut self.root_ids, mode, reverse);
+        Self::sort_ids(&self.nodes, &mut self.dep_root_ids, mode, reverse);
+
+        let selected = self.selected_item().map(|s| s.to_string());
+        self.rebuild_visible();
+        if let Some(pos) = selected.and_then(|sel| self.visible_items.iter().position(|x| *x == sel)) {
+            self.cursor = pos;
+        }
+    }
+
+    /// Apply a change to an issue locally (e.g. right after a successful bd update)
+    /// and recompute what depends on it: ready state, root order and visibility.
+    /// The cursor stays on the selected issue, or at the same row if it was hidden.
+    pub fn update_issue(&mut self, id: &str, f: impl FnOnce(&mut Issue)) -> bool {
+        let Some(node) = self.nodes.get_mut(id) else {
+            return false;
+        };
+        f(&mut node.issue);
+
+        let issues: Vec<Issue> = self.nodes.values().map(|n| n.issue.clone()).collect();
+        self.ready_ids = Self::compute_ready_ids(&issues);
+        self.rollups = self.compute_rollups();
+        Self::sort_ids(&self.nodes, &mut self.root_ids, self.sort_mode, self.sort_reverse);
+        Self::sort_ids(&self.nodes, &mut self.dep_root_ids, self.sort_mode, self.sort_reverse);
+
+        let selected = self.selected_item().map(|s| s.to_string());
+        self.rebuild_visible();
+        if let Some(pos) = selected.and_then(|sel| self.visible_items.iter().position(|x| *x == sel)) {
+            self.cursor = pos;
+        }
+        true
+    }
+
+    /// Ready issues: not closed or explicitly blocked, and every `blocks` dependency is closed.
+    /// Other dependency types (related, parent-child, ...) don't hold up work, matching `bd ready`.
+    pub fn compute_ready_ids(issues: &[Issue]) -> HashSet<String> {
+        let status: HashMap<&str, &str> = issues.iter()
+            .map(|i| (i.id.as_str(), i.status.as_str()))
+            .collect();
+
+        issues.iter()
+            .filter(|issue| issue.status != "closed" && issue.status != "blocked")
+            .filter(|issue| {
+                issue.dependencies.iter().flatten()
+                    .filter(|d| holds_up_work(d))
+                    // Unknown blockers can't be checked, so they don't block
+                    .all(|d| status.get(d.id.as_str()).is_none_or(|s| *s == "closed"))
+            })
+            .map(|issue| issue.id.clone())
+            .collect()
+    }
+
+    /// Compare the locally computed ready set against `bd ready` output and
+    /// remember the issues where they disagree
+    pub fn cross_check_ready(&mut self, bd_ready_ids: &HashSet<String>) {
+        self.ready_mismatch_ids = self.ready_ids
+            .symmetric_difference(bd_ready_ids)
+            .filter(|id| self.nodes.contains_key(*id))
+            .cloned()
+            .collect();
+    }
+
+    /// Existing issues that `id` depends on, other than through `related` links
+    fn blocking_dep_ids<'a>(nodes: &'a HashMap<String, TreeNode>, id: &str) -> impl Iterator<Item = &'a str> {
+        nodes.get(id)
+            .and_then(|n| n.issue.dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|d| d.dependency_type.as_deref() != Some("related") && nodes.contains_key(&d.id))
+            .map(|d| d.id.as_str())
+    }
+
+    /// Dependency cycles: strongly connected components of the dependency graph with
+    /// more than one issue, or an issue that depends on itself (Tarjan's algorithm).
+    /// Members are in ID order, and cycles are ordered by their first member.
+    fn find_dep_cycles(nodes: &HashMap<String, TreeNode>) -> Vec<Vec<String>> {
+        struct Tarjan<'a> {
+            nodes: &'a HashMap<String, TreeNode>,
+            index: HashMap<&'a str, usize>,
+            lowlink: HashMap<&'a str, usize>,
+            stack: Vec<&'a str>,
+            on_stack: HashSet<&'a str>,
+            cycles: Vec<Vec<String>>,
+        }
+
+        impl<'a> Tarjan<'a> {
+            fn visit(&mut self, id: &'a str) {
+                let index = self.index.len();
+                self.index.insert(id, index);
+                self.lowlink.insert(id, index);
+                self.stack.push(id);
+                self.on_stack.insert(id);
+
+                for dep in IssueTree::blocking_dep_ids(self.nodes, id) {
+                    if !self.index.contains_key(dep) {
+                        self.visit(dep);
+                        let low = self.lowlink[id].min(self.lowlink[dep]);
+                        self.lowlink.insert(id, low);
+                    } else if self.on_stack.contains(dep) {
+                        let low = self.lowlink[id].min(self.index[dep]);
+                        self.lowlink.insert(id, low);
+                    }
+                }
+
+                if self.lowlink[id] == self.index[id] {
+                    let mut component = vec![];
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(member);
+                        component.push(member.to_string());
+                        if member == id {
+                            break;
+                        }
+                    }
+                    let self_loop = IssueTree::blocking_dep_ids(self.nodes, id).any(|dep| dep == id);
+                    if component.len() > 1 || self_loop {
+                        component.sort_by(|a, b| natural_cmp(a, b));
+                        self.cycles.push(component);
+                    }
+                }
+            }
+        }
+
+        let mut tarjan = Tarjan {
+            nodes,
+            index: HashMap::new(),
+            lowlink: HashMap::new(),
+            stack: vec![],
+            on_stack: HashSet::new(),
+            cycles: vec![],
+        };
+        let mut ids: Vec<&str> = nodes.keys().map(|id| id.as_str()).collect();
+        ids.sort_by(|a, b| natural_cmp(a, b));
+        for id in ids {
+            if !tarjan.index.contains_key(id) {
+                tarjan.visit(id);
+            }
+        }
+
+        let mut cycles = tarjan.cycles;
+        cycles.sort_by(|a, b| natural_cmp(&a[0], &b[0]));
+        cycles
+    }
+
+    /// The dependencies between members of a cycle, as (dependent, blocker, type):
+    /// one of them is the edge to remove
+    pub fn cycle_edges(&self, cycle: &[String]) -> Vec<(String, String, String)> {
+        cycle.iter()
+            .flat_map(|id| {
+                self.nodes.get(id)
+                    .and_then(|n| n.issue.dependencies.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .filter(|d| d.dependency_type.as_deref() != Some("related"))
+                    .filter(|d| cycle.contains(&d.id))
+                    .map(move |d| (id.clone(), d.id.clone(), d.dependency_type.clone().unwrap_or_else(|| "blocks".to_string())))
+            })
+            .collect()
+    }
+
     // "bsv-abc.1.2" -> Some("bsv-abc.1"), "bsv-abc" -> None
     fn parent_from_dotted_id(id: &str) -> Option<String> {
         id.rfind('.').map(|pos| id[..pos].to_string())
     }
 
+    /// Parent of each issue in the ID view: the explicit `parent` field, else a
+    /// `parent-child` dependency, else the dotted ID. Parents that don't exist are
+    /// ignored. Also returns the issues whose sources name different parents, and
+    /// the issues cut loose from a parent cycle, with a description of why.
+    fn resolve_id_parents(nodes: &HashMap<String, TreeNode>) -> (HashMap<String, String>, HashMap<String, String>) {
+        let mut parents = HashMap::new();
+        let mut conflicts = HashMap::new();
+
+        for (id, node) in nodes {
+            let issue = &node.issue;
+            let mut sources: Vec<(&str, String)> = vec![];
+            if let Some(parent) = &issue.parent {
+                sources.push(("parent field", parent.clone()));
+            }
+            for dep in issue.dependencies.iter().flatten() {
+                if dep.dependency_type.as_deref() == Some("parent-child") {
+                    sources.push(("parent-child dependency", dep.id.clone()));
+                }
+            }
+            if let Some(parent) = Self::parent_from_dotted_id(id) {
+                sources.push(("dotted ID", parent));
+            }
+            sources.retain(|(_, parent)| parent != id && nodes.contains_key(parent));
+
+            let Some((_, parent)) = sources.first() else { continue };
+            if sources.iter().any(|(_, other)| other != parent) {
+                let described: Vec<String> = sources.iter()
+                    .map(|(source, parent)| format!("{} {}", source, parent))
+                    .collect();
+                conflicts.insert(id.clone(), described.join(", "));
+            }
+            parents.insert(id.clone(), parent.clone());
+        }
+
+        // Explicit parents can form a cycle, which would hide every issue in it:
+        // make the first issue of the cycle (in ID order) a root
+        let mut ids: Vec<&String> = nodes.keys().collect();
+        ids.sort_by(|a, b| natural_cmp(a, b));
+        for id in ids {
+            let mut path = vec![id.clone()];
+            let mut current = id.clone();
+            while let Some(parent) = parents.get(&current) {
+                if *parent == *id {
+                    parents.remove(id);
+                    conflicts.insert(id.clone(), format!("parent cycle: {}", path.join(" → ")));
+                    break;
+                }
+                if path.contains(parent) {
+                    break; // A cycle further up, broken from its own first issue
+                }
+                path.push(parent.clone());
+                current = parent.clone();
+            }
+        }
+
+        (parents, conflicts)
+    }
+
     pub fn rebuild_visible(&mut self) {
         self.visible_items.clear();
+        self.dep_refs.clear();
+        self.compute_filter_sets();
         match self.hierarchy_mode {
             HierarchyMode::IdBased => {
                 for root_id in &self.root_ids.clone() {
@@ -179,23 +495,83 @@ impl IssueTree {
                 // This prevents items from appearing multiple times at different depths
                 let mut added: HashSet<String> = HashSet::new();
                 for root_id in &self.dep_root_ids.clone() {
-                    self.add_visible_recursive_dep(root_id, 0, &mut visited, &mut added);
+                    self.add_visible_recursive_dep(root_id, None, 0, &mut visited, &mut added);
                 }
             }
+            HierarchyMode::Grouped(group_by) => self.add_visible_grouped(group_by),
+            HierarchyMode::ReadyQueue => self.add_visible_ready(),
+            HierarchyMode::Timeline => self.add_visible_timeline(),
         }
         if self.cursor >= self.visible_items.len() && !self.visible_items.is_empty() {
             self.cursor = self.visible_items.len() - 1;
         }
     }
 
+    /// Work out which issues the filter keeps: the matches, plus their ancestors in the
+    /// current mode so matches are shown in context
+    fn compute_filter_sets(&mut self) {
+        self.filter_matches.clear();
+        self.filter_context.clear();
+        let Some(filter) = &self.filter else { return };
+
+        // Closed issues only match if the filter asks for a status or they're shown anyway
+        let closed_can_match = self.show_closed || filter.selects_status();
+        self.filter_matches = self.nodes.values()
+            .filter(|n| closed_can_match || n.issue.status != "closed")
+            .filter(|n| filter.matches(&n.issue, self.ready_ids.contains(&n.issue.id)))
+            .map(|n| n.issue.id.clone())
+            .collect();
+
+        let mut pending: Vec<String> = self.filter_matches.iter().cloned().collect();
+        while let Some(id) = pending.pop() {
+            let parents: Vec<String> = match self.hierarchy_mode {
+                HierarchyMode::IdBased => self.id_parents.get(&id).cloned().into_iter().collect(),
+                HierarchyMode::DependencyBased => self.nodes.get(&id)
+                    .and_then(|n| n.issue.dependencies.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .filter(|d| d.dependency_type.as_deref() != Some("related"))
+                    .map(|d| d.id.clone())
+                    .collect(),
+                // Group and day headers are shown for any group with matches; the queue is flat
+                HierarchyMode::Grouped(_) | HierarchyMode::ReadyQueue | HierarchyMode::Timeline => vec![],
+            };
+            for parent in parents {
+                if self.nodes.contains_key(&parent) && self.filter_context.insert(parent.clone()) {
+                    pending.push(parent);
+                }
+            }
+        }
+    }
+
+    /// Whether the filter hides an issue: it neither matches nor leads to a match
+    fn filtered_out(&self, id: &str) -> bool {
+        self.filter.is_some() && !self.filter_matches.contains(id) && !self.filter_context.contains(id)
+    }
+
+    /// Shown only as an ancestor of filter matches, not a match itself
+    pub fn is_filter_context(&self, id: &str) -> bool {
+        self.filter.is_some() && !self.filter_matches.contains(id)
+    }
+
+    /// Closed issues are hidden unless shown, or asked for by the filter
+    fn is_hidden_closed(&self, id: &str, is_closed: bool) -> bool {
+        is_closed && !self.show_closed && !self.filter_matches.contains(id)
+    }
+
     fn add_visible_recursive_id(&mut self, id: &str, depth: usize) {
+        if self.filtered_out(id) {
+            return;
+        }
+
         // Check if this issue is closed
         let is_closed = self.nodes.get(id)
             .map(|node| node.issue.status == "closed")
             .unwrap_or(false);
+        let is_hidden = self.is_hidden_closed(id, is_closed);
 
         // Only add to visible if showing closed OR issue is not closed
-        if self.show_closed || !is_closed {
+        if !is_hidden {
             self.visible_items.push(id.to_string());
 
             if let Some(node) = self.nodes.get_mut(id) {
@@ -205,27 +581,17 @@ impl IssueTree {
 
         // Traverse children if:
         // 1. This node is expanded, OR
-        // 2. This node is closed and hidden (so open children can still appear)
-        let should_traverse = self.expanded.contains(id) || (!self.show_closed && is_closed);
+        // 2. This node is closed and hidden (so open children can still appear), OR
+        // 3. This node leads to filter matches
+        let should_traverse = self.expanded.contains(id) || is_hidden || self.filter_context.contains(id);
 
         if should_traverse {
             if let Some(node) = self.nodes.get(id).cloned() {
                 let mut children = node.children.clone();
-                // Sort children by priority then title
-                children.sort_by(|a, b| {
-                    let node_a = self.nodes.get(a);
-                    let node_b = self.nodes.get(b);
-                    match (node_a, node_b) {
-                        (Some(na), Some(nb)) => {
-                            na.issue.priority.cmp(&nb.issue.priority)
-                                .then_with(|| na.issue.title.cmp(&nb.issue.title))
-                        }
-                        _ => std::cmp::Ordering::Equal,
-                    }
-                });
+                Self::sort_ids(&self.nodes, &mut children, self.sort_mode, self.sort_reverse);
                 // If current node is hidden (closed), children appear at same depth
                 // Otherwise, children are indented
-                let child_depth = if !self.show_closed && is_closed { depth } else { depth + 1 };
+                let child_depth = if is_hidden { depth } else { depth + 1 };
                 for child_id in children {
                     self.add_visible_recursive_id(&child_id, child_depth);
                 }
@@ -233,13 +599,19 @@ impl IssueTree {
         }
     }
 
+    /// `parent` is the nearest visible row above `id` in the hierarchy
     fn add_visible_recursive_dep(
         &mut self,
         id: &str,
+        parent: Option<&str>,
         depth: usize,
         visited: &mut HashSet<String>,
         added: &mut HashSet<String>,
     ) {
+        if self.filtered_out(id) {
+            return;
+        }
+
         // Check if this issue is closed
         let is_closed = self.nodes.get(id)
             .map(|node| node.issue.status == "closed")
@@ -251,13 +623,25 @@ impl IssueTree {
         }
 
         // Check if this node is hidden (closed and not showing closed)
-        let is_hidden = !self.show_closed && is_closed;
+        let is_hidden = self.is_hidden_closed(id, is_closed);
 
         // Only add to visible if showing closed OR issue is not closed
-        if self.show_closed || !is_closed {
-            // Global deduplication: show each item only once (first occurrence wins)
+        if !is_hidden {
+            // Global deduplication: show each item only once (first occurrence wins),
+            // or as a reference row that leads back to the first occurrence
             if added.contains(id) {
-                return; // Already shown elsewhere in tree, skip entirely
+                if let Some(parent) = parent.filter(|_| self.show_dep_refs) {
+                    let ref_id = format!("{}{}>{}", REF_PREFIX, parent, id);
+                    if !self.dep_refs.contains_key(&ref_id) {
+                        self.visible_items.push(ref_id.clone());
+                        self.dep_refs.insert(ref_id, DepRef {
+                            id: id.to_string(),
+                            parent: parent.to_string(),
+                            depth,
+                        });
+                    }
+                }
+                return;
             }
             self.visible_items.push(id.to_string());
             added.insert(id.to_string());
@@ -269,65 +653,371 @@ impl IssueTree {
 
         // Traverse children if:
         // 1. This node is expanded, OR
-        // 2. This node is closed and hidden (so open children can still appear)
-        let should_traverse = self.dep_expanded.contains(id) || is_hidden;
+        // 2. This node is closed and hidden (so open children can still appear), OR
+        // 3. This node leads to filter matches
+        let should_traverse = self.dep_expanded.contains(id) || is_hidden || self.filter_context.contains(id);
 
         if should_traverse {
             if let Some(node) = self.nodes.get(id).cloned() {
                 let mut children = node.dep_children.clone();
-                // Sort children by priority then title
-                children.sort_by(|a, b| {
-                    let node_a = self.nodes.get(a);
-                    let node_b = self.nodes.get(b);
-                    match (node_a, node_b) {
-                        (Some(na), Some(nb)) => {
-                            na.issue.priority.cmp(&nb.issue.priority)
-                                .then_with(|| na.issue.title.cmp(&nb.issue.title))
-                        }
-                        _ => std::cmp::Ordering::Equal,
-                    }
-                });
+                Self::sort_ids(&self.nodes, &mut children, self.sort_mode, self.sort_reverse);
                 visited.insert(id.to_string()); // Mark as in-path
                 // If current node is hidden (closed), children appear at same depth
                 // Otherwise, children are indented
                 let child_depth = if is_hidden { depth } else { depth + 1 };
+                let child_parent = if is_hidden { parent } else { Some(id) };
                 for child_id in children {
-                    self.add_visible_recursive_dep(&child_id, child_depth, visited, added);
+                    self.add_visible_recursive_dep(&child_id, child_parent, child_depth, visited, added);
                 }
                 visited.remove(id); // Remove from path when backtracking
             }
         }
     }
 
+    /// Flat lists of issues under a header per group, skipping empty groups
+    fn add_visible_grouped(&mut self, group_by: GroupBy) {
+        let mut members: HashMap<String, Vec<String>> = HashMap::new();
+        for node in self.nodes.values() {
+            for name in group_names(&node.issue, group_by) {
+                members.entry(name).or_default().push(node.issue.id.clone());
+            }
+        }
+
+        let mut names: Vec<String> = members.keys().cloned().collect();
+        names.sort_by(|a, b| compare_group_names(a, b, group_by));
+
+        self.groups.clear();
+        for name in names {
+            let mut ids = members.remove(&name).unwrap_or_default();
+            Self::sort_ids(&self.nodes, &mut ids, self.sort_mode, self.sort_reverse);
+            ids.retain(|id| {
+                let is_closed = self.nodes[id].issue.status == "closed";
+                !self.filtered_out(id) && !self.is_hidden_closed(id, is_closed)
+            });
+            if ids.is_empty() {
+                continue;
+            }
+
+            let header_id = format!("{}{}:{}", GROUP_PREFIX, group_by.name(), name);
+            self.groups.insert(header_id.clone(), GroupHeader { name, count: ids.len() });
+            self.visible_items.push(header_id.clone());
+            if self.group_expanded.contains(&header_id) {
+                for id in ids {
+                    if let Some(node) = self.nodes.get_mut(&id) {
+                        node.depth = 1;
+                    }
+                    self.visible_items.push(id);
+                }
+            }
+        }
+    }
+
+    /// Ready issues by priority, then by how much open work each one unblocks
+    fn add_visible_ready(&mut self) {
+        let mut queue: Vec<(&str, usize)> = self.ready_ids.iter()
+            .filter(|id| self.nodes.get(*id).is_some_and(|n| n.issue.status != "closed"))
+            .filter(|id| !self.filtered_out(id))
+            .map(|id| (id.as_str(), self.transitive_dependents(id)))
+            .collect();
+        queue.sort_by(|(a, a_unblocks), (b, b_unblocks)| {
+            let (a, b) = (&self.nodes[*a].issue, &self.nodes[*b].issue);
+            a.priority.cmp(&b.priority)
+                .then_with(|| b_unblocks.cmp(a_unblocks))
+                .then_with(|| compare_issues(a, b, SortMode::Priority))
+        });
+
+        let ids: Vec<String> = queue.into_iter().map(|(id, _)| id.to_string()).collect();
+        for id in &ids {
+            if let Some(node) = self.nodes.get_mut(id) {
+                node.depth = 0;
+            }
+        }
+        self.visible_items = ids;
+    }
+
+    /// Issues by their latest activity, newest first, under a header per day
+    fn add_visible_timeline(&mut self) {
+        let mut events: Vec<(String, Activity)> = self.nodes.values()
+            .filter(|n| !self.filtered_out(&n.issue.id))
+            .filter(|n| !self.is_hidden_closed(&n.issue.id, n.issue.status == "closed"))
+            .filter_map(|n| Activity::latest(&n.issue).map(|a| (n.issue.id.clone(), a)))
+            .collect();
+        events.sort_by(|(a, a_activity), (b, b_activity)| {
+            b_activity.at.cmp(&a_activity.at).then_with(|| natural_cmp(a, b))
+        });
+
+        self.groups.clear();
+        let today = activity::day_of(self.now, self.utc_offset);
+        for day_events in events.chunk_by(|(_, a), (_, b)| a.day(self.utc_offset) == b.day(self.utc_offset)) {
+            let day = day_events[0].1.day(self.utc_offset);
+            let header_id = Self::day_header_id(day);
+            self.groups.insert(header_id.clone(), GroupHeader {
+                name: activity::day_label(day, today),
+                count: day_events.len(),
+            });
+            self.visible_items.push(header_id.clone());
+            if self.group_expanded.contains(&header_id) {
+                for (id, _) in day_events {
+                    if let Some(node) = self.nodes.get_mut(id) {
+                        node.depth = 1;
+                    }
+                    self.visible_items.push(id.clone());
+                }
+            }
+        }
+    }
+
+    /// ID of the timeline's header for a day (in days since the epoch)
+    fn day_header_id(day: i64) -> String {
+        format!("{}day:{}", GROUP_PREFIX, activity::date(day))
+    }
+
+    /// Set the timeline's clock, with the local UTC offset at that time
+    pub fn set_now(&mut self, now: i64) {
+        self.now = now;
+        self.utc_offset = activity::local_offset(now);
+    }
+
+    /// Expand the timeline's headers for today and yesterday
+    pub fn expand_recent_days(&mut self) {
+        let today = activity::day_of(self.now, self.utc_offset);
+        self.group_expanded.insert(Self::day_header_id(today));
+        self.group_expanded.insert(Self::day_header_id(today - 1));
+        self.rebuild_visible();
+    }
+
+    /// Issues in each board column (see `BOARD_STATUSES`), in the tree's sort order.
+    /// The filter applies, and closed issues are only listed while they're shown.
+    pub fn board_columns(&self) -> Vec<Vec<String>> {
+        BOARD_STATUSES.iter()
+            .map(|status| {
+                let mut ids: Vec<String> = self.nodes.values()
+                    .filter(|n| n.issue.status == *status)
+                    .filter(|n| self.show_closed || n.issue.status != "closed")
+                    .filter(|n| self.filter.is_none() || self.filter_matches.contains(&n.issue.id))
+                    .map(|n| n.issue.id.clone())
+                    .collect();
+                Self::sort_ids(&self.nodes, &mut ids, self.sort_mode, self.sort_reverse);
+                ids
+            })
+            .collect()
+    }
+
+    /// Is this visible item a group header rather than an issue?
+    pub fn is_group(id: &str) -> bool {
+        id.starts_with(GROUP_PREFIX)
+    }
+
+    /// Is this visible item a reference to an issue shown elsewhere in the Deps view?
+    pub fn is_dep_ref(id: &str) -> bool {
+        id.starts_with(REF_PREFIX)
+    }
+
+    /// If a reference row is selected, move the cursor to the issue's first occurrence
+    pub fn jump_to_primary(&mut self) -> bool {
+        let Some(dep_ref) = self.selected_item().and_then(|item| self.dep_refs.get(item)) else {
+            return false;
+        };
+        match self.visible_items.iter().position(|x| *x == dep_ref.id) {
+            Some(pos) => {
+                self.cursor = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get the current expansion state based on hierarchy mode
     fn current_expanded(&self) -> &HashSet<String> {
         match self.hierarchy_mode {
             HierarchyMode::IdBased => &self.expanded,
             HierarchyMode::DependencyBased => &self.dep_expanded,
+            HierarchyMode::Grouped(_) | HierarchyMode::Timeline => &self.group_expanded,
+            // Nothing expands in the flat ready queue
+            HierarchyMode::ReadyQueue => &self.expanded,
+        }
+    }
+
+    fn current_expanded_mut(&mut self) -> &mut HashSet<String> {
+        match self.hierarchy_mode {
+            HierarchyMode::IdBased => &mut self.expanded,
+            HierarchyMode::DependencyBased => &mut self.dep_expanded,
+            HierarchyMode::Grouped(_) | HierarchyMode::Timeline => &mut self.group_expanded,
+            HierarchyMode::ReadyQueue => &mut self.expanded,
         }
     }
 
     /// Get the current children for a node based on hierarchy mode
-    fn current_children<'a>(&self, node: &'a TreeNode) -> &'a Vec<String> {
+    fn current_children<'a>(&self, node: &'a TreeNode) -> &'a [String] {
         match self.hierarchy_mode {
             HierarchyMode::IdBased => &node.children,
             HierarchyMode::DependencyBased => &node.dep_children,
+            // Issues are listed flat under their group or day headers, or in the queue
+            HierarchyMode::Grouped(_) | HierarchyMode::ReadyQueue | HierarchyMode::Timeline => &[],
         }
     }
 
     /// Check if a node has children in the current hierarchy mode
     pub fn has_children_in_current_mode(&self, id: &str) -> bool {
+        if self.groups.contains_key(id) {
+            return true;
+        }
         self.nodes.get(id)
             .map(|n| !self.current_children(n).is_empty())
             .unwrap_or(false)
     }
 
+    /// Progress over all descendants of `id` in the ID view, closed or not,
+    /// or None if it has no children
+    pub fn rollup(&self, id: &str) -> Option<&Rollup> {
+        self.rollups.get(id)
+    }
+
+    /// Rollups of every issue with children in the ID view, so drawing a frame
+    /// doesn't walk each subtree again
+    fn compute_rollups(&self) -> HashMap<String, Rollup> {
+        self.nodes.iter()
+            .filter(|(_, node)| !node.children.is_empty())
+            .map(|(id, node)| (id.clone(), self.compute_rollup(id, node)))
+            .collect()
+    }
+
+    fn compute_rollup(&self, id: &str, node: &TreeNode) -> Rollup {
+        let mut rollup = Rollup::default();
+        let mut by_status: HashMap<&str, usize> = HashMap::new();
+        let mut seen = HashSet::from([id]);
+        let mut pending: Vec<&str> = node.children.iter().map(|c| c.as_str()).collect();
+        while let Some(child_id) = pending.pop() {
+            let Some(child) = self.nodes.get(child_id).filter(|_| seen.insert(child_id)) else {
+                continue;
+            };
+            rollup.total += 1;
+            if child.issue.status == "closed" {
+                rollup.closed += 1;
+            } else if self.ready_ids.contains(child_id) {
+                rollup.ready += 1;
+            } else {
+                rollup.blocked += 1;
+            }
+            *by_status.entry(child.issue.status.as_str()).or_default() += 1;
+            pending.extend(child.children.iter().map(|c| c.as_str()));
+        }
+
+        rollup.by_status = by_status.into_iter()
+            .map(|(status, count)| (status.to_string(), count))
+            .collect();
+        rollup.by_status.sort_by(|(a, _), (b, _)| status_rank(a).cmp(&status_rank(b)).then_with(|| a.cmp(b)));
+        rollup
+    }
+
+    /// Issues that `id` hangs from in the Deps view: its non-`related` dependencies
+    pub fn dep_blockers(&self, id: &str) -> Vec<&str> {
+        Self::blocking_dep_ids(&self.nodes, id).collect()
+    }
+
+    /// Open issues that `id` waits on directly: its `blocks` dependencies
+    fn open_blockers(&self, id: &str) -> Vec<&str> {
+        self.nodes.get(id)
+            .and_then(|n| n.issue.dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|d| holds_up_work(d))
+            .filter(|d| self.nodes.get(&d.id).is_some_and(|b| b.issue.status != "closed"))
+            .map(|d| d.id.as_str())
+            .collect()
+    }
+
+    /// Longest chain of open blockers below `id` in the dependency graph, nearest
+    /// first and ending at an issue with no open blockers (usually a ready one).
+    /// Empty if nothing open blocks it.
+    pub fn critical_path(&self, id: &str) -> Vec<String> {
+        fn longest<'a>(
+            tree: &'a IssueTree,
+            id: &'a str,
+            memo: &mut HashMap<&'a str, Vec<&'a str>>,
+            on_path: &mut HashSet<&'a str>,
+        ) -> Vec<&'a str> {
+            if let Some(chain) = memo.get(id) {
+                return chain.clone();
+            }
+            on_path.insert(id);
+            let mut best: Vec<&str> = vec![];
+            for blocker in tree.open_blockers(id) {
+                // Cycles have no end to walk to
+                if on_path.contains(blocker) {
+                    continue;
+                }
+                let mut chain = vec![blocker];
+                chain.extend(longest(tree, blocker, memo, on_path));
+                if chain.len() > best.len() {
+                    best = chain;
+                }
+            }
+            on_path.remove(id);
+            memo.insert(id, best.clone());
+            best
+        }
+
+        longest(self, id, &mut HashMap::new(), &mut HashSet::new())
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Number of open issues waiting on `id` through `blocks` dependencies,
+    /// directly or through other issues
+    pub fn transitive_dependents(&self, id: &str) -> usize {
+        let mut seen = HashSet::from([id]);
+        let mut pending = vec![id];
+        let mut count = 0;
+        while let Some(current) = pending.pop() {
+            let Some(node) = self.nodes.get(current) else { continue };
+            for child in &node.dep_children {
+                let waits = self.nodes.get(child).is_some_and(|c| {
+                    c.issue.status != "closed"
+                        && c.issue.dependencies.iter().flatten().any(|d| d.id == current && holds_up_work(d))
+                });
+                if waits && seen.insert(child) {
+                    count += 1;
+                    pending.push(child);
+                }
+            }
+        }
+        count
+    }
+
+    /// Open issues that hold up other open work, with how many issues wait on each,
+    /// most downstream work first
+    pub fn blocking_impact(&self) -> Vec<(String, usize)> {
+        let mut impact: Vec<(String, usize)> = self.nodes.values()
+            .filter(|n| n.issue.status != "closed")
+            .map(|n| (n.issue.id.clone(), self.transitive_dependents(&n.issue.id)))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        impact.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count)
+                .then_with(|| compare_issues(&self.nodes[a].issue, &self.nodes[b].issue, SortMode::Priority))
+        });
+        impact
+    }
+
     /// Check if a node is expanded in the current hierarchy mode
     pub fn is_expanded_in_current_mode(&self, id: &str) -> bool {
         self.current_expanded().contains(id)
     }
 
+    /// The selected issue (the one a reference row stands for), or None when a
+    /// group header is selected
     pub fn selected_id(&self) -> Option<&str> {
+        let item = self.selected_item().filter(|id| !Self::is_group(id))?;
+        match self.dep_refs.get(item) {
+            Some(dep_ref) => Some(dep_ref.id.as_str()),
+            None => Some(item),
+        }
+    }
+
+    /// The selected row: an issue ID, a group header's ID or a reference row's ID
+    pub fn selected_item(&self) -> Option<&str> {
         self.visible_items.get(self.cursor).map(|s| s.as_str())
     }
 
@@ -358,12 +1048,12 @@ impl IssueTree {
     }
 
     pub fn toggle_expand(&mut self) {
-        if let Some(id) = self.selected_id().map(|s| s.to_string()) {
+        if self.jump_to_primary() {
+            return;
+        }
+        if let Some(id) = self.selected_item().map(|s| s.to_string()) {
             if self.has_children_in_current_mode(&id) {
-                let expanded = match self.hierarchy_mode {
-                    HierarchyMode::IdBased => &mut self.expanded,
-                    HierarchyMode::DependencyBased => &mut self.dep_expanded,
-                };
+                let expanded = self.current_expanded_mut();
                 if expanded.contains(&id) {
                     expanded.remove(&id);
                 } else {
@@ -375,12 +1065,12 @@ impl IssueTree {
     }
 
     pub fn expand(&mut self) {
-        if let Some(id) = self.selected_id().map(|s| s.to_string()) {
+        if self.jump_to_primary() {
+            return;
+        }
+        if let Some(id) = self.selected_item().map(|s| s.to_string()) {
             if self.has_children_in_current_mode(&id) {
-                let expanded = match self.hierarchy_mode {
-                    HierarchyMode::IdBased => &mut self.expanded,
-                    HierarchyMode::DependencyBased => &mut self.dep_expanded,
-                };
+                let expanded = self.current_expanded_mut();
                 if !expanded.contains(&id) {
                     expanded.insert(id);
                     self.rebuild_visible();
@@ -390,31 +1080,22 @@ impl IssueTree {
     }
 
     pub fn collapse(&mut self) {
-        if let Some(id) = self.selected_id().map(|s| s.to_string()) {
-            let expanded = match self.hierarchy_mode {
-                HierarchyMode::IdBased => &mut self.expanded,
-                HierarchyMode::DependencyBased => &mut self.dep_expanded,
-            };
+        if self.hierarchy_mode == HierarchyMode::ReadyQueue {
+            return;
+        }
+        if let Some(id) = self.selected_item().map(|s| s.to_string()) {
+            let expanded = self.current_expanded_mut();
             if expanded.contains(&id) {
                 expanded.remove(&id);
                 self.rebuild_visible();
+            } else if matches!(self.hierarchy_mode, HierarchyMode::Grouped(_) | HierarchyMode::Timeline) {
+                // The header above, as an issue can be in several groups
+                if let Some(pos) = self.visible_items[..self.cursor].iter().rposition(|x| Self::is_group(x)) {
+                    self.cursor = pos;
+                }
             } else {
                 // If already collapsed or leaf, move to parent
-                // In ID mode: use dotted ID parent
-                // In Dep mode: find first dependency (if any)
-                let parent_id = match self.hierarchy_mode {
-                    HierarchyMode::IdBased => Self::parent_from_dotted_id(&id),
-                    HierarchyMode::DependencyBased => {
-                        self.nodes.get(&id).and_then(|node| {
-                            node.issue.dependencies.as_ref().and_then(|deps| {
-                                deps.iter()
-                                    .find(|d| d.dependency_type.as_deref() != Some("related"))
-                                    .map(|d| d.id.clone())
-                            })
-                        })
-                    }
-                };
-                if let Some(parent_id) = parent_id {
+                if let Some(parent_id) = self.parent_in_current_mode(&id) {
                     if let Some(pos) = self.visible_items.iter().position(|x| x == &parent_id) {
                         self.cursor = pos;
                     }
@@ -423,6 +1104,168 @@ impl IssueTree {
         }
     }
 
+    /// Parent of a node in the current mode
+    /// In ID mode: the resolved parent (parent field, parent-child dependency or dotted ID)
+    /// In Dep mode: find first dependency (if any)
+    fn parent_in_current_mode(&self, id: &str) -> Option<String> {
+        match self.hierarchy_mode {
+            HierarchyMode::IdBased => self.id_parents.get(id).cloned(),
+            HierarchyMode::DependencyBased => {
+                if let Some(dep_ref) = self.dep_refs.get(id) {
+                    return Some(dep_ref.parent.clone());
+                }
+                self.nodes.get(id).and_then(|node| {
+                    node.issue.dependencies.as_ref().and_then(|deps| {
+                        deps.iter()
+                            .find(|d| d.dependency_type.as_deref() != Some("related"))
+                            .map(|d| d.id.clone())
+                    })
+                })
+            }
+            HierarchyMode::Grouped(group_by) => self.nodes.get(id).and_then(|node| {
+                group_names(&node.issue, group_by).into_iter().next()
+                    .map(|name| format!("{}{}:{}", GROUP_PREFIX, group_by.name(), name))
+            }),
+            HierarchyMode::Timeline => self.nodes.get(id)
+                .and_then(|node| Activity::latest(&node.issue))
+                .map(|activity| Self::day_header_id(activity.day(self.utc_offset))),
+            HierarchyMode::ReadyQueue => None,
+        }
+    }
+
+    /// Move the cursor to an issue, expanding its ancestors so it is visible.
+    /// Returns false if the issue isn't in the tree or stays hidden (e.g. closed).
+    pub fn select_issue(&mut self, id: &str) -> bool {
+        if !self.nodes.contains_key(id) {
+            return false;
+        }
+
+        let mut current = id.to_string();
+        let mut seen = HashSet::new();
+        while let Some(parent_id) = self.parent_in_current_mode(&current) {
+            let is_parent = self.nodes.contains_key(&parent_id) || Self::is_group(&parent_id);
+            if !is_parent || !seen.insert(parent_id.clone()) {
+                break;
+            }
+            self.current_expanded_mut().insert(parent_id.clone());
+            current = parent_id;
+        }
+        self.rebuild_visible();
+
+        match self.visible_items.iter().position(|x| x == id) {
+            Some(pos) => {
+                self.cursor = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The cycle that making `id` depend on `depends_on` would create, as the path
+    /// `id -> depends_on -> ... -> id`, or None if the new edge is safe.
+    ///
+    /// Follows the same edges as the Deps view; `related` links never form a hierarchy,
+    /// so they can't create a cycle.
+    pub fn dependency_cycle(&self, id: &str, depends_on: &str, dep_type: &str) -> Option<Vec<String>> {
+        if dep_type == "related" {
+            return None;
+        }
+        if id == depends_on {
+            return Some(vec![id.to_string(), id.to_string()]);
+        }
+
+        // Depth-first search from depends_on back to id, remembering how we got to each node
+        let mut came_from: HashMap<String, String> = HashMap::new();
+        let mut stack = vec![depends_on.to_string()];
+        while let Some(current) = stack.pop() {
+            let deps = self.nodes.get(&current)
+                .and_then(|n| n.issue.dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .filter(|d| d.dependency_type.as_deref() != Some("related"));
+            for dep in deps {
+                if dep.id == depends_on || came_from.contains_key(&dep.id) {
+                    continue;
+                }
+                came_from.insert(dep.id.clone(), current.clone());
+                if dep.id == id {
+                    let mut path = vec![id.to_string()];
+                    let mut step = id.to_string();
+                    while let Some(prev) = came_from.get(&step) {
+                        path.push(prev.clone());
+                        step = prev.clone();
+                    }
+                    path.reverse();
+                    path.insert(0, id.to_string());
+                    return Some(path);
+                }
+                stack.push(dep.id.clone());
+            }
+        }
+        None
+    }
+
+    /// Does an issue match the active search? Matches are case-insensitive substrings
+    /// of the ID, title, description or a label.
+    pub fn issue_matches(&self, id: &str) -> bool {
+        let (Some(query), Some(node)) = (&self.search, self.nodes.get(id)) else {
+            return false;
+        };
+        let issue = &node.issue;
+        [Some(&issue.id), Some(&issue.title), issue.description.as_ref()]
+            .into_iter()
+            .flatten()
+            .chain(issue.labels.iter().flatten())
+            .any(|text| text.to_lowercase().contains(query))
+    }
+
+    /// Number of issues matching the active search that can be shown
+    pub fn search_match_count(&self) -> usize {
+        self.nodes.values()
+            .filter(|n| self.show_closed || n.issue.status != "closed")
+            .filter(|n| self.issue_matches(&n.issue.id))
+            .count()
+    }
+
+    /// Every issue in display order, as if all nodes in the current mode were expanded
+    fn fully_expanded_order(&mut self) -> Vec<String> {
+        let all: HashSet<String> = self.nodes.keys().chain(self.groups.keys()).cloned().collect();
+        let saved = std::mem::replace(self.current_expanded_mut(), all);
+        let cursor = self.cursor;
+        self.rebuild_visible();
+        let order = std::mem::take(&mut self.visible_items);
+
+        *self.current_expanded_mut() = saved;
+        self.rebuild_visible();
+        self.cursor = cursor;
+        order
+    }
+
+    /// Move to the next (or previous) search match in tree order, wrapping around and
+    /// expanding collapsed ancestors so the match is visible. With `include_current`, the
+    /// selected issue counts as a match (for search-as-you-type). Returns false if nothing matches.
+    pub fn jump_to_match(&mut self, forward: bool, include_current: bool) -> bool {
+        if self.search.is_none() {
+            return false;
+        }
+        let order = self.fully_expanded_order();
+        let start = self.selected_id()
+            .and_then(|id| order.iter().position(|x| x == id))
+            .unwrap_or(0);
+
+        let len = order.len();
+        let offsets: Vec<usize> = if include_current { (0..len).collect() } else { (1..=len).collect() };
+        let target = offsets.into_iter()
+            .map(|offset| if forward { (start + offset) % len } else { (start + len - offset % len) % len })
+            .map(|i| order[i].clone())
+            .find(|id| self.issue_matches(id));
+
+        match target {
+            Some(id) => self.select_issue(&id),
+            None => false,
+        }
+    }
+
     pub fn debug_dump(&self) {
         eprintln!("=== Tree Debug Dump ===");
         eprintln!("Hierarchy Mode: {:?}", self.hierarchy_mode);
@@ -430,13 +1273,25 @@ impl IssueTree {
         eprintln!("=== ID-Based (Epics) Hierarchy ===");
         eprintln!("Root IDs: {:?}", self.root_ids);
         eprintln!("Expanded: {:?}", self.expanded);
+        if !self.parent_conflicts.is_empty() {
+            eprintln!("Parent conflicts: {:?}", self.parent_conflicts);
+        }
         eprintln!();
         eprintln!("=== Dependency-Based (Deps) Hierarchy ===");
         eprintln!("Dep Root IDs: {:?}", self.dep_root_ids);
         eprintln!("Dep Expanded: {:?}", self.dep_expanded);
         eprintln!("Multi-parent IDs: {:?}", self.multi_parent_ids);
+        for (i, cycle) in self.dep_cycles.iter().enumerate() {
+            eprintln!("Dependency cycle {}: {}", i + 1, cycle.join(", "));
+            for (from, to, dep_type) in self.cycle_edges(cycle) {
+                eprintln!("  {} -> {} ({})", from, to, dep_type);
+            }
+        }
         eprintln!();
         eprintln!("Ready IDs: {:?}", self.ready_ids);
+        if !self.ready_mismatch_ids.is_empty() {
+            eprintln!("Ready mismatch with bd ready: {:?}", self.ready_mismatch_ids);
+        }
         eprintln!();
         eprintln!("All nodes:");
         for (id, node) in &self.nodes {
@@ -461,7 +1316,8 @@ impl IssueTree {
                     "[BLOCKED]"
                 };
                 let multi = if self.multi_parent_ids.contains(id) { " [MULTI]" } else { "" };
-                eprintln!("{} {}{} - {} {}{}", marker, indent, id, node.issue.title, status, multi);
+                let mismatch = if self.ready_mismatch_ids.contains(id) { " [MISMATCH]" } else { "" };
+                eprintln!("{} {}{} - {} {}{}{}", marker, indent, id, node.issue.title, status, multi, mismatch);
             }
         }
         eprintln!("=== End Dump ===");
@@ -478,22 +1334,33 @@ impl IssueTree {
     }
 
     pub fn toggle_expand_all(&mut self) {
-        let expanded = match self.hierarchy_mode {
-            HierarchyMode::IdBased => &mut self.expanded,
-            HierarchyMode::DependencyBased => &mut self.dep_expanded,
+        let parents: Vec<String> = match self.hierarchy_mode {
+            HierarchyMode::ReadyQueue => return,
+            HierarchyMode::IdBased => self.nodes.values()
+                .filter(|n| !n.children.is_empty())
+                .map(|n| n.issue.id.clone())
+                .collect(),
+            HierarchyMode::DependencyBased => self.nodes.values()
+                .filter(|n| !n.dep_children.is_empty())
+                .map(|n| n.issue.id.clone())
+                .collect(),
+            HierarchyMode::Grouped(_) | HierarchyMode::Timeline => self.groups.keys().cloned().collect(),
         };
+        let is_grouped = matches!(self.hierarchy_mode, HierarchyMode::Grouped(_) | HierarchyMode::Timeline);
+        let expanded = self.current_expanded_mut();
 
-        // If anything is expanded, collapse all; otherwise expand all
-        if expanded.is_empty() {
-            // Expand all nodes with children (in current mode)
-            for (id, node) in &self.nodes {
-                let has_children = match self.hierarchy_mode {
-                    HierarchyMode::IdBased => !node.children.is_empty(),
-                    HierarchyMode::DependencyBased => !node.dep_children.is_empty(),
-                };
-                if has_children {
-                    expanded.insert(id.clone());
-                }
+        // If anything is expanded, collapse all; otherwise expand all.
+        // Headers of the other groupings share the set, so only this grouping's count.
+        let any_expanded = if is_grouped {
+            parents.iter().any(|id| expanded.contains(id))
+        } else {
+            !expanded.is_empty()
+        };
+        if !any_expanded {
+            expanded.extend(parents);
+        } else if is_grouped {
+            for id in &parents {
+                expanded.remove(id);
             }
         } else {
             expanded.clear();
@@ -506,6 +1373,16 @@ impl IssueTree {
         self.rebuild_visible();
     }
 
+    /// Show or hide the Deps view's reference rows, keeping the selected issue selected
+    pub fn toggle_dep_refs(&mut self) {
+        self.show_dep_refs = !self.show_dep_refs;
+        let selected = self.selected_id().map(|s| s.to_string());
+        self.rebuild_visible();
+        if let Some(pos) = selected.and_then(|sel| self.visible_items.iter().position(|x| *x == sel)) {
+            self.cursor = pos;
+        }
+    }
+
     /// Set the hierarchy mode and rebuild visible items
     pub fn set_hierarchy_mode(&mut self, mode: HierarchyMode) {
         self.hierarchy_mode = mode;
@@ -513,6 +1390,98 @@ impl IssueTree {
     }
 }
 
+/// Whether a dependency holds up work: only `blocks` ones do (related, parent-child,
+/// ... don't), matching `bd ready`
+fn holds_up_work(dep: &Dependency) -> bool {
+    matches!(dep.dependency_type.as_deref(), None | Some("blocks"))
+}
+
+/// Order two issues by `mode`, then priority, title and ID
+fn compare_issues(a: &Issue, b: &Issue, mode: SortMode) -> Ordering {
+    let primary = match mode {
+        SortMode::Priority => Ordering::Equal,
+        SortMode::Updated => b.updated_at.cmp(&a.updated_at),
+        SortMode::Created => a.created_at.cmp(&b.created_at),
+        SortMode::Id => natural_cmp(&a.id, &b.id),
+        SortMode::Status => status_rank(&a.status).cmp(&status_rank(&b.status)),
+    };
+    primary
+        .then_with(|| a.priority.cmp(&b.priority))
+        .then_with(|| a.title.cmp(&b.title))
+        .then_with(|| natural_cmp(&a.id, &b.id))
+}
+
+/// Names of the groups an issue is listed under (one per label when grouping by label)
+fn group_names(issue: &Issue, group_by: GroupBy) -> Vec<String> {
+    match group_by {
+        GroupBy::Status => vec![issue.status.clone()],
+        GroupBy::Type => vec![issue.issue_type.clone()],
+        GroupBy::Priority => vec![format!("P{}", issue.priority)],
+        GroupBy::Label => match issue.labels.as_deref() {
+            Some(labels) if !labels.is_empty() => labels.to_vec(),
+            _ => vec![NO_LABEL.to_string()],
+        },
+        GroupBy::Assignee => vec![issue.assignee.clone()
+            .filter(|a| !a.is_empty())
+            .unwrap_or_else(|| UNASSIGNED.to_string())],
+    }
+}
+
+/// Header order: statuses by workflow, P0 first, names naturally, and no label/assignee last
+fn compare_group_names(a: &str, b: &str, group_by: GroupBy) -> Ordering {
+    let rank = |name: &str| match group_by {
+        GroupBy::Status => status_rank(name),
+        _ => u8::from(name == NO_LABEL || name == UNASSIGNED),
+    };
+    rank(a).cmp(&rank(b)).then_with(|| natural_cmp(a, b))
+}
+
+/// Work in progress first, closed last
+pub fn status_rank(status: &str) -> u8 {
+    match status {
+        "in_progress" => 0,
+        "open" => 1,
+        "blocked" => 2,
+        "deferred" => 3,
+        "closed" => 5,
+        _ => 4,
+    }
+}
+
+/// Compare strings with runs of digits compared as numbers, so `x.2` < `x.10`
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                        digits.push(c);
+                    }
+                    digits.trim_start_matches('0').to_string()
+                };
+                let (x, y) = (take_number(&mut a), take_number(&mut b));
+                let order = x.len().cmp(&y.len()).then_with(|| x.cmp(&y));
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -522,26 +1491,18 @@ mod tests {
         Issue {
             id: id.to_string(),
             title: title.to_string(),
-            description: None,
             status: "open".to_string(),
             priority,
             issue_type: "task".to_string(),
             created_at: "2024-01-01".to_string(),
-            created_by: None,
             updated_at: "2024-01-01".to_string(),
-            labels: None,
-            parent: None,
-            dependencies: None,
-            dependents: None,
-            notes: None,
-            design: None,
-            acceptance_criteria: None,
+            ..Default::default()
         }
     }
 
     /// Helper to create a tree with ID-based hierarchy (default mode)
-    fn make_tree(issues: Vec<Issue>, expanded: HashSet<String>, ready_ids: HashSet<String>) -> IssueTree {
-        IssueTree::from_issues(issues, expanded, HashSet::new(), ready_ids, HierarchyMode::IdBased)
+    fn make_tree(issues: Vec<Issue>, expanded: HashSet<String>) -> IssueTree {
+        IssueTree::from_issues(issues, expanded, HashSet::new(), HierarchyMode::IdBased)
     }
 
     #[test]
@@ -566,7 +1527,7 @@ mod tests {
             make_issue("bsv-c", "Issue C", 2),
         ];
 
-        let tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let tree = make_tree(issues, HashSet::new());
 
         assert_eq!(tree.root_ids.len(), 3);
         // Should be sorted by priority then title
@@ -584,7 +1545,7 @@ mod tests {
             make_issue("bsv-epic.1.1", "Subtask 1.1", 2),
         ];
 
-        let tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let tree = make_tree(issues, HashSet::new());
 
         // Only the epic should be a root
         assert_eq!(tree.root_ids.len(), 1);
@@ -612,7 +1573,7 @@ mod tests {
             make_issue("bsv-other", "Other", 2),
         ];
 
-        let tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let tree = make_tree(issues, HashSet::new());
 
         // Both should be roots since bsv-epic doesn't exist
         assert_eq!(tree.root_ids.len(), 2);
@@ -620,6 +1581,55 @@ mod tests {
         assert!(tree.root_ids.contains(&"bsv-other".to_string()));
     }
 
+    fn parent_child(parent_id: &str) -> Option<Vec<crate::bd::Dependency>> {
+        Some(vec![crate::bd::Dependency {
+            id: parent_id.to_string(),
+            title: format!("Parent {}", parent_id),
+            dependency_type: Some("parent-child".to_string()),
+        }])
+    }
+
+    #[test]
+    fn test_explicit_parents_override_dotted_ids() {
+        // bsv-a.1 was reparented to bsv-b; bsv-c has a parent-child dependency on bsv-a.1
+        let mut moved = make_issue("bsv-a.1", "Moved", 2);
+        moved.parent = Some("bsv-b".to_string());
+        let mut linked = make_issue("bsv-c", "Linked", 2);
+        linked.dependencies = parent_child("bsv-a.1");
+        // Agreeing sources and a missing explicit parent aren't conflicts
+        let mut agreed = make_issue("bsv-a.2", "Agreed", 2);
+        agreed.parent = Some("bsv-a".to_string());
+        agreed.dependencies = parent_child("bsv-a");
+        let mut orphan = make_issue("bsv-d", "Orphan", 2);
+        orphan.parent = Some("bsv-gone".to_string());
+        let issues = vec![
+            make_issue("bsv-a", "A", 2), make_issue("bsv-b", "B", 2), moved, linked, agreed, orphan,
+        ];
+
+        let tree = make_tree(issues, HashSet::from(["bsv-b".to_string(), "bsv-a.1".to_string()]));
+        assert_eq!(tree.root_ids, vec!["bsv-a", "bsv-b", "bsv-d"]);
+        assert_eq!(tree.nodes["bsv-a"].children, vec!["bsv-a.2"]);
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-b", "bsv-a.1", "bsv-c", "bsv-d"]);
+        assert_eq!(tree.nodes["bsv-c"].depth, 2);
+
+        assert_eq!(tree.parent_conflicts.len(), 1);
+        assert_eq!(tree.parent_conflicts["bsv-a.1"], "parent field bsv-b, dotted ID bsv-a");
+    }
+
+    #[test]
+    fn test_parent_cycle_becomes_root() {
+        let mut a = make_issue("bsv-a", "A", 2);
+        a.parent = Some("bsv-b".to_string());
+        let mut b = make_issue("bsv-b", "B", 2);
+        b.dependencies = parent_child("bsv-a");
+        let mut tree = make_tree(vec![a, b], HashSet::new());
+
+        assert_eq!(tree.root_ids, vec!["bsv-a"]);
+        assert_eq!(tree.parent_conflicts["bsv-a"], "parent cycle: bsv-a → bsv-b");
+        assert!(tree.select_issue("bsv-b"));
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-b"]);
+    }
+
     #[test]
     fn test_visible_items_collapsed() {
         let issues = vec![
@@ -628,7 +1638,7 @@ mod tests {
             make_issue("bsv-b", "B", 2),
         ];
 
-        let tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let tree = make_tree(issues, HashSet::new());
 
         // With nothing expanded, should only see roots
         assert_eq!(tree.visible_items.len(), 2);
@@ -647,7 +1657,7 @@ mod tests {
         let mut expanded = HashSet::new();
         expanded.insert("bsv-a".to_string());
 
-        let tree = make_tree(issues, expanded, HashSet::new());
+        let tree = make_tree(issues, expanded);
 
         // Should see A, A.1, and B
         assert_eq!(tree.visible_items.len(), 3);
@@ -661,7 +1671,7 @@ mod tests {
             make_issue("bsv-c", "C", 2),
         ];
 
-        let mut tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let mut tree = make_tree(issues, HashSet::new());
 
         assert_eq!(tree.cursor, 0);
         assert_eq!(tree.selected_id(), Some("bsv-a"));
@@ -694,7 +1704,7 @@ mod tests {
             make_issue("bsv-a.1", "A.1", 2),
         ];
 
-        let mut tree = make_tree(issues, HashSet::new(), HashSet::new());
+        let mut tree = make_tree(issues, HashSet::new());
 
         // Initially only root visible
         assert_eq!(tree.visible_items.len(), 1);
@@ -729,13 +1739,54 @@ mod tests {
         expanded.insert("bsv-a".to_string());
         expanded.insert("bsv-a.1".to_string());
 
-        let tree = make_tree(issues, expanded, HashSet::new());
+        let tree = make_tree(issues, expanded);
 
         assert_eq!(tree.nodes.get("bsv-a").unwrap().depth, 0);
         assert_eq!(tree.nodes.get("bsv-a.1").unwrap().depth, 1);
         assert_eq!(tree.nodes.get("bsv-a.1.1").unwrap().depth, 2);
     }
 
+    #[test]
+    fn test_rollup_counts_all_descendants() {
+        let mut blocked = make_issue("bsv-a.2", "Blocked", 2);
+        blocked.dependencies = Some(vec![crate::bd::Dependency {
+            id: "bsv-a.1".to_string(),
+            title: "A.1".to_string(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let mut started = make_issue("bsv-a.1.1", "Started", 2);
+        started.status = "in_progress".to_string();
+        let issues = vec![
+            make_issue("bsv-a", "A", 2),
+            make_issue("bsv-a.1", "A.1", 2),
+            started,
+            blocked,
+            make_closed_issue("bsv-a.3", "Done", 2),
+            make_closed_issue("bsv-a.1.2", "Done too", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+
+        // Closed children count even while they're hidden
+        assert_eq!(tree.rollup("bsv-a"), Some(&Rollup {
+            total: 5,
+            closed: 2,
+            ready: 2,
+            blocked: 1,
+            by_status: vec![
+                ("in_progress".to_string(), 1),
+                ("open".to_string(), 2),
+                ("closed".to_string(), 2),
+            ],
+        }));
+        assert_eq!(tree.rollup("bsv-a.1").map(|r| (r.closed, r.total)), Some((1, 2)));
+        assert_eq!(tree.rollup("bsv-a.2"), None);
+
+        // Local updates keep the cached rollups current, blockers included
+        tree.update_issue("bsv-a.1", |issue| issue.status = "closed".to_string());
+        let rollup = tree.rollup("bsv-a").unwrap();
+        assert_eq!((rollup.closed, rollup.ready, rollup.blocked), (3, 2, 0));
+    }
+
     // === Dependency Hierarchy Tests ===
 
     fn make_issue_with_deps(id: &str, title: &str, dep_ids: Vec<&str>) -> Issue {
@@ -751,25 +1802,18 @@ mod tests {
         Issue {
             id: id.to_string(),
             title: title.to_string(),
-            description: None,
             status: "open".to_string(),
             priority: 2,
             issue_type: "task".to_string(),
             created_at: "2024-01-01".to_string(),
-            created_by: None,
             updated_at: "2024-01-01".to_string(),
-            labels: None,
-            parent: None,
             dependencies,
-            dependents: None,
-            notes: None,
-            design: None,
-            acceptance_criteria: None,
+            ..Default::default()
         }
     }
 
     fn make_tree_dep_mode(issues: Vec<Issue>, dep_expanded: HashSet<String>) -> IssueTree {
-        IssueTree::from_issues(issues, HashSet::new(), dep_expanded, HashSet::new(), HierarchyMode::DependencyBased)
+        IssueTree::from_issues(issues, HashSet::new(), dep_expanded, HierarchyMode::DependencyBased)
     }
 
     #[test]
@@ -821,7 +1865,7 @@ mod tests {
         let tree = make_tree_dep_mode(issues, HashSet::new());
 
         // multi should be in multi_parent_ids
-        assert!(tree.multi_parent_ids.contains(&"multi".to_string()));
+        assert!(tree.multi_parent_ids.contains("multi"));
         // multi should be a child of both root1 and root2
         assert!(tree.nodes.get("root1").unwrap().dep_children.contains(&"multi".to_string()));
         assert!(tree.nodes.get("root2").unwrap().dep_children.contains(&"multi".to_string()));
@@ -877,7 +1921,6 @@ mod tests {
             issues.clone(),
             HashSet::new(),
             HashSet::new(),
-            HashSet::new(),
             HierarchyMode::IdBased
         );
         // epic.1 is child of epic, task is a root
@@ -889,7 +1932,6 @@ mod tests {
             issues,
             HashSet::new(),
             HashSet::new(),
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
         // task depends on epic.1, so task is child of epic.1 in dep view
@@ -948,20 +1990,12 @@ mod tests {
         Issue {
             id: id.to_string(),
             title: title.to_string(),
-            description: None,
             status: "closed".to_string(),
             priority,
             issue_type: "task".to_string(),
             created_at: "2024-01-01".to_string(),
-            created_by: None,
             updated_at: "2024-01-01".to_string(),
-            labels: None,
-            parent: None,
-            dependencies: None,
-            dependents: None,
-            notes: None,
-            design: None,
-            acceptance_criteria: None,
+            ..Default::default()
         }
     }
 
@@ -980,7 +2014,6 @@ mod tests {
             issues,
             expanded,
             HashSet::new(),
-            HashSet::new(),
             HierarchyMode::IdBased
         );
 
@@ -1021,7 +2054,6 @@ mod tests {
             issues,
             expanded,
             HashSet::new(),
-            HashSet::new(),
             HierarchyMode::IdBased
         );
 
@@ -1051,7 +2083,6 @@ mod tests {
             issues,
             HashSet::new(),
             dep_expanded,
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
 
@@ -1092,7 +2123,6 @@ mod tests {
             issues,
             HashSet::new(),
             HashSet::new(), // not expanded - closed items will auto-traverse when hidden
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
 
@@ -1151,7 +2181,6 @@ mod tests {
             issues,
             HashSet::new(),
             dep_expanded,
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
 
@@ -1193,7 +2222,6 @@ mod tests {
             issues,
             HashSet::new(),
             dep_expanded,
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
 
@@ -1233,7 +2261,6 @@ mod tests {
             issues,
             HashSet::new(),
             dep_expanded,
-            HashSet::new(),
             HierarchyMode::DependencyBased
         );
 
@@ -1246,4 +2273,702 @@ mod tests {
         // All 7 items should be visible
         assert_eq!(tree.visible_items.len(), 7);
     }
+
+    #[test]
+    fn test_dep_refs_repeat_multi_parent_issues() {
+        let issues = vec![
+            make_issue_with_deps("root1", "Root 1", vec![]),
+            make_issue_with_deps("root2", "Root 2", vec![]),
+            make_issue_with_deps("multi", "Multi-parent", vec!["root1", "root2"]),
+            make_issue_with_deps("leaf", "Leaf", vec!["multi"]),
+        ];
+        let dep_expanded: HashSet<String> = ["root1", "root2", "multi"].iter().map(|s| s.to_string()).collect();
+        let mut tree = make_tree_dep_mode(issues, dep_expanded);
+        assert_eq!(tree.visible_items, vec!["root1", "multi", "leaf", "root2"]);
+
+        tree.toggle_dep_refs();
+        assert_eq!(tree.visible_items.len(), 5);
+        let ref_row = tree.visible_items[4].clone();
+        assert!(IssueTree::is_dep_ref(&ref_row));
+        assert_eq!(tree.dep_refs[&ref_row], DepRef {
+            id: "multi".to_string(),
+            parent: "root2".to_string(),
+            depth: 1,
+        });
+        // The reference row's own children aren't repeated
+        assert_eq!(tree.visible_items.iter().filter(|id| *id == "leaf").count(), 1);
+
+        // A reference row stands for its issue, and expanding it leads to the first occurrence
+        tree.cursor = 4;
+        assert_eq!(tree.selected_id(), Some("multi"));
+        tree.expand();
+        assert_eq!(tree.cursor, 1);
+        assert!(tree.is_expanded_in_current_mode("multi"));
+
+        // Collapsing from a reference row moves to the row it's shown under
+        tree.cursor = 4;
+        tree.collapse();
+        assert_eq!(tree.selected_item(), Some("root2"));
+
+        // Hiding references keeps the issue selected
+        tree.cursor = 4;
+        tree.toggle_dep_refs();
+        assert_eq!(tree.selected_item(), Some("multi"));
+        assert!(tree.dep_refs.is_empty());
+    }
+
+    #[test]
+    fn test_dep_refs_only_in_deps_view() {
+        let issues = vec![
+            make_issue_with_deps("root1", "Root 1", vec![]),
+            make_issue_with_deps("root2", "Root 2", vec![]),
+            make_issue_with_deps("multi", "Multi-parent", vec!["root1", "root2"]),
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::new());
+        tree.show_dep_refs = true;
+        tree.expanded = ["root1", "root2"].iter().map(|s| s.to_string()).collect();
+        tree.set_hierarchy_mode(HierarchyMode::IdBased);
+        assert!(tree.dep_refs.is_empty());
+        assert!(!tree.jump_to_primary());
+    }
+
+    // === Ready computation tests ===
+
+    #[test]
+    fn test_ready_computed_from_dependencies() {
+        let mut closed_blocker = make_issue_with_deps("done", "Done", vec![]);
+        closed_blocker.status = "closed".to_string();
+        let mut related = make_issue_with_deps("related", "Related", vec!["open"]);
+        related.dependencies.as_mut().unwrap()[0].dependency_type = Some("related".to_string());
+        let mut explicitly_blocked = make_issue_with_deps("stuck", "Stuck", vec![]);
+        explicitly_blocked.status = "blocked".to_string();
+
+        let issues = vec![
+            make_issue_with_deps("open", "Open", vec![]),
+            closed_blocker,
+            make_issue_with_deps("blocked", "Blocked", vec!["open"]),
+            make_issue_with_deps("unblocked", "Unblocked", vec!["done"]),
+            related,
+            explicitly_blocked,
+            make_issue_with_deps("external", "External blocker", vec!["other-repo-1"]),
+        ];
+
+        let tree = make_tree(issues, HashSet::new());
+
+        let expected: HashSet<String> = ["open", "unblocked", "related", "external"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(tree.ready_ids, expected);
+    }
+
+    #[test]
+    fn test_cross_check_ready() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        assert!(tree.ready_mismatch_ids.is_empty());
+
+        // bd agrees
+        tree.cross_check_ready(&HashSet::from(["a".to_string()]));
+        assert!(tree.ready_mismatch_ids.is_empty());
+
+        // bd says b is ready and a isn't; unknown IDs are ignored
+        tree.cross_check_ready(&HashSet::from(["b".to_string(), "ghost".to_string()]));
+        let expected: HashSet<String> = HashSet::from(["a".to_string(), "b".to_string()]);
+        assert_eq!(tree.ready_mismatch_ids, expected);
+    }
+
+    // === Selection tests ===
+
+    #[test]
+    fn test_select_issue_expands_ancestors() {
+        let issues = vec![
+            make_issue("bsv-a", "Epic", 1),
+            make_issue("bsv-a.1", "Child", 2),
+            make_issue("bsv-a.1.1", "Grandchild", 2),
+            make_issue("bsv-b", "Other", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-b"]);
+
+        assert!(tree.select_issue("bsv-a.1.1"));
+        assert_eq!(tree.selected_id(), Some("bsv-a.1.1"));
+        assert!(tree.expanded.contains("bsv-a"));
+        assert!(tree.expanded.contains("bsv-a.1"));
+
+        assert!(!tree.select_issue("bsv-missing"));
+        assert_eq!(tree.selected_id(), Some("bsv-a.1.1"));
+    }
+
+    #[test]
+    fn test_select_issue_in_dep_mode() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec!["b"]),
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::new());
+        assert_eq!(tree.visible_items, vec!["a"]);
+
+        assert!(tree.select_issue("c"));
+        assert_eq!(tree.visible_items, vec!["a", "b", "c"]);
+        assert_eq!(tree.cursor, 2);
+    }
+
+    // === Local update tests ===
+
+    #[test]
+    fn test_update_issue_close_hides_and_unblocks() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec![]),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        assert!(!tree.ready_ids.contains("b"));
+        tree.cursor = 0;
+        assert_eq!(tree.selected_id(), Some("a"));
+
+        assert!(tree.update_issue("a", |i| i.status = "closed".to_string()));
+
+        // Closing the blocker makes b ready, and a disappears with closed issues hidden
+        assert!(tree.ready_ids.contains("b"));
+        assert!(!tree.ready_ids.contains("a"));
+        assert!(!tree.visible_items.contains(&"a".to_string()));
+        // The cursor stays on the same row, now the next issue
+        assert_eq!(tree.cursor, 0);
+
+        // Reopening brings it back and blocks b again
+        tree.toggle_show_closed();
+        tree.update_issue("a", |i| i.status = "open".to_string());
+        tree.toggle_show_closed();
+        assert!(tree.visible_items.contains(&"a".to_string()));
+        assert!(!tree.ready_ids.contains("b"));
+
+        assert!(!tree.update_issue("missing", |i| i.status = "closed".to_string()));
+    }
+
+    #[test]
+    fn test_update_issue_keeps_selection() {
+        let issues = vec![
+            make_issue("bsv-a", "A", 1),
+            make_issue("bsv-b", "B", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        tree.cursor = 1;
+
+        // Raising bsv-b's priority moves it to the top; the cursor follows it
+        tree.update_issue("bsv-b", |i| i.priority = 0);
+        assert_eq!(tree.visible_items, vec!["bsv-b", "bsv-a"]);
+        assert_eq!(tree.selected_id(), Some("bsv-b"));
+    }
+
+    // === Cycle detection tests ===
+
+    #[test]
+    fn test_dependency_cycle() {
+        let mut related = make_issue_with_deps("d", "D", vec!["c"]);
+        related.dependencies.as_mut().unwrap()[0].dependency_type = Some("related".to_string());
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec!["b"]),
+            related,
+        ];
+        let tree = make_tree(issues, HashSet::new());
+
+        // c depends on b, which depends on a: a can't depend on c
+        assert_eq!(
+            tree.dependency_cycle("a", "c", "blocks"),
+            Some(vec!["a".to_string(), "c".to_string(), "b".to_string(), "a".to_string()])
+        );
+        assert_eq!(
+            tree.dependency_cycle("a", "a", "blocks"),
+            Some(vec!["a".to_string(), "a".to_string()])
+        );
+        assert!(tree.dependency_cycle("a", "c", "parent-child").is_some());
+
+        // Related links are ignored in both directions
+        assert_eq!(tree.dependency_cycle("a", "c", "related"), None);
+        assert_eq!(tree.dependency_cycle("c", "d", "blocks"), None);
+        assert_eq!(tree.dependency_cycle("c", "a", "blocks"), None);
+    }
+
+    #[test]
+    fn test_dep_cycles_found_and_kept_visible() {
+        // a → b → c → a nothing else blocks; d ⇄ e hangs below f; g depends on itself
+        let mut related = make_issue_with_deps("h", "H", vec!["a"]);
+        related.dependencies.as_mut().unwrap()[0].dependency_type = Some("related".to_string());
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec!["c"]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec!["b"]),
+            make_issue_with_deps("d", "D", vec!["e", "f"]),
+            make_issue_with_deps("e", "E", vec!["d"]),
+            make_issue_with_deps("f", "F", vec![]),
+            make_issue_with_deps("g", "G", vec!["g"]),
+            related,
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::new());
+
+        assert_eq!(tree.dep_cycles, vec![vec!["a", "b", "c"], vec!["d", "e"], vec!["g"]]);
+        assert_eq!(tree.cycle_edges(&tree.dep_cycles[0]), vec![
+            ("a".to_string(), "c".to_string(), "blocks".to_string()),
+            ("b".to_string(), "a".to_string(), "blocks".to_string()),
+            ("c".to_string(), "b".to_string(), "blocks".to_string()),
+        ]);
+
+        // Cycles that nothing else blocks get a root, so no issue disappears
+        assert_eq!(tree.dep_root_ids, vec!["a", "f", "g", "h"]);
+        tree.toggle_expand_all();
+        let mut visible = tree.visible_items.clone();
+        visible.sort();
+        assert_eq!(visible, vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
+    }
+
+    #[test]
+    fn test_critical_path_and_blocking_impact() {
+        // e waits on d and c; d on b, b on a. The closed x blocks nothing any more.
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec!["x"]),
+            make_issue_with_deps("d", "D", vec!["b"]),
+            make_issue_with_deps("e", "E", vec!["c", "d"]),
+            make_closed_issue("x", "X", 2),
+        ];
+        let tree = make_tree_dep_mode(issues, HashSet::new());
+
+        assert_eq!(tree.critical_path("e"), vec!["d", "b", "a"]);
+        assert_eq!(tree.critical_path("c"), Vec::<String>::new());
+        assert_eq!(tree.transitive_dependents("a"), 3);
+        assert_eq!(tree.transitive_dependents("x"), 2);
+        assert_eq!(tree.blocking_impact(), vec![
+            ("a".to_string(), 3),
+            ("b".to_string(), 2),
+            ("c".to_string(), 1),
+            ("d".to_string(), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_parent_child_links_dont_block() {
+        // a is an epic with child b; c is blocked by b
+        let mut b = make_issue_with_deps("b", "B", vec!["a"]);
+        b.dependencies.as_mut().unwrap()[0].dependency_type = Some("parent-child".to_string());
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            b,
+            make_issue_with_deps("c", "C", vec!["b"]),
+        ];
+        let tree = make_tree_dep_mode(issues, HashSet::new());
+
+        assert!(tree.ready_ids.contains("b"));
+        assert_eq!(tree.critical_path("b"), Vec::<String>::new());
+        assert_eq!(tree.critical_path("c"), vec!["b"]);
+        assert_eq!(tree.transitive_dependents("a"), 0);
+        assert_eq!(tree.transitive_dependents("b"), 1);
+        assert_eq!(tree.blocking_impact(), vec![("b".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_critical_path_survives_cycles() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec!["b"]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "C", vec!["a"]),
+        ];
+        let tree = make_tree_dep_mode(issues, HashSet::new());
+
+        assert_eq!(tree.critical_path("c"), vec!["a", "b"]);
+        assert_eq!(tree.transitive_dependents("a"), 2);
+    }
+
+    #[test]
+    fn test_ready_queue_order() {
+        // Among the P1s, b unblocks two issues and c one; d is blocked, e closed
+        let mut issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec![]),
+            make_issue_with_deps("c", "C", vec![]),
+            make_issue_with_deps("d", "D", vec!["b"]),
+            make_issue_with_deps("f", "F", vec!["d", "c"]),
+            make_closed_issue("e", "E", 0),
+            make_issue_with_deps("g", "G", vec![]),
+        ];
+        issues[0].priority = 2;
+        issues[1].priority = 1;
+        issues[2].priority = 1;
+        issues[6].priority = 0;
+        let mut tree = IssueTree::from_issues(issues, HashSet::from(["a".to_string()]), HashSet::new(), HierarchyMode::ReadyQueue);
+
+        assert_eq!(tree.visible_items, vec!["g", "b", "c", "a"]);
+        assert!(tree.visible_items.iter().all(|id| tree.nodes[id].depth == 0));
+
+        // Flat: nothing to expand, and the Epics expansion is left alone
+        tree.cursor = 3;
+        tree.collapse();
+        tree.toggle_expand_all();
+        assert!(tree.expanded.contains("a"));
+
+        // Finishing b moves d up into the queue
+        tree.update_issue("b", |i| i.status = "closed".to_string());
+        assert_eq!(tree.visible_items, vec!["g", "c", "d", "a"]);
+    }
+
+    #[test]
+    fn test_timeline_by_day() {
+        // "Now" is 2026-10-16 12:00 UTC
+        let mut issues = vec![
+            make_issue("a", "Created today", 2),
+            make_issue("b", "Updated today", 2),
+            make_closed_issue("c", "Closed yesterday", 2),
+            make_issue("d", "Old", 2),
+            make_issue("e", "No timestamps", 2),
+        ];
+        issues[0].created_at = "2026-10-16T09:00:00Z".to_string();
+        issues[0].updated_at = "2026-10-16T09:00:00Z".to_string();
+        issues[1].updated_at = "2026-10-16T11:00:00Z".to_string();
+        issues[2].updated_at = "2026-10-15T18:00:00Z".to_string();
+        issues[2].closed_at = Some("2026-10-15T18:00:00Z".to_string());
+        issues[4].created_at = String::new();
+        issues[4].updated_at = String::new();
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::Timeline);
+        tree.now = activity::parse_timestamp("2026-10-16T12:00:00Z").unwrap();
+        tree.utc_offset = 0;
+        tree.show_closed = true;
+        tree.expand_recent_days();
+
+        assert_eq!(tree.visible_items, vec![
+            "group:day:2026-10-16", "b", "a",
+            "group:day:2026-10-15", "c",
+            "group:day:2024-01-01",
+        ]);
+        assert_eq!(tree.groups["group:day:2026-10-16"], GroupHeader { name: "Today".to_string(), count: 2 });
+        assert_eq!(tree.groups["group:day:2026-10-15"].name, "Yesterday");
+        assert_eq!(tree.groups["group:day:2024-01-01"], GroupHeader { name: "Mon 1 Jan 2024".to_string(), count: 1 });
+
+        // Older days start collapsed; selecting an issue opens its day
+        assert!(tree.select_issue("d"));
+        assert_eq!(&tree.visible_items[5..], ["group:day:2024-01-01", "d"]);
+        tree.collapse();
+        assert_eq!(tree.selected_item(), Some("group:day:2024-01-01"));
+
+        // Closed issues drop out with the rest of the tree
+        tree.toggle_show_closed();
+        assert!(!tree.visible_items.contains(&"c".to_string()));
+        assert!(!tree.groups.contains_key("group:day:2026-10-15"));
+    }
+
+    // === Search tests ===
+
+    #[test]
+    fn test_issue_matches_fields_case_insensitively() {
+        let mut issue = make_issue("bsv-a", "Parser rewrite", 1);
+        issue.description = Some("Handle UTF-8 input".to_string());
+        issue.labels = Some(vec!["backend".to_string()]);
+        let mut tree = make_tree(vec![issue, make_issue("bsv-b", "Docs", 2)], HashSet::new());
+
+        assert!(!tree.issue_matches("bsv-a"));
+        for query in ["bsv-a", "parser", "utf-8", "backend"] {
+            tree.search = Some(query.to_string());
+            assert!(tree.issue_matches("bsv-a"), "{}", query);
+            assert!(!tree.issue_matches("bsv-b"), "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_jump_to_match_expands_and_wraps() {
+        let issues = vec![
+            make_issue("bsv-a", "Epic", 1),
+            make_issue("bsv-a.1", "Fix parser", 2),
+            make_issue("bsv-b", "Parser docs", 2),
+            make_issue("bsv-b.1", "Unrelated", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        tree.search = Some("parser".to_string());
+        assert_eq!(tree.search_match_count(), 2);
+
+        // The first hit is inside a collapsed epic
+        assert!(tree.jump_to_match(true, false));
+        assert_eq!(tree.selected_id(), Some("bsv-a.1"));
+        assert!(tree.expanded.contains("bsv-a"));
+
+        assert!(tree.jump_to_match(true, false));
+        assert_eq!(tree.selected_id(), Some("bsv-b"));
+        // bsv-b stays collapsed: its child was never a match
+        assert!(!tree.expanded.contains("bsv-b"));
+
+        // Wraps around in both directions
+        assert!(tree.jump_to_match(true, false));
+        assert_eq!(tree.selected_id(), Some("bsv-a.1"));
+        assert!(tree.jump_to_match(false, false));
+        assert_eq!(tree.selected_id(), Some("bsv-b"));
+
+        // Search-as-you-type stays on a selected match
+        assert!(tree.jump_to_match(true, true));
+        assert_eq!(tree.selected_id(), Some("bsv-b"));
+
+        tree.search = Some("nothing".to_string());
+        assert!(!tree.jump_to_match(true, false));
+        assert_eq!(tree.selected_id(), Some("bsv-b"));
+    }
+
+    #[test]
+    fn test_jump_to_match_in_dep_mode() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "Needle", vec!["b"]),
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::new());
+        tree.search = Some("needle".to_string());
+
+        assert!(tree.jump_to_match(true, false));
+        assert_eq!(tree.selected_id(), Some("c"));
+        assert!(tree.dep_expanded.contains("a"));
+        assert!(tree.dep_expanded.contains("b"));
+    }
+
+    // === Filter tests ===
+
+    fn filter(source: &str) -> Option<Filter> {
+        Some(Filter::parse(source, &Default::default()).unwrap())
+    }
+
+    #[test]
+    fn test_board_columns_by_status() {
+        let status = |id: &str, priority: i32, status: &str| {
+            let mut issue = make_issue(id, id, priority);
+            issue.status = status.to_string();
+            issue
+        };
+        let issues = vec![
+            status("bsv-a", 2, "open"),
+            status("bsv-b", 1, "open"),
+            status("bsv-c", 2, "in_progress"),
+            status("bsv-d", 2, "blocked"),
+            status("bsv-e", 2, "closed"),
+            status("bsv-f", 2, "deferred"),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        assert_eq!(tree.board_columns(), vec![
+            vec!["bsv-b", "bsv-a"],
+            vec!["bsv-c"],
+            vec!["bsv-d"],
+            vec![],
+        ]);
+
+        tree.toggle_show_closed();
+        assert_eq!(tree.board_columns()[3], vec!["bsv-e"]);
+
+        tree.filter = filter("priority:1");
+        tree.rebuild_visible();
+        assert_eq!(tree.board_columns(), vec![vec!["bsv-b"], vec![], vec![], vec![]]);
+    }
+
+    #[test]
+    fn test_filter_keeps_ancestors_as_context() {
+        let mut bug = make_issue("bsv-a.1.1", "Crash", 1);
+        bug.issue_type = "bug".to_string();
+        let issues = vec![
+            make_issue("bsv-a", "Epic", 1),
+            make_issue("bsv-a.1", "Feature", 2),
+            bug,
+            make_issue("bsv-a.2", "Other", 2),
+            make_issue("bsv-b", "Unrelated", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        tree.filter = filter("type:bug");
+        tree.rebuild_visible();
+
+        // The collapsed ancestors are opened up to reach the match
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-a.1", "bsv-a.1.1"]);
+        assert!(tree.is_filter_context("bsv-a"));
+        assert!(!tree.is_filter_context("bsv-a.1.1"));
+
+        tree.filter = None;
+        tree.rebuild_visible();
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-b"]);
+    }
+
+    #[test]
+    fn test_filter_matching_parent_respects_expansion() {
+        let issues = vec![
+            make_issue("bsv-a", "Epic", 1),
+            make_issue("bsv-a.1", "Child", 1),
+            make_issue("bsv-b", "Other", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        tree.filter = filter("priority:1");
+        tree.rebuild_visible();
+        // bsv-a.1 matches, so its ancestor bsv-a is opened even though it also matches
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-a.1"]);
+
+        tree.filter = filter("id:bsv-a -priority:1");
+        tree.rebuild_visible();
+        assert!(tree.visible_items.is_empty());
+    }
+
+    #[test]
+    fn test_filter_in_dep_mode() {
+        let issues = vec![
+            make_issue_with_deps("a", "A", vec![]),
+            make_issue_with_deps("b", "B", vec!["a"]),
+            make_issue_with_deps("c", "Needle", vec!["b"]),
+            make_issue_with_deps("d", "D", vec!["a"]),
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::new());
+        tree.filter = filter("needle");
+        tree.rebuild_visible();
+        assert_eq!(tree.visible_items, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_status_filter_shows_closed_issues() {
+        let issues = vec![
+            make_issue("bsv-a", "Open", 1),
+            make_closed_issue("bsv-b", "Done", 2),
+        ];
+        let mut tree = make_tree(issues, HashSet::new());
+        assert_eq!(tree.visible_items, vec!["bsv-a"]);
+
+        tree.filter = filter("status:closed");
+        tree.rebuild_visible();
+        assert_eq!(tree.visible_items, vec!["bsv-b"]);
+
+        // Other filters leave closed issues hidden
+        tree.filter = filter("priority:>=0");
+        tree.rebuild_visible();
+        assert_eq!(tree.visible_items, vec!["bsv-a"]);
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("bsv-a.2", "bsv-a.10"), Ordering::Less);
+        assert_eq!(natural_cmp("bsv-a.10", "bsv-a.9"), Ordering::Greater);
+        assert_eq!(natural_cmp("bsv-a.02", "bsv-a.2"), Ordering::Equal);
+        assert_eq!(natural_cmp("bsv-a", "bsv-a.1"), Ordering::Less);
+        assert_eq!(natural_cmp("bsv-b", "bsv-a.1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_sort_modes_apply_to_roots_and_children() {
+        let mut issues = vec![
+            make_issue("bsv-a", "Epic", 2),
+            make_issue("bsv-a.2", "Second", 0),
+            make_issue("bsv-a.10", "Tenth", 1),
+            make_issue("bsv-b", "Other", 1),
+        ];
+        issues[0].updated_at = "2024-03-01".to_string();
+        issues[1].created_at = "2023-12-01".to_string();
+        issues[2].status = "in_progress".to_string();
+        issues[3].created_at = "2023-06-01".to_string();
+        let mut tree = make_tree(issues, HashSet::from(["bsv-a".to_string()]));
+        assert_eq!(tree.visible_items, vec!["bsv-b", "bsv-a", "bsv-a.2", "bsv-a.10"]);
+
+        tree.set_sort(SortMode::Id, false);
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-a.2", "bsv-a.10", "bsv-b"]);
+
+        tree.set_sort(SortMode::Id, true);
+        assert_eq!(tree.visible_items, vec!["bsv-b", "bsv-a", "bsv-a.10", "bsv-a.2"]);
+
+        // Newest update first; ties fall back to priority
+        tree.set_sort(SortMode::Updated, false);
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-a.2", "bsv-a.10", "bsv-b"]);
+
+        tree.set_sort(SortMode::Created, false);
+        assert_eq!(tree.visible_items, vec!["bsv-b", "bsv-a", "bsv-a.2", "bsv-a.10"]);
+
+        tree.set_sort(SortMode::Status, false);
+        assert_eq!(tree.visible_items, vec!["bsv-b", "bsv-a", "bsv-a.10", "bsv-a.2"]);
+
+        // Sorting survives local updates
+        tree.update_issue("bsv-b", |i| i.status = "blocked".to_string());
+        assert_eq!(tree.visible_items, vec!["bsv-a", "bsv-a.10", "bsv-a.2", "bsv-b"]);
+    }
+
+    #[test]
+    fn test_sort_in_dep_mode_keeps_selection() {
+        let issues = vec![
+            make_issue("bsv-root", "Root", 2),
+            make_issue_with_deps("bsv-x.10", "Late", vec!["bsv-root"]),
+            make_issue_with_deps("bsv-x.9", "Early", vec!["bsv-root"]),
+            make_issue("bsv-lone", "Alone", 0),
+        ];
+        let mut tree = make_tree_dep_mode(issues, HashSet::from(["bsv-root".to_string()]));
+        assert!(tree.select_issue("bsv-x.9"));
+
+        tree.set_sort(SortMode::Id, false);
+        assert_eq!(tree.visible_items, vec!["bsv-lone", "bsv-root", "bsv-x.9", "bsv-x.10"]);
+        assert_eq!(tree.selected_id(), Some("bsv-x.9"));
+        assert_eq!(tree.dep_root_ids, vec!["bsv-lone", "bsv-root"]);
+    }
+
+    fn make_grouped_tree(issues: Vec<Issue>, group_by: GroupBy) -> IssueTree {
+        IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::Grouped(group_by))
+    }
+
+    #[test]
+    fn test_group_by_label_lists_issues_under_each_label() {
+        let mut a = make_issue("bsv-a", "Alpha", 1);
+        a.labels = Some(vec!["ui".to_string(), "backend".to_string()]);
+        let mut b = make_issue("bsv-a.1", "Beta", 0);
+        b.labels = Some(vec!["ui".to_string()]);
+        let mut closed = make_closed_issue("bsv-c", "Gamma", 0);
+        closed.labels = Some(vec!["docs".to_string()]);
+        let issues = vec![a, b, closed, make_issue("bsv-d", "Delta", 2)];
+        let mut tree = make_grouped_tree(issues, GroupBy::Label);
+
+        // Headers only, in name order with unlabeled last; the closed-only group is hidden
+        assert_eq!(tree.visible_items, vec!["group:label:backend", "group:label:ui", "group:label:(no label)"]);
+        assert_eq!(tree.groups["group:label:ui"], GroupHeader { name: "ui".to_string(), count: 2 });
+        assert_eq!(tree.selected_id(), None);
+        assert_eq!(tree.selected_item(), Some("group:label:backend"));
+
+        // Headers expand like any other node; issues are listed flat
+        tree.cursor = 1;
+        tree.toggle_expand();
+        assert_eq!(tree.visible_items, vec![
+            "group:label:backend", "group:label:ui", "bsv-a.1", "bsv-a", "group:label:(no label)",
+        ]);
+        assert_eq!(tree.nodes["bsv-a"].depth, 1);
+        assert!(!tree.has_children_in_current_mode("bsv-a"));
+
+        // Collapsing from an issue goes to its header
+        tree.cursor = 3;
+        tree.collapse();
+        assert_eq!(tree.selected_item(), Some("group:label:ui"));
+
+        tree.toggle_show_closed();
+        assert!(tree.visible_items.contains(&"group:label:docs".to_string()));
+    }
+
+    #[test]
+    fn test_group_header_order_and_navigation() {
+        let mut issues = vec![
+            make_issue("bsv-a", "Alpha", 10),
+            make_issue("bsv-b", "Beta", 2),
+            make_issue("bsv-c", "Gamma", 0),
+        ];
+        issues[0].status = "in_progress".to_string();
+        issues[1].assignee = Some("sam".to_string());
+        let mut tree = make_grouped_tree(issues, GroupBy::Priority);
+        assert_eq!(tree.visible_items, vec!["group:priority:P0", "group:priority:P2", "group:priority:P10"]);
+
+        tree.set_hierarchy_mode(HierarchyMode::Grouped(GroupBy::Status));
+        assert_eq!(tree.visible_items, vec!["group:status:in_progress", "group:status:open"]);
+
+        tree.set_hierarchy_mode(HierarchyMode::Grouped(GroupBy::Assignee));
+        assert_eq!(tree.visible_items, vec!["group:assignee:sam", "group:assignee:(unassigned)"]);
+
+        // Selecting an issue expands its header; Tab toggles this grouping's headers
+        assert!(tree.select_issue("bsv-c"));
+        assert!(tree.group_expanded.contains("group:assignee:(unassigned)"));
+        tree.toggle_expand_all();
+        assert_eq!(tree.visible_items.len(), 2);
+        tree.toggle_expand_all();
+        assert_eq!(tree.visible_items.len(), 5);
+    }
 }
@@ -1,10 +1,13 @@
+use crate::activity::{self, Activity};
 use crate::bd::Issue;
-use crate::tree::IssueTree;
+use crate::graph::{DepGraph, NODE_WIDTH};
+use crate::stats::{ProjectStats, THROUGHPUT_WEEKS};
+use crate::tree::{IssueTree, Rollup, BOARD_STATUSES};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Clear, List, ListItem, ListState, Paragraph, Sparkline, Wrap},
     Frame,
 };
 
@@ -273,8 +276,80 @@ fn parse_inline_markdown(text: &str) -> String {
     text.to_string()
 }
 
+/// Forms and popups drawn over the main panels, if open
+#[derive(Default, Clone, Copy)]
+pub struct Overlays<'a> {
+    /// New issue form, shown in place of the details panel
+    pub create_form: Option<&'a crate::CreateForm>,
+    /// One-line prompt along the bottom
+    pub prompt: Option<&'a crate::Prompt>,
+    /// Priority/type/label picker
+    pub picker: Option<&'a crate::Picker>,
+    /// Dependency manager
+    pub dep_editor: Option<&'a crate::DepEditor>,
+    /// Ctrl+P issue finder
+    pub finder: Option<&'a crate::IssueSearch>,
+    /// `I` blocking-impact list
+    pub impact_list: Option<&'a crate::ImpactList>,
+    /// `C` dependency cycle list
+    pub cycle_list: Option<&'a crate::CycleList>,
+    /// `V` dependency graph view
+    pub graph_view: Option<&'a DepGraph>,
+    /// Kanban board, shown in place of both panels
+    pub board: Option<&'a crate::Board>,
+    /// `#` project statistics dashboard
+    pub stats: Option<&'a ProjectStats>,
+}
+
+/// What the tree knows about the issue in the details panel, beyond the issue itself
+#[derive(Debug, Default)]
+pub struct DetailExtras<'a> {
+    /// How the issue's parent sources disagree
+    pub parent_conflict: Option<&'a str>,
+    /// Progress of its descendants, if it has children
+    pub rollup: Option<&'a Rollup>,
+    /// Where it sits in the dependency graph, in Deps mode
+    pub deps: Option<&'a DepSummary>,
+}
+
+impl<'a> DetailExtras<'a> {
+    fn new(tree: &'a IssueTree, id: &str, deps: Option<&'a DepSummary>) -> Self {
+        let is_deps = tree.hierarchy_mode == crate::HierarchyMode::DependencyBased;
+        DetailExtras {
+            parent_conflict: tree.parent_conflicts.get(id).map(|c| c.as_str()),
+            rollup: tree.rollup(id),
+            deps: deps.filter(|_| is_deps),
+        }
+    }
+}
+
+/// An issue's place in the dependency graph. Both parts walk the graph, so the app
+/// works this out when the selection or the tree changes, not on every frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepSummary {
+    /// Longest chain of open blockers as (ID, title, ready)
+    pub critical_path: Vec<(String, String, bool)>,
+    /// Open issues waiting on it, directly or not
+    pub unblocks: usize,
+}
+
+impl DepSummary {
+    pub fn new(tree: &IssueTree, id: &str) -> Self {
+        DepSummary {
+            critical_path: tree.critical_path(id).into_iter()
+                .map(|b| {
+                    let title = tree.nodes[&b].issue.title.clone();
+                    let is_ready = tree.ready_ids.contains(&b);
+                    (b, title, is_ready)
+                })
+                .collect(),
+            unblocks: tree.transitive_dependents(id),
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
-pub fn render(frame: &mut Frame, tree: &IssueTree, selected_details: Option<&Issue>, show_help: bool, focus: crate::Focus, detail_scroll: u16, edit_state: Option<&crate::EditState>, panel_ratio: f32, tree_scroll: usize, daemon_slow: bool, is_loading: bool) {
+pub fn render(frame: &mut Frame, tree: &IssueTree, selected_details: Option<&Issue>, dep_summary: Option<&DepSummary>, show_help: bool, focus: crate::Focus, detail_scroll: u16, edit_state: Option<&crate::EditState>, panel_ratio: f32, tree_scroll: usize, status_note: Option<&str>, is_loading: bool, overlays: Overlays) {
     // Convert ratio to percentages, clamped to reasonable bounds
     let left_percent = ((panel_ratio.clamp(0.15, 0.85)) * 100.0) as u16;
     let right_percent = 100 - left_percent;
@@ -284,19 +359,644 @@ pub fn render(frame: &mut Frame, tree: &IssueTree, selected_details: Option<&Iss
         .constraints([Constraint::Percentage(left_percent), Constraint::Percentage(right_percent)])
         .split(frame.area());
 
-    let tree_focused = focus == crate::Focus::Tree;
-    render_tree_panel(frame, tree, chunks[0], tree_focused, tree_scroll, daemon_slow, is_loading);
+    if let Some(board) = overlays.board {
+        render_board(frame, tree, board, frame.area(), status_note);
+    } else {
+        let tree_focused = focus == crate::Focus::Tree;
+        render_tree_panel(frame, tree, chunks[0], tree_focused, tree_scroll, status_note, is_loading);
+
+        // Use full details if available (has dependencies), otherwise fall back to tree node
+        let issue_for_details = selected_details.or_else(|| tree.selected_node().map(|n| &n.issue));
+        let extras = issue_for_details.map(|i| DetailExtras::new(tree, &i.id, dep_summary)).unwrap_or_default();
+        match overlays.create_form {
+            Some(form) => render_create_panel(frame, form, chunks[1]),
+            None => render_detail_panel(frame, issue_for_details, &tree.ready_ids, &extras, chunks[1], !tree_focused, detail_scroll, edit_state),
+        }
+    }
+
+    if let Some(prompt) = overlays.prompt {
+        render_prompt(frame, prompt);
+    }
+
+    if let Some(picker) = overlays.picker {
+        render_picker(frame, picker);
+    }
+
+    if let Some(editor) = overlays.dep_editor {
+        render_dep_editor(frame, editor);
+    }
+
+    if let Some(finder) = overlays.finder {
+        render_finder(frame, finder);
+    }
+
+    if let Some(list) = overlays.impact_list {
+        render_impact_list(frame, list);
+    }
+
+    if let Some(list) = overlays.cycle_list {
+        render_cycle_list(frame, list);
+    }
+
+    if let Some(graph) = overlays.graph_view {
+        render_graph_view(frame, tree, graph);
+    }
 
-    // Use full details if available (has dependencies), otherwise fall back to tree node
-    let issue_for_details = selected_details.or_else(|| tree.selected_node().map(|n| &n.issue));
-    render_detail_panel(frame, issue_for_details, &tree.ready_ids, chunks[1], !tree_focused, detail_scroll, edit_state);
+    if let Some(stats) = overlays.stats {
+        render_stats(frame, tree, stats);
+    }
 
     if show_help {
         render_help_overlay(frame);
     }
 }
 
-fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: bool, tree_scroll: usize, daemon_slow: bool, is_loading: bool) {
+/// Rect of the given size centered in `area` (clamped to fit)
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Popup list for the priority/type pickers and the label editor
+fn render_picker(frame: &mut Frame, picker: &crate::Picker) {
+    use crate::PickerKind;
+
+    let mut lines: Vec<Line> = Vec::new();
+    if picker.kind == PickerKind::Labels {
+        let mut input = vec![Span::styled("Filter/add: ", Style::default().fg(Color::DarkGray))];
+        input.extend(edit_buffer_lines(&picker.input).remove(0).spans);
+        lines.push(Line::from(input));
+        lines.push(Line::from(""));
+    }
+
+    let options = picker.visible_options();
+    for (i, option) in options.iter().enumerate() {
+        let text = match picker.kind {
+            PickerKind::Priority => {
+                let priority: usize = option.parse().unwrap_or(0);
+                format!("P{}  {}", priority, crate::PRIORITY_NAMES.get(priority).unwrap_or(&""))
+            }
+            PickerKind::IssueType => option.to_string(),
+            PickerKind::Labels => {
+                let mark = if picker.checked.contains(*option) { "[x]" } else { "[ ]" };
+                format!("{} {}", mark, option)
+            }
+        };
+        let style = if i == picker.cursor {
+            Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!(" {} ", text), style)));
+    }
+    if options.is_empty() {
+        lines.push(Line::from(Span::styled(" Enter adds a new label ", Style::default().fg(Color::DarkGray))));
+    }
+
+    let hint = match picker.kind {
+        PickerKind::Priority => " Enter/0-4=set  Esc=cancel ",
+        PickerKind::IssueType => " Enter=set  Esc=cancel ",
+        PickerKind::Labels => " Space=toggle Tab=complete Enter=add/apply ",
+    };
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0)
+        .max(picker.title().len())
+        .max(hint.chars().count()) as u16 + 2;
+    let area = centered_rect(frame.area(), width, lines.len() as u16 + 2);
+
+    frame.render_widget(Clear, area);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(picker.title())
+            .title_bottom(Line::from(hint).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(paragraph, area);
+}
+
+/// Popup listing an issue's dependencies, or searching for one to add
+fn render_dep_editor(frame: &mut Frame, editor: &crate::DepEditor) {
+    const MAX_MATCHES: usize = 10;
+    let selected = Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+
+    let mut lines: Vec<Line> = Vec::new();
+    let hint = match &editor.search {
+        Some(search) => {
+            let mut input = vec![Span::styled(
+                format!("Add ({}): ", editor.dep_type_name()),
+                Style::default().fg(Color::DarkGray),
+            )];
+            input.extend(edit_buffer_lines(&search.input).remove(0).spans);
+            lines.push(Line::from(input));
+            lines.push(Line::from(""));
+
+            // Scroll the result list with the cursor
+            let start = search.cursor.saturating_sub(MAX_MATCHES - 1);
+            for (i, label) in search.match_labels().enumerate().skip(start).take(MAX_MATCHES) {
+                let style = if i == search.cursor { selected } else { Style::default() };
+                lines.push(Line::from(Span::styled(format!(" {} ", label), style)));
+            }
+            if search.match_count() == 0 {
+                lines.push(Line::from(Span::styled(" No matching issues ", Style::default().fg(Color::DarkGray))));
+            }
+            " Tab=type Enter=add Esc=back "
+        }
+        None => {
+            for (i, dep) in editor.deps.iter().enumerate() {
+                let dep_type = dep.dependency_type.as_deref().unwrap_or("unknown");
+                let style = if i == editor.cursor { selected } else { Style::default() };
+                lines.push(Line::from(Span::styled(
+                    format!(" {:<12} {} {} ", dep_type, dep.id, dep.title),
+                    style,
+                )));
+            }
+            if editor.deps.is_empty() {
+                lines.push(Line::from(Span::styled(" No dependencies ", Style::default().fg(Color::DarkGray))));
+            }
+            " a=add x=remove Esc=close "
+        }
+    };
+    if let Some(error) = &editor.error {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!(" {} ", error), Style::default().fg(Color::Red))));
+    }
+
+    let title = format!(" Dependencies of {} ", editor.issue_id);
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0)
+        .max(title.chars().count())
+        .max(hint.chars().count())
+        .max(40) as u16 + 2;
+    let area = centered_rect(frame.area(), width, lines.len() as u16 + 2);
+
+    frame.render_widget(Clear, area);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .title_bottom(Line::from(hint).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(paragraph, area);
+}
+
+/// Ctrl+P popup: fuzzy search over every issue
+fn render_finder(frame: &mut Frame, finder: &crate::IssueSearch) {
+    const MAX_MATCHES: usize = 12;
+    let selected = Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+
+    let mut input = vec![Span::styled("> ", Style::default().fg(Color::DarkGray))];
+    input.extend(edit_buffer_lines(&finder.input).remove(0).spans);
+    let mut lines = vec![Line::from(input), Line::from("")];
+
+    // Scroll the result list with the cursor
+    let start = finder.cursor.saturating_sub(MAX_MATCHES - 1);
+    for (i, label) in finder.match_labels().enumerate().skip(start).take(MAX_MATCHES) {
+        let style = if i == finder.cursor { selected } else { Style::default() };
+        lines.push(Line::from(Span::styled(format!(" {} ", label), style)));
+    }
+    if finder.match_count() == 0 {
+        lines.push(Line::from(Span::styled(" No matching issues ", Style::default().fg(Color::DarkGray))));
+    }
+
+    let title = format!(" Go to issue ({}) ", finder.match_count());
+    let hint = " Enter=go Esc=close ";
+    // Fixed width and height, so the popup doesn't jump around while typing
+    let width = (frame.area().width * 2 / 3).max(40);
+    let area = centered_rect(frame.area(), width, MAX_MATCHES as u16 + 4);
+
+    frame.render_widget(Clear, area);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .title_bottom(Line::from(hint).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(paragraph, area);
+}
+
+/// `I` popup: open issues ranked by how much open work waits on them
+fn render_impact_list(frame: &mut Frame, list: &crate::ImpactList) {
+    const MAX_ITEMS: usize = 12;
+    let selected = Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![];
+    // Scroll the list with the cursor
+    let start = list.cursor.saturating_sub(MAX_ITEMS - 1);
+    for (i, (_, label, count)) in list.items.iter().enumerate().skip(start).take(MAX_ITEMS) {
+        let style = if i == list.cursor { selected } else { Style::default() };
+        lines.push(Line::from(Span::styled(format!(" {:>4}  {} ", count, label), style)));
+    }
+    if list.items.is_empty() {
+        lines.push(Line::from(Span::styled(" Nothing open is blocking other work ", Style::default().fg(Color::DarkGray))));
+    }
+
+    let title = " Unblocks most work (open issues waiting) ";
+    let hint = " Enter=go Esc=close ";
+    let width = (frame.area().width * 2 / 3).max(40);
+    let area = centered_rect(frame.area(), width, lines.len() as u16 + 2);
+
+    frame.render_widget(Clear, area);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .title_bottom(Line::from(hint).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(paragraph, area);
+}
+
+/// `C` popup: each dependency cycle, then the dependencies between its members
+fn render_cycle_list(frame: &mut Frame, list: &crate::CycleList) {
+    let selected = Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![];
+    let mut cursor_line = 0;
+    for (i, cycle) in list.cycles.iter().enumerate() {
+        lines.push(Line::from(Span::styled(
+            format!(" Cycle {}: {} ", i + 1, cycle.join(", ")),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        for (edge_idx, (_, from, to, dep_type)) in list.edges.iter().enumerate().filter(|(_, e)| e.0 == i) {
+            if edge_idx == list.cursor {
+                cursor_line = lines.len();
+            }
+            let style = if edge_idx == list.cursor { selected } else { Style::default() };
+            lines.push(Line::from(Span::styled(format!("   {} → {} ({}) ", from, to, dep_type), style)));
+        }
+    }
+    if list.cycles.is_empty() {
+        lines.push(Line::from(Span::styled(" No dependency cycles ", Style::default().fg(Color::DarkGray))));
+    }
+
+    let title = format!(" Dependency cycles ({}) ", list.cycles.len());
+    let hint = " Enter=go to dependent Esc=close ";
+    let width = (frame.area().width * 2 / 3).max(40);
+    let area = centered_rect(frame.area(), width, lines.len() as u16 + 2);
+    // Keep the highlighted edge in view when the list is taller than the popup
+    let scroll = (cursor_line + 1).saturating_sub(area.height.saturating_sub(2) as usize);
+
+    frame.render_widget(Clear, area);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .title_bottom(Line::from(hint).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .style(Style::default().bg(Color::Black))
+        .scroll((scroll as u16, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// Kanban board: a column per status with a card per issue, bordered in its
+/// priority color and titled in its ready/blocked/closed color
+fn render_board(frame: &mut Frame, tree: &IssueTree, board: &crate::Board, area: Rect, status_note: Option<&str>) {
+    const CARD_HEIGHT: u16 = 4;
+    let columns = tree.board_columns();
+
+    let [board_area, hint_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+    let column_areas = Layout::horizontal([Constraint::Ratio(1, BOARD_STATUSES.len() as u32); BOARD_STATUSES.len()])
+        .split(board_area);
+
+    for (i, (status, ids)) in BOARD_STATUSES.iter().zip(&columns).enumerate() {
+        let is_current = i == board.column;
+        let block = Block::default()
+            .title(format!(" {} ({}) ", status.replace('_', " "), ids.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if is_current { Color::Cyan } else { Color::DarkGray }));
+        let inner = block.inner(column_areas[i]);
+        frame.render_widget(block, column_areas[i]);
+
+        if *status == "closed" && !tree.show_closed {
+            let hidden = Paragraph::new(Span::styled(" c shows closed issues", Style::default().fg(Color::DarkGray)));
+            frame.render_widget(hidden, inner);
+            continue;
+        }
+
+        // Scroll the column so its selected card stays in view
+        let fits = (inner.height / CARD_HEIGHT).max(1) as usize;
+        let row = board.row(&columns, i);
+        let first = (row + 1).saturating_sub(fits);
+        for (n, id) in ids.iter().enumerate().skip(first).take(fits) {
+            let issue = &tree.nodes[id].issue;
+            let card_area = Rect::new(inner.x, inner.y + (n - first) as u16 * CARD_HEIGHT, inner.width, CARD_HEIGHT.min(inner.height));
+            let is_selected = is_current && n == row;
+            let title_style = if issue.status == "closed" {
+                Style::default().fg(Color::DarkGray)
+            } else if tree.ready_ids.contains(id) {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            let mut card_style = Style::default();
+            if is_selected {
+                card_style = card_style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+            }
+            let card = Paragraph::new(vec![
+                Line::from(vec![
+                    Span::styled(id.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::styled(format!(" P{}", issue.priority), Style::default().fg(priority_color(issue.priority))),
+                ]),
+                Line::from(Span::styled(issue.title.clone(), title_style)),
+            ])
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(priority_color(issue.priority))))
+            .style(card_style);
+            frame.render_widget(card, card_area);
+        }
+    }
+
+    let hint = " h/l=column j/k=card H/L=move card Enter=show in tree b=back to tree ";
+    let hint_line = match status_note {
+        Some(note) => Line::from(vec![
+            Span::styled(format!(" {} ", note), Style::default().fg(Color::Yellow)),
+            Span::styled(hint, Style::default().fg(Color::DarkGray)),
+        ]),
+        None => Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+    };
+    frame.render_widget(Paragraph::new(hint_line), hint_area);
+}
+
+/// Nearly full-screen view of the dependency graph around `graph.center`, scrolled
+/// so the centre issue is in the middle when the graph doesn't fit
+fn render_graph_view(frame: &mut Frame, tree: &IssueTree, graph: &DepGraph) {
+    let canvas = graph.draw(|id| format!("{} {}", id, tree.nodes[id].issue.title));
+
+    let mut title = format!(" Dependency graph: {} (radius {}) ", graph.center, graph.radius);
+    if graph.hidden_edges > 0 {
+        title.push_str(&format!("⟳ {} cycle edges not drawn ", graph.hidden_edges));
+    }
+    let hint = " k/j=blocker/blocked h/l=beside +/-=radius Enter=go to issue Esc=close ";
+    let screen = frame.area();
+    let area = centered_rect(screen, screen.width.saturating_sub(4), screen.height.saturating_sub(2));
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(hint).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    // Canvas offset that puts the centre issue mid-view, or centres a graph that fits
+    let offset = |size: usize, view: u16, focus: usize| -> isize {
+        let view = view as usize;
+        if size <= view {
+            -(((view - size) / 2) as isize)
+        } else {
+            focus.saturating_sub(view / 2).min(size - view) as isize
+        }
+    };
+    let (cx, cy) = canvas.center_of(&graph.center).unwrap_or_default();
+    let dx = offset(canvas.width, inner.width, cx);
+    let dy = offset(canvas.height, inner.height, cy);
+
+    let edge_style = Style::default().fg(Color::Gray);
+    let buf = frame.buffer_mut();
+    for row in 0..inner.height {
+        for col in 0..inner.width {
+            let (x, y) = (col as isize + dx, row as isize + dy);
+            if x < 0 || y < 0 || x as usize >= canvas.width || y as usize >= canvas.height {
+                continue;
+            }
+            let (x, y) = (x as usize, y as usize);
+            let in_box = canvas.boxes.iter()
+                .find(|(_, bx, by)| (*bx..*bx + NODE_WIDTH).contains(&x) && (*by..*by + 3).contains(&y));
+            let style = match in_box {
+                Some((id, _, _)) => {
+                    let issue = &tree.nodes[id].issue;
+                    let style = if issue.status == "closed" {
+                        Style::default().fg(Color::DarkGray)
+                    } else if tree.ready_ids.contains(id) {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default().fg(Color::Red)
+                    };
+                    if *id == graph.center {
+                        style.add_modifier(Modifier::BOLD)
+                    } else {
+                        style
+                    }
+                }
+                None => edge_style,
+            };
+            buf[(inner.x + col, inner.y + row)].set_char(canvas.rows[y][x]).set_style(style);
+        }
+    }
+}
+
+/// `#` dashboard: counts as bar charts on the left; throughput, epics and the
+/// issues needing attention on the right
+fn render_stats(frame: &mut Frame, tree: &IssueTree, stats: &ProjectStats) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let screen = frame.area();
+    let area = centered_rect(screen, screen.width.saturating_sub(4), screen.height.saturating_sub(2));
+    let block = Block::default()
+        .title(" Project statistics ")
+        .title_bottom(Line::from(" Esc=close ").centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [left, right] = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(inner);
+
+    // Left: summary, then issues per status, priority and type
+    let open = stats.ready + stats.blocked;
+    let mut summary = vec![
+        Line::from(format!(" {} issues, {} open", stats.total, open)),
+        Line::from(vec![
+            Span::styled(format!(" {} ready", stats.ready), Style::default().fg(Color::Green)),
+            Span::raw(" / "),
+            Span::styled(format!("{} blocked", stats.blocked), Style::default().fg(Color::Red)),
+        ]),
+        Line::from(format!(" {} epics, {} complete", stats.epics.len(), stats.epics_done())),
+    ];
+    if let Some(percent) = (stats.ready * 100).checked_div(open) {
+        summary[1].spans.push(Span::styled(format!(" ({}% ready)", percent), dim));
+    }
+    let bars = |counts: Vec<(String, usize, Color)>| -> BarChart<'static> {
+        let bars: Vec<Bar> = counts.into_iter()
+            .map(|(label, count, color)| Bar::default()
+                .label(Line::from(label))
+                .value(count as u64)
+                .style(Style::default().fg(color)))
+            .collect();
+        BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .value_style(Style::default().fg(Color::Black).bg(Color::Gray))
+            .data(BarGroup::default().bars(&bars))
+    };
+    let by_status = bars(stats.by_status.iter()
+        .map(|(status, n)| (status.replace('_', " "), *n, if status == "closed" { Color::DarkGray } else { Color::Cyan }))
+        .collect());
+    let by_priority = bars(stats.by_priority.iter()
+        .map(|(priority, n)| (format!("P{}", priority), *n, priority_color(*priority)))
+        .collect());
+    let by_type = bars(stats.by_type.iter().map(|(t, n)| (t.clone(), *n, Color::Cyan)).collect());
+
+    let [summary_area, status_area, priority_area, type_area] = Layout::vertical([
+        Constraint::Length(summary.len() as u16 + 1),
+        Constraint::Length(stats.by_status.len() as u16 + 2),
+        Constraint::Length(stats.by_priority.len() as u16 + 2),
+        Constraint::Min(3),
+    ]).areas(left);
+    frame.render_widget(Paragraph::new(summary), summary_area);
+    frame.render_widget(by_status.block(Block::bordered().title(" By status ").border_style(dim)), status_area);
+    frame.render_widget(by_priority.block(Block::bordered().title(" By priority ").border_style(dim)), priority_area);
+    frame.render_widget(by_type.block(Block::bordered().title(" By type ").border_style(dim)), type_area);
+
+    // Right: closings per week, epic progress, oldest open issues, missing descriptions
+    let closed: u64 = stats.closed_per_week.iter().sum();
+    let throughput = Sparkline::default()
+        .block(Block::bordered()
+            .title(format!(" Closed per week: {} in {} weeks ", closed, THROUGHPUT_WEEKS))
+            .title_bottom(Line::from(" this week → ").right_aligned())
+            .border_style(dim))
+        .data(&stats.closed_per_week)
+        .style(Style::default().fg(Color::Green));
+
+    let title_of = |id: &str| tree.nodes.get(id).map(|n| n.issue.title.clone()).unwrap_or_default();
+    let epics: Vec<Line> = stats.epics.iter()
+        .map(|(id, rollup)| {
+            const GAUGE_WIDTH: usize = 10;
+            let filled = rollup.closed * GAUGE_WIDTH / rollup.total.max(1);
+            Line::from(vec![
+                Span::styled(format!(" {:>7} ", format!("{}/{}", rollup.closed, rollup.total)), dim),
+                Span::styled("█".repeat(filled), Style::default().fg(Color::Green)),
+                Span::styled("░".repeat(GAUGE_WIDTH - filled), dim),
+                Span::raw(format!(" {} {}", id, title_of(id))),
+            ])
+        })
+        .collect();
+    let oldest: Vec<Line> = stats.oldest_open.iter()
+        .map(|(id, created)| Line::from(vec![
+            Span::styled(format!(" {:>8} ", activity::relative_time(*created, tree.now)), dim),
+            Span::raw(format!("{} {}", id, title_of(id))),
+        ]))
+        .collect();
+    let no_description = if stats.no_description.is_empty() {
+        Line::from(Span::styled(" Every open issue has one", dim))
+    } else {
+        Line::from(format!(" {}", stats.no_description.join(", ")))
+    };
+
+    let [throughput_area, epics_area, oldest_area, description_area] = Layout::vertical([
+        Constraint::Length(5),
+        Constraint::Length(epics.len().max(1) as u16 + 2),
+        Constraint::Length(oldest.len().max(1) as u16 + 2),
+        Constraint::Min(3),
+    ]).areas(right);
+    frame.render_widget(throughput, throughput_area);
+    frame.render_widget(Paragraph::new(epics).block(Block::bordered().title(" Epics ").border_style(dim)), epics_area);
+    frame.render_widget(Paragraph::new(oldest).block(Block::bordered().title(" Oldest open ").border_style(dim)), oldest_area);
+    frame.render_widget(Paragraph::new(no_description)
+        .wrap(Wrap { trim: false })
+        .block(Block::bordered()
+            .title(format!(" No description ({}) ", stats.no_description.len()))
+            .border_style(dim)), description_area);
+}
+
+/// One-line input box along the bottom of the screen
+fn render_prompt(frame: &mut Frame, prompt: &crate::Prompt) {
+    let area = frame.area();
+    let height = 3.min(area.height);
+    let prompt_area = Rect::new(area.x, area.y + area.height - height, area.width, height);
+
+    frame.render_widget(Clear, prompt_area);
+    let mut block = Block::default()
+        .title(format!(" {} ", prompt.label()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    if let Some(error) = &prompt.error {
+        block = block.title_bottom(Span::styled(format!(" {} ", error), Style::default().fg(Color::Red)));
+    }
+    let paragraph = Paragraph::new(edit_buffer_lines(&prompt.input)).block(block);
+    frame.render_widget(paragraph, prompt_area);
+}
+
+/// Highlight for `/` search hits in the tree
+const SEARCH_MATCH_STYLE: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+
+/// Split `text` into spans, highlighting case-insensitive occurrences of `query` (lowercase)
+fn highlight_matches(text: &str, query: &str, style: Style) -> Vec<Span<'static>> {
+    let lower = text.to_lowercase();
+    // Lowercasing can change byte lengths (e.g. some non-ASCII letters); skip highlighting then
+    if query.is_empty() || lower.len() != text.len() {
+        return vec![Span::styled(text.to_string(), style)];
+    }
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find(query) {
+        let start = pos + found;
+        let end = start + query.len();
+        if start > pos {
+            spans.push(Span::styled(text[pos..start].to_string(), style));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), SEARCH_MATCH_STYLE));
+        pos = end;
+    }
+    if pos < text.len() || spans.is_empty() {
+        spans.push(Span::styled(text[pos..].to_string(), style));
+    }
+    spans
+}
+
+/// Row for a group header in the grouped views, e.g. "▶ P1 (4)"
+fn group_header_item(header: &crate::tree::GroupHeader, is_expanded: bool, is_selected: bool) -> ListItem<'static> {
+    let icon = if is_expanded { "▼ " } else { "▶ " };
+    let mut style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    if is_selected {
+        style = style.bg(Color::DarkGray);
+    }
+    ListItem::new(Line::from(vec![
+        Span::styled(format!("{}{}", icon, header.name), style),
+        Span::styled(format!(" ({})", header.count), Style::default().fg(Color::DarkGray)),
+    ]))
+    .style(if is_selected { Style::default().bg(Color::DarkGray) } else { Style::default() })
+}
+
+/// Indentation for a tree row: normal up to depth 4, then a [N] indicator
+fn tree_indent(depth: usize) -> String {
+    const MAX_VISUAL_INDENT: usize = 4;
+    if depth <= MAX_VISUAL_INDENT {
+        "  ".repeat(depth)
+    } else {
+        format!("{}[{}]", "  ".repeat(MAX_VISUAL_INDENT), depth)
+    }
+}
+
+/// Dimmed row repeating an issue under another of its blockers in the Deps view,
+/// e.g. "↪ bsv-abc Title"
+fn dep_ref_item(tree: &IssueTree, dep_ref: &crate::tree::DepRef, is_selected: bool) -> ListItem<'static> {
+    let title = tree.nodes.get(&dep_ref.id).map(|n| n.issue.title.as_str()).unwrap_or_default();
+    let style = if is_selected {
+        Style::default().fg(Color::Gray).bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    ListItem::new(Line::from(Span::styled(
+        format!("{}↪ {} {}", tree_indent(dep_ref.depth), dep_ref.id, title),
+        style.add_modifier(Modifier::DIM),
+    )))
+    .style(if is_selected { Style::default().bg(Color::DarkGray) } else { Style::default() })
+}
+
+fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: bool, tree_scroll: usize, status_note: Option<&str>, is_loading: bool) {
     use crate::HierarchyMode;
 
     // Show loading message if still loading
@@ -322,6 +1022,12 @@ fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: b
         .iter()
         .enumerate()
         .filter_map(|(idx, id)| {
+            if let Some(header) = tree.groups.get(id) {
+                return Some(group_header_item(header, tree.is_expanded_in_current_mode(id), idx == tree.cursor));
+            }
+            if let Some(dep_ref) = tree.dep_refs.get(id) {
+                return Some(dep_ref_item(tree, dep_ref, idx == tree.cursor));
+            }
             tree.nodes.get(id).map(|node| {
                 let is_selected = idx == tree.cursor;
                 // Use mode-aware children check
@@ -330,15 +1036,12 @@ fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: b
                 let is_closed = node.issue.status == "closed";
                 let is_ready = tree.ready_ids.contains(id);
                 let is_multi_parent = tree.multi_parent_ids.contains(id);
+                let is_ready_mismatch = tree.ready_mismatch_ids.contains(id);
+                let is_parent_conflict = tree.hierarchy_mode == HierarchyMode::IdBased
+                    && tree.parent_conflicts.contains_key(id);
 
                 // Build the tree prefix with indentation
-                // Use hybrid indent: normal up to depth 4, then show [N] indicator
-                const MAX_VISUAL_INDENT: usize = 4;
-                let indent = if node.depth <= MAX_VISUAL_INDENT {
-                    "  ".repeat(node.depth)
-                } else {
-                    format!("{}[{}]", "  ".repeat(MAX_VISUAL_INDENT), node.depth)
-                };
+                let indent = tree_indent(node.depth);
 
                 let icon = if has_children {
                     if is_expanded { "▼ " } else { "▶ " }
@@ -370,17 +1073,71 @@ fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: b
                     Style::default().fg(Color::DarkGray)
                 };
 
-                let line = Line::from(vec![
-                    Span::styled(format!("{}{}", indent, icon), text_style),
-                    Span::styled(format!("{} ", node.issue.id), id_style),
-                    Span::styled(node.issue.title.clone(), text_style),
-                ]);
+                let mut spans = vec![Span::styled(format!("{}{}", indent, icon), text_style)];
+                match tree.search.as_deref().filter(|_| tree.issue_matches(id)) {
+                    Some(query) => {
+                        let shown = [&node.issue.id, &node.issue.title].iter()
+                            .any(|text| text.to_lowercase().contains(query));
+                        if shown {
+                            spans.extend(highlight_matches(&node.issue.id, query, id_style));
+                        } else {
+                            // Matched in the description or labels: highlight the whole ID
+                            spans.push(Span::styled(node.issue.id.clone(), SEARCH_MATCH_STYLE));
+                        }
+                        spans.push(Span::styled(" ", id_style));
+                        spans.extend(highlight_matches(&node.issue.title, query, text_style));
+                    }
+                    None => {
+                        spans.push(Span::styled(format!("{} ", node.issue.id), id_style));
+                        spans.push(Span::styled(node.issue.title.clone(), text_style));
+                    }
+                }
+                // Epic progress, e.g. "[7/12 ✓ 3 ready 2 blocked]"
+                if let Some(rollup) = tree.rollup(id).filter(|_| tree.hierarchy_mode == HierarchyMode::IdBased) {
+                    let mut summary = format!(" [{}/{} ✓", rollup.closed, rollup.total);
+                    if rollup.ready > 0 {
+                        summary.push_str(&format!(" {} ready", rollup.ready));
+                    }
+                    if rollup.blocked > 0 {
+                        summary.push_str(&format!(" {} blocked", rollup.blocked));
+                    }
+                    summary.push(']');
+                    let rollup_style = if is_selected { Style::default().fg(Color::Gray) } else { Style::default().fg(Color::DarkGray) };
+                    spans.push(Span::styled(summary, rollup_style));
+                }
+                // How much waiting work picking this up would unblock
+                if tree.hierarchy_mode == HierarchyMode::ReadyQueue {
+                    let unblocks = tree.transitive_dependents(id);
+                    if unblocks > 0 {
+                        let unblocks_style = if is_selected { Style::default().fg(Color::Gray) } else { Style::default().fg(Color::DarkGray) };
+                        spans.push(Span::styled(format!(" [unblocks {}]", unblocks), unblocks_style));
+                    }
+                }
+                // What happened to it last, and when
+                if let Some(activity) = Activity::latest(&node.issue).filter(|_| tree.hierarchy_mode == HierarchyMode::Timeline) {
+                    let activity_style = if is_selected { Style::default().fg(Color::Gray) } else { Style::default().fg(Color::DarkGray) };
+                    let when = activity::relative_time(activity.at, tree.now);
+                    spans.push(Span::styled(format!(" · {} {}", activity.kind.name(), when), activity_style));
+                }
+                // bd ready disagrees with the locally computed ready state
+                if is_ready_mismatch {
+                    spans.push(Span::styled(" ≠bd", Style::default().fg(Color::Yellow)));
+                }
+                // The parent field, parent-child dependencies and dotted ID disagree
+                if is_parent_conflict {
+                    spans.push(Span::styled(" ≠parent", Style::default().fg(Color::Yellow)));
+                }
+                let line = Line::from(spans);
 
-                let style = if is_selected {
+                let mut style = if is_selected {
                     Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 };
+                // Shown only to give filter matches their place in the tree
+                if tree.is_filter_context(id) {
+                    style = style.add_modifier(Modifier::DIM);
+                }
 
                 ListItem::new(line).style(style)
             })
@@ -389,23 +1146,50 @@ fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: b
 
     // Show mode indicator in title
     let mode_indicator = match tree.hierarchy_mode {
-        HierarchyMode::IdBased => "Epics",
-        HierarchyMode::DependencyBased => "Deps",
+        HierarchyMode::IdBased => "Epics".to_string(),
+        HierarchyMode::DependencyBased => "Deps".to_string(),
+        HierarchyMode::Grouped(group_by) => format!("by {}", group_by.name()),
+        HierarchyMode::ReadyQueue => "Ready".to_string(),
+        HierarchyMode::Timeline => "Timeline".to_string(),
     };
-    let title = format!(" Issues ({}) ", mode_indicator);
+    let mut title = format!(" Issues ({}) ", mode_indicator);
+    // The ready queue and the timeline have their own fixed order
+    let is_sorted = tree.sort_mode != crate::SortMode::default() || tree.sort_reverse;
+    if is_sorted && !matches!(tree.hierarchy_mode, HierarchyMode::ReadyQueue | HierarchyMode::Timeline) {
+        let arrow = if tree.sort_reverse { " ↑" } else { "" };
+        title.push_str(&format!("[by {}{}] ", tree.sort_mode.name(), arrow));
+    }
+    if let Some(filter) = &tree.filter {
+        title.push_str(&format!("[filter: {}] ", filter.source));
+    }
+    if let Some(query) = &tree.search {
+        title.push_str(&format!("/{} [{}] ", query, tree.search_match_count()));
+    }
+
+    let mut warnings: Vec<String> = status_note.iter().map(|n| n.to_string()).collect();
+    if !tree.ready_mismatch_ids.is_empty() {
+        warnings.push(format!("≠bd ready: {}", tree.ready_mismatch_ids.len()));
+    }
+    if !tree.dep_cycles.is_empty() {
+        warnings.push(format!("⟳ cycles: {} (C)", tree.dep_cycles.len()));
+    }
+    if tree.hierarchy_mode == HierarchyMode::IdBased && !tree.parent_conflicts.is_empty() {
+        warnings.push(format!("≠parent: {}", tree.parent_conflicts.len()));
+    }
 
     let border_color = if focused { Color::Cyan } else { Color::DarkGray };
     let list = List::new(items)
         .block(Block::default()
             .title(title)
-            .title_bottom(Line::from(if daemon_slow {
-                vec![
-                    Span::styled(" bd daemon slow! ", Style::default().fg(Color::Yellow)),
-                    Span::raw("? help  d=Epics/Deps "),
-                ]
+            // Warnings are left-aligned so the start of a long message stays visible
+            .title_bottom(if warnings.is_empty() {
+                Line::from(" ? help  d=Epics/Deps ").centered()
             } else {
-                vec![Span::raw(" ? help  d=Epics/Deps ")]
-            }).centered())
+                Line::from(vec![
+                    Span::styled(format!(" {} ", warnings.join("  ")), Style::default().fg(Color::Yellow)),
+                    Span::raw("? help  d=Epics/Deps "),
+                ]).left_aligned()
+            })
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color)));
 
@@ -418,7 +1202,8 @@ fn render_tree_panel(frame: &mut Frame, tree: &IssueTree, area: Rect, focused: b
     frame.render_stateful_widget(list, area, &mut list_state);
 }
 
-fn render_detail_panel(frame: &mut Frame, issue: Option<&Issue>, ready_ids: &std::collections::HashSet<String>, area: Rect, focused: bool, scroll: u16, edit_state: Option<&crate::EditState>) {
+#[allow(clippy::too_many_arguments)]
+fn render_detail_panel(frame: &mut Frame, issue: Option<&Issue>, ready_ids: &std::collections::HashSet<String>, extras: &DetailExtras, area: Rect, focused: bool, scroll: u16, edit_state: Option<&crate::EditState>) {
     // If we're in edit mode, render the edit UI
     if let Some(edit) = edit_state {
         render_edit_panel(frame, issue, edit, area);
@@ -426,7 +1211,7 @@ fn render_detail_panel(frame: &mut Frame, issue: Option<&Issue>, ready_ids: &std
     }
 
     let content = match issue {
-        Some(issue) => format_issue_detail(issue, ready_ids),
+        Some(issue) => format_issue_detail(issue, ready_ids, extras),
         None => vec![Line::from("No issue selected")],
     };
 
@@ -444,31 +1229,9 @@ fn render_detail_panel(frame: &mut Frame, issue: Option<&Issue>, ready_ids: &std
     frame.render_widget(paragraph, area);
 }
 
-fn render_edit_panel(frame: &mut Frame, issue: Option<&Issue>, edit: &crate::EditState, area: Rect) {
-    let field_name = match edit.field {
-        crate::EditField::Title => "Title",
-        crate::EditField::Description => "Description",
-    };
-
-    let title = format!(" Editing {} (Esc=cancel, Ctrl+S=save) ", field_name);
-
-    // Create the content lines
-    let mut lines: Vec<Line> = Vec::new();
-
-    // Show the issue ID
-    if let Some(issue) = issue {
-        lines.push(Line::from(vec![
-            Span::styled("ID: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(issue.id.clone()),
-        ]));
-        lines.push(Line::from(""));
-    }
-
-    // Show field label
-    lines.push(Line::from(Span::styled(
-        format!("{}:", field_name),
-        Style::default().add_modifier(Modifier::BOLD).fg(Color::Yellow),
-    )));
+/// The input's text as lines, with the cursor shown as a highlighted cell
+fn edit_buffer_lines(edit: &crate::TextInput) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
 
     // Render the editable text with cursor
     // Split buffer into lines
@@ -502,6 +1265,34 @@ fn render_edit_panel(frame: &mut Frame, issue: Option<&Issue>, edit: &crate::Edi
         }
     }
 
+    lines
+}
+
+fn render_edit_panel(frame: &mut Frame, issue: Option<&Issue>, edit: &crate::EditState, area: Rect) {
+    let field_name = edit.field.label();
+
+    let title = format!(" Editing {} (Esc=cancel, Ctrl+S=save) ", field_name);
+
+    // Create the content lines
+    let mut lines: Vec<Line> = Vec::new();
+
+    // Show the issue ID
+    if let Some(issue) = issue {
+        lines.push(Line::from(vec![
+            Span::styled("ID: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(issue.id.clone()),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    // Show field label
+    lines.push(Line::from(Span::styled(
+        format!("{}:", field_name),
+        Style::default().add_modifier(Modifier::BOLD).fg(Color::Yellow),
+    )));
+
+    lines.extend(edit_buffer_lines(&edit.input));
+
     // Add hint at bottom
     lines.push(Line::from(""));
     let hint = if edit.is_modified() {
@@ -527,7 +1318,84 @@ fn render_edit_panel(frame: &mut Frame, issue: Option<&Issue>, edit: &crate::Edi
     frame.render_widget(paragraph, area);
 }
 
-fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<String>) -> Vec<Line<'static>> {
+/// The new issue form (`a` / `A`), shown in place of the details panel
+fn render_create_panel(frame: &mut Frame, form: &crate::CreateForm, area: Rect) {
+    let title = match &form.parent {
+        Some(parent) => format!(" New child of {} (Esc=cancel, Ctrl+S=create) ", parent),
+        None => " New issue (Esc=cancel, Ctrl+S=create) ".to_string(),
+    };
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, (field, input)) in crate::CreateField::ALL.iter().zip(&form.inputs).enumerate() {
+        let hint = match field {
+            crate::CreateField::IssueType => format!(" ({})", crate::ISSUE_TYPES.join(", ")),
+            crate::CreateField::Priority => " (0-4)".to_string(),
+            crate::CreateField::Labels => " (comma-separated)".to_string(),
+            crate::CreateField::Title | crate::CreateField::Description => String::new(),
+        };
+        let label_style = if i == form.current {
+            Style::default().add_modifier(Modifier::BOLD).fg(Color::Yellow)
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}:", field.label()), label_style),
+            Span::styled(hint, Style::default().fg(Color::DarkGray)),
+        ]));
+        if i == form.current {
+            lines.extend(edit_buffer_lines(input));
+        } else {
+            lines.extend(input.buffer.split('\n').map(|l| Line::from(l.to_string())));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "Tab/Enter=next field  Shift+Tab=previous",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)))
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Progress bar and per-status counts for an epic, e.g. "Progress: ████░░░░ 7/12 (58%)"
+fn progress_lines(rollup: &Rollup) -> Vec<Line<'static>> {
+    const BAR_WIDTH: usize = 20;
+    let filled = rollup.closed * BAR_WIDTH / rollup.total.max(1);
+    let percent = rollup.closed * 100 / rollup.total.max(1);
+    let breakdown: Vec<String> = rollup.by_status.iter()
+        .map(|(status, count)| format!("{} {}", status, count))
+        .collect();
+
+    vec![
+        Line::from(vec![
+            Span::styled("Progress: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("█".repeat(filled), Style::default().fg(Color::Green)),
+            Span::styled("░".repeat(BAR_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+            Span::raw(format!(" {}/{} ({}%)", rollup.closed, rollup.total, percent)),
+        ]),
+        Line::from(Span::styled(format!("  {}", breakdown.join(" · ")), Style::default().fg(Color::DarkGray))),
+    ]
+}
+
+/// Color for a priority: P0 red through P4 gray
+fn priority_color(priority: i32) -> Color {
+    match priority {
+        0 => Color::Red,
+        1 => Color::Yellow,
+        2 => Color::Green,
+        3 => Color::Blue,
+        _ => Color::DarkGray,
+    }
+}
+
+fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<String>, extras: &DetailExtras) -> Vec<Line<'static>> {
     let mut lines = vec![];
 
     // Title
@@ -547,13 +1415,7 @@ fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<Stri
     ]));
 
     // Status and Priority
-    let priority_color = match issue.priority {
-        0 => Color::Red,
-        1 => Color::Yellow,
-        2 => Color::Green,
-        3 => Color::Blue,
-        _ => Color::DarkGray,
-    };
+    let priority_color = priority_color(issue.priority);
     lines.push(Line::from(vec![
         Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(issue.status.clone()),
@@ -595,6 +1457,41 @@ fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<Stri
             }
         }
     }
+
+    // Where the parent sources disagree, the first one wins in the Epics view
+    if let Some(conflict) = extras.parent_conflict {
+        lines.push(Line::from(vec![
+            Span::styled("Parent conflict: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(conflict.to_string(), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    // Epic progress over all descendants
+    if let Some(rollup) = extras.rollup {
+        lines.push(Line::from(""));
+        lines.extend(progress_lines(rollup));
+    }
+
+    // What stands between the issue and being workable, and what waits on it
+    if let Some(deps) = extras.deps {
+        if !deps.critical_path.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("Critical path ({} open blocker{}):", deps.critical_path.len(), if deps.critical_path.len() == 1 { "" } else { "s" }),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            for (id, title, is_ready) in &deps.critical_path {
+                let style = if *is_ready { Style::default().fg(Color::Green) } else { Style::default().fg(Color::Red) };
+                lines.push(Line::from(Span::styled(format!("  → {} {}", id, title), style)));
+            }
+        }
+        if deps.unblocks > 0 {
+            lines.push(Line::from(vec![
+                Span::styled("Unblocks: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{} open issue{} downstream", deps.unblocks, if deps.unblocks == 1 { "" } else { "s" })),
+            ]));
+        }
+    }
     lines.push(Line::from(""));
 
     // Description (with markdown)
@@ -620,14 +1517,19 @@ fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<Stri
         }
     }
 
-    // Notes (with markdown)
-    if let Some(notes) = &issue.notes {
-        if !notes.is_empty() {
+    // Design, acceptance criteria and notes (with markdown)
+    let long_fields = [
+        ("Design:", &issue.design),
+        ("Acceptance Criteria:", &issue.acceptance_criteria),
+        ("Notes:", &issue.notes),
+    ];
+    for (heading, text) in long_fields {
+        if let Some(text) = text.as_deref().filter(|t| !t.is_empty()) {
             lines.push(Line::from(Span::styled(
-                "Notes:",
+                heading,
                 Style::default().add_modifier(Modifier::BOLD),
             )));
-            lines.extend(markdown_to_lines(notes));
+            lines.extend(markdown_to_lines(text));
             lines.push(Line::from(""));
         }
     }
@@ -670,6 +1572,12 @@ fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<Stri
         Span::styled("Updated: ", Style::default().fg(Color::DarkGray)),
         Span::styled(issue.updated_at.clone(), Style::default().fg(Color::DarkGray)),
     ]));
+    if let Some(closed_at) = &issue.closed_at {
+        lines.push(Line::from(vec![
+            Span::styled("Closed: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(closed_at.clone(), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
 
     lines
 }
@@ -677,16 +1585,6 @@ fn format_issue_detail(issue: &Issue, ready_ids: &std::collections::HashSet<Stri
 fn render_help_overlay(frame: &mut Frame) {
     let area = frame.area();
 
-    // Center the help box
-    let help_width = 50.min(area.width.saturating_sub(4));
-    let help_height = 30.min(area.height.saturating_sub(4));
-    let x = (area.width.saturating_sub(help_width)) / 2;
-    let y = (area.height.saturating_sub(help_height)) / 2;
-    let help_area = Rect::new(x, y, help_width, help_height);
-
-    // Clear the area first
-    frame.render_widget(Clear, help_area);
-
     let help_text = vec![
         Line::from(Span::styled("Tree Panel", Style::default().add_modifier(Modifier::BOLD))),
         Line::from("  j / ↓         Move down"),
@@ -698,6 +1596,23 @@ fn render_help_overlay(frame: &mut Frame) {
         Line::from("  Space         Toggle expand/collapse"),
         Line::from("  Tab           Toggle expand/collapse all"),
         Line::from(""),
+        Line::from(Span::styled("Global", Style::default().add_modifier(Modifier::BOLD))),
+        Line::from("  c             Toggle show/hide closed"),
+        Line::from("  d / m         Epics/Deps view / repeat shared"),
+        Line::from("  v / R / b / T Group / ready / board / timeline"),
+        Line::from("  s / S         Cycle sort order / reverse"),
+        Line::from("  x / o / w     Close / reopen / in progress"),
+        Line::from("  P / t / L     Set priority / type / labels"),
+        Line::from("  D             Manage dependencies"),
+        Line::from("  I / C / V / # Unblocks / cycles / graph / stats"),
+        Line::from("  /             Search (n / N = next / prev)"),
+        Line::from("  f / F         Filter / save filter as @name"),
+        Line::from("  Ctrl+P        Go to issue"),
+        Line::from("  a / A         Add issue / add child"),
+        Line::from("  r             Refresh data"),
+        Line::from("  ?             Toggle this help"),
+        Line::from("  q / Ctrl+C    Quit"),
+        Line::from(""),
         Line::from(Span::styled("Details Panel", Style::default().add_modifier(Modifier::BOLD))),
         Line::from("  j / k         Scroll up/down"),
         Line::from("  g / G         Top/bottom"),
@@ -709,13 +1624,7 @@ fn render_help_overlay(frame: &mut Frame) {
         Line::from("  Esc           Cancel editing"),
         Line::from("  Ctrl+S        Save changes"),
         Line::from("  Tab/Shift+Tab Navigate fields"),
-        Line::from(""),
-        Line::from(Span::styled("Global", Style::default().add_modifier(Modifier::BOLD))),
-        Line::from("  c             Toggle show/hide closed"),
-        Line::from("  d             Toggle Epics/Deps view"),
-        Line::from("  r             Refresh data"),
-        Line::from("  ?             Toggle this help"),
-        Line::from("  q / Ctrl+C    Quit"),
+        Line::from("  Enter         Next field (new issue form)"),
         Line::from(""),
         Line::from(Span::styled("Mouse", Style::default().add_modifier(Modifier::BOLD))),
         Line::from("  Click         Select issue / focus panel"),
@@ -738,14 +1647,48 @@ fn render_help_overlay(frame: &mut Frame) {
         ]),
     ];
 
-    let help_paragraph = Paragraph::new(help_text)
-        .block(Block::default()
-            .title(" Help (? to close) ")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Yellow)))
+    // Center the help box, tall enough for all of the text if the terminal allows.
+    // When it doesn't fit, split it into two columns at a section break.
+    const COLUMN_WIDTH: u16 = 50;
+    let max_rows = area.height.saturating_sub(6) as usize;
+    let two_columns = help_text.len() > max_rows && area.width >= COLUMN_WIDTH * 2 + 6;
+    let columns: Vec<Vec<Line>> = if two_columns {
+        let middle = help_text.len() / 2;
+        let split = (middle..help_text.len())
+            .find(|&i| help_text[i].width() == 0)
+            .map_or(middle, |i| i + 1);
+        let mut left = help_text;
+        let right = left.split_off(split);
+        vec![left, right]
+    } else {
+        vec![help_text]
+    };
+    let rows = columns.iter().map(|c| c.len()).max().unwrap_or(0) as u16;
+
+    let help_width = (COLUMN_WIDTH * columns.len() as u16).min(area.width.saturating_sub(4));
+    let help_height = (rows + 2).min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(help_width)) / 2;
+    let y = (area.height.saturating_sub(help_height)) / 2;
+    let help_area = Rect::new(x, y, help_width, help_height);
+
+    // Clear the area first
+    frame.render_widget(Clear, help_area);
+
+    let block = Block::default()
+        .title(" Help (? to close) ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
         .style(Style::default().bg(Color::Black));
+    let inner = block.inner(help_area);
+    frame.render_widget(block, help_area);
 
-    frame.render_widget(help_paragraph, help_area);
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns.len() as u32); columns.len()])
+        .split(inner);
+    for (column, column_area) in columns.into_iter().zip(column_areas.iter()) {
+        frame.render_widget(Paragraph::new(column), *column_area);
+    }
 }
 
 #[cfg(test)]
@@ -911,15 +1854,8 @@ mod tests {
             priority: 2,
             issue_type: "task".to_string(),
             created_at: "2024-01-01".to_string(),
-            created_by: None,
             updated_at: "2024-01-01".to_string(),
-            labels: None,
-            parent: None,
-            dependencies: None,
-            dependents: None,
-            notes: None,
-            design: None,
-            acceptance_criteria: None,
+            ..Default::default()
         }
     }
 
@@ -952,8 +1888,7 @@ mod tests {
                 },
             ]),
             notes: Some("- Item one\n  - Nested item\n- Item two".to_string()),
-            design: None,
-            acceptance_criteria: None,
+            ..Default::default()
         }
     }
 
@@ -966,7 +1901,7 @@ mod tests {
         let ready_ids: HashSet<String> = HashSet::new();
 
         terminal.draw(|frame| {
-            render_detail_panel(frame, Some(&issue), &ready_ids, frame.area(), true, 0, None);
+            render_detail_panel(frame, Some(&issue), &ready_ids, &DetailExtras::default(), frame.area(), true, 0, None);
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -988,7 +1923,7 @@ mod tests {
         ready_ids.insert("bsv-456".to_string());
 
         terminal.draw(|frame| {
-            render_detail_panel(frame, Some(&issue), &ready_ids, frame.area(), true, 0, None);
+            render_detail_panel(frame, Some(&issue), &ready_ids, &DetailExtras::default(), frame.area(), true, 0, None);
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -1004,7 +1939,7 @@ mod tests {
         let ready_ids: HashSet<String> = HashSet::new();
 
         terminal.draw(|frame| {
-            render_detail_panel(frame, Some(&issue), &ready_ids, frame.area(), true, 0, None);
+            render_detail_panel(frame, Some(&issue), &ready_ids, &DetailExtras::default(), frame.area(), true, 0, None);
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -1028,12 +1963,11 @@ mod tests {
 
         let mut expanded = HashSet::new();
         expanded.insert("bsv-a".to_string());
-        let ready_ids = HashSet::new();
 
-        let tree = IssueTree::from_issues(issues, expanded, HashSet::new(), ready_ids, HierarchyMode::IdBased);
+        let tree = IssueTree::from_issues(issues, expanded, HashSet::new(), HierarchyMode::IdBased);
 
         terminal.draw(|frame| {
-            render_tree_panel(frame, &tree, frame.area(), true, 0, false, false);
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -1046,6 +1980,563 @@ mod tests {
         assert!(output.contains("Child Issue"));
     }
 
+    #[test]
+    fn test_tree_panel_ready_mismatch_warning() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(50, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let issues = vec![make_test_issue("bsv-a", "First Issue", "open")];
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
+        // Locally bsv-a is ready, but bd ready returned nothing
+        tree.cross_check_ready(&HashSet::new());
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("First Issue ≠bd"));
+        assert!(output.contains("≠bd ready: 1"));
+    }
+
+    #[test]
+    fn test_parent_conflict_marked_in_tree_and_details() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(140, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut moved = make_test_issue("bsv-a.1", "Moved", "open");
+        moved.parent = Some("bsv-b".to_string());
+        let issues = vec![
+            make_test_issue("bsv-a", "Old Epic", "open"),
+            make_test_issue("bsv-b", "New Epic", "open"),
+            moved,
+        ];
+        let mut tree = IssueTree::from_issues(issues, HashSet::from(["bsv-b".to_string()]), HashSet::new(), HierarchyMode::IdBased);
+        assert!(tree.select_issue("bsv-a.1"));
+
+        terminal.draw(|frame| {
+            render(frame, &tree, None, None, false, crate::Focus::Tree, 0, None, 0.5, 0, None, false, Overlays::default());
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("bsv-a.1 Moved ≠parent"));
+        assert!(output.contains("≠parent: 1"));
+        assert!(output.contains("Parent conflict: parent field bsv-b, dotted ID bsv-a"));
+    }
+
+    #[test]
+    fn test_epic_rollup_in_tree_and_details() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(120, 16);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let issues = vec![
+            make_test_issue("bsv-a", "Epic", "open"),
+            make_test_issue("bsv-a.1", "Done", "closed"),
+            make_test_issue("bsv-a.2", "Started", "in_progress"),
+            make_test_issue("bsv-a.3", "Todo", "open"),
+            make_test_issue("bsv-a.4", "More", "open"),
+        ];
+        let tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
+
+        terminal.draw(|frame| {
+            render(frame, &tree, None, None, false, crate::Focus::Tree, 0, None, 0.5, 0, None, false, Overlays::default());
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("▶ bsv-a Epic [1/4 ✓ 3 ready]"));
+        assert!(output.contains("Progress: █████░░░░░░░░░░░░░░░ 1/4 (25%)"));
+        assert!(output.contains("in_progress 1 · open 2 · closed 1"));
+    }
+
+    #[test]
+    fn test_tree_panel_highlights_search_matches() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(50, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut labeled = make_test_issue("bsv-b", "Second Issue", "open");
+        labeled.labels = Some(vec!["docs".to_string()]);
+        let issues = vec![make_test_issue("bsv-a", "First Docs", "open"), labeled];
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
+        tree.search = Some("docs".to_string());
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Issues (Epics) /docs [2]"));
+
+        let row_text = |y: u16| (0..50).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+        let highlighted = |y: u16, needle: &str| {
+            let text = row_text(y);
+            let start = text[..text.find(needle).unwrap()].chars().count() as u16;
+            (start..start + needle.len() as u16).all(|x| buffer[(x, y)].bg == Color::Yellow)
+        };
+        // The matching part of the title, or the whole ID for a label match
+        assert!(highlighted(1, "Docs"));
+        assert!(!highlighted(1, "First"));
+        assert!(highlighted(2, "bsv-b"));
+        assert!(!highlighted(2, "Second"));
+    }
+
+    #[test]
+    fn test_tree_panel_shows_filter_and_dims_context() {
+        use crate::HierarchyMode;
+        use std::collections::BTreeMap;
+
+        let backend = TestBackend::new(60, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut bug = make_test_issue("bsv-a.1", "Crash", "open");
+        bug.issue_type = "bug".to_string();
+        let issues = vec![make_test_issue("bsv-a", "Epic", "open"), bug, make_test_issue("bsv-b", "Other", "open")];
+        let mut tree = IssueTree::from_issues(issues, HashSet::from(["bsv-a".to_string()]), HashSet::new(), HierarchyMode::IdBased);
+        tree.filter = Some(crate::filter::Filter::parse("type:bug", &BTreeMap::new()).unwrap());
+        tree.rebuild_visible();
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Issues (Epics) [filter: type:bug]"));
+        assert!(output.contains("bsv-a.1 Crash"));
+        assert!(!output.contains("Other"));
+        // The epic is only there as context for its matching child
+        assert!(buffer[(5, 1)].modifier.contains(Modifier::DIM));
+        assert!(!buffer[(7, 2)].modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_tree_panel_renders_group_headers() {
+        use crate::{GroupBy, HierarchyMode};
+
+        let backend = TestBackend::new(50, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut bug = make_test_issue("bsv-b", "Crash", "open");
+        bug.issue_type = "bug".to_string();
+        let issues = vec![make_test_issue("bsv-a", "Chore", "open"), bug, make_test_issue("bsv-c", "Docs", "open")];
+        let expanded = HashSet::from(["group:type:bug".to_string()]);
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::Grouped(GroupBy::Type));
+        tree.group_expanded = expanded;
+        tree.rebuild_visible();
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Issues (by type)"));
+        assert!(output.contains("▼ bug (1)"));
+        assert!(output.contains("  bsv-b Crash"));
+        assert!(output.contains("▶ task (2)"));
+        assert!(!output.contains("bsv-a"));
+    }
+
+    #[test]
+    fn test_timeline_shows_latest_activity() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(60, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut updated = make_test_issue("bsv-a", "Parser", "open");
+        updated.created_at = "2026-10-01T09:00:00Z".to_string();
+        updated.updated_at = "2026-10-16T09:00:00Z".to_string();
+        let mut closed = make_test_issue("bsv-b", "Crash", "closed");
+        closed.closed_at = Some("2026-10-16T11:55:00Z".to_string());
+        let mut tree = IssueTree::from_issues(vec![updated, closed], HashSet::new(), HashSet::new(), HierarchyMode::Timeline);
+        tree.now = activity::parse_timestamp("2026-10-16T12:00:00Z").unwrap();
+        tree.utc_offset = 0;
+        tree.show_closed = true;
+        tree.expand_recent_days();
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Issues (Timeline)"));
+        assert!(output.contains("▼ Today (2)"));
+        assert!(output.contains("bsv-b Crash · closed 5m ago"));
+        assert!(output.contains("bsv-a Parser · updated 3h ago"));
+    }
+
+    #[test]
+    fn test_create_panel_shows_fields() {
+        let backend = TestBackend::new(60, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut form = crate::CreateForm::new(Some("bsv-a".to_string()));
+        form.current_input_mut().insert_str("Write docs");
+        form.next_field();
+
+        terminal.draw(|frame| {
+            render_create_panel(frame, &form, frame.area());
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("New child of bsv-a"));
+        assert!(output.contains("Write docs"));
+        assert!(output.contains("Type: (task, bug, feature, epic, chore)"));
+        assert!(output.contains("Priority: (0-4)"));
+        assert!(output.contains("Labels: (comma-separated)"));
+    }
+
+    #[test]
+    fn test_prompt_renders_label_and_input() {
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut prompt = crate::Prompt::new(crate::PromptKind::CloseReason { issue_id: "bsv-a".to_string() });
+        prompt.input.insert_str("Duplicate");
+
+        terminal.draw(|frame| {
+            render_prompt(frame, &prompt);
+        }).unwrap();
+
+        // Drawn along the bottom of the screen
+        let buffer = terminal.backend().buffer();
+        assert!(buffer[(0, 6)].symbol().trim().is_empty());
+        assert_eq!(buffer[(0, 7)].symbol(), "┌");
+
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Close bsv-a - reason"));
+        assert!(output.contains("Duplicate"));
+    }
+
+    fn make_picker_issue() -> Issue {
+        let mut issue = make_test_issue("bsv-a", "First Issue", "open");
+        issue.labels = Some(vec!["ui".to_string()]);
+        issue
+    }
+
+    #[test]
+    fn test_priority_picker_highlights_current() {
+        let backend = TestBackend::new(60, 15);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let issue = make_picker_issue();
+        let picker = crate::Picker::priority(&issue);
+
+        terminal.draw(|frame| {
+            render_picker(frame, &picker);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Priority for bsv-a"));
+        assert!(output.contains("P0  Critical"));
+        assert!(output.contains("P4  Backlog"));
+        assert_eq!(picker.current_option().map(|s| s.as_str()), Some(issue.priority.to_string().as_str()));
+    }
+
+    #[test]
+    fn test_label_picker_checks_and_filters() {
+        let backend = TestBackend::new(60, 15);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let issue = make_picker_issue();
+        let mut picker = crate::Picker::labels(&issue, ["backend".to_string(), "ui".to_string()]);
+
+        terminal.draw(|frame| {
+            render_picker(frame, &picker);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("[ ] backend"));
+        assert!(output.contains("[x] ui"));
+
+        // Typing filters the list
+        picker.input.insert_str("back");
+        terminal.draw(|frame| {
+            render_picker(frame, &picker);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("[ ] backend"));
+        assert!(!output.contains("[x] ui"));
+    }
+
+    #[test]
+    fn test_dep_editor_lists_and_searches() {
+        let backend = TestBackend::new(70, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut issue = make_test_issue("bsv-a", "First Issue", "open");
+        issue.dependencies = Some(vec![crate::bd::Dependency {
+            id: "bsv-b".to_string(),
+            title: "Second Issue".to_string(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let others = vec![
+            make_test_issue("bsv-b", "Second Issue", "open"),
+            make_test_issue("bsv-c", "Parser rewrite", "open"),
+            make_test_issue("bsv-d", "Docs pass", "open"),
+        ];
+        let mut editor = crate::DepEditor::new(&issue, &others);
+
+        terminal.draw(|frame| {
+            render_dep_editor(frame, &editor);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Dependencies of bsv-a"));
+        assert!(output.contains("blocks       bsv-b Second Issue"));
+
+        // Searching offers the other issues, minus existing dependencies
+        editor.start_search();
+        editor.search.as_mut().unwrap().set_query("parse");
+        editor.cycle_type();
+        editor.error = Some("Would create a cycle: bsv-a → bsv-c → bsv-a".to_string());
+        terminal.draw(|frame| {
+            render_dep_editor(frame, &editor);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Add (related): parse"));
+        assert!(output.contains("bsv-c Parser rewrite"));
+        assert!(!output.contains("bsv-d Docs pass"));
+        assert!(output.contains("Would create a cycle"));
+        assert_eq!(editor.current_match().as_deref(), Some("bsv-c"));
+    }
+
+    #[test]
+    fn test_finder_matches_all_issues() {
+        let backend = TestBackend::new(70, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let issues = vec![
+            make_test_issue("bsv-a", "First Issue", "open"),
+            make_test_issue("bsv-a.3.2", "Deeply nested", "open"),
+            make_test_issue("bsv-b", "Docs pass", "closed"),
+        ];
+        let mut finder = crate::IssueSearch::new(&issues);
+        finder.set_query("a32");
+        terminal.draw(|frame| {
+            render_finder(frame, &finder);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Go to issue (1)"));
+        assert!(output.contains("> a32"));
+        assert!(output.contains("bsv-a.3.2 Deeply nested"));
+        assert!(!output.contains("First Issue"));
+        assert_eq!(finder.current(), Some("bsv-a.3.2"));
+    }
+
+    #[test]
+    fn test_critical_path_and_impact_list() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut middle = make_test_issue("bsv-b", "Middle", "open");
+        middle.dependencies = Some(vec![crate::bd::Dependency {
+            id: "bsv-a".to_string(),
+            title: "Base".to_string(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let mut top = make_test_issue("bsv-c", "Top", "open");
+        top.dependencies = Some(vec![crate::bd::Dependency {
+            id: "bsv-b".to_string(),
+            title: "Middle".to_string(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let issues = vec![make_test_issue("bsv-a", "Base", "open"), middle, top];
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::DependencyBased);
+        assert!(tree.select_issue("bsv-c"));
+        let summary = DepSummary::new(&tree, "bsv-c");
+
+        terminal.draw(|frame| {
+            render(frame, &tree, None, Some(&summary), false, crate::Focus::Tree, 0, None, 0.5, 0, None, false, Overlays::default());
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Critical path (2 open blockers):"));
+        assert!(output.contains("→ bsv-b Middle"));
+        assert!(output.contains("→ bsv-a Base"));
+
+        let list = crate::ImpactList::new(&tree);
+        terminal.draw(|frame| {
+            render_impact_list(frame, &list);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("2  bsv-a Base"));
+        assert!(output.contains("1  bsv-b Middle"));
+        assert_eq!(list.current(), Some("bsv-a"));
+    }
+
+    #[test]
+    fn test_graph_view_centres_selected_issue() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let blocks = |id: &str| Some(vec![crate::bd::Dependency {
+            id: id.to_string(),
+            title: String::new(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let mut middle = make_test_issue("bsv-b", "Middle", "open");
+        middle.dependencies = blocks("bsv-a");
+        let mut top = make_test_issue("bsv-c", "Top", "open");
+        top.dependencies = blocks("bsv-b");
+        let issues = vec![make_test_issue("bsv-a", "Base", "open"), middle, top];
+        let tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::DependencyBased);
+
+        let graph = DepGraph::around(&tree, "bsv-b", 1);
+        terminal.draw(|frame| {
+            render_graph_view(frame, &tree, &graph);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Dependency graph: bsv-b (radius 1)"));
+        assert!(output.contains("│ bsv-a Base"));
+        assert!(output.contains("║ bsv-b Middle"));
+        assert!(output.contains("│ bsv-c Top"));
+        assert_eq!(output.matches('▼').count(), 2);
+
+        // The centre issue's box is in the middle of the screen
+        let lines: Vec<&str> = output.lines().collect();
+        let row = lines.iter().position(|l| l.contains("║ bsv-b Middle")).unwrap();
+        assert!((10..=13).contains(&row), "centre box on row {}", row);
+    }
+
+    #[test]
+    fn test_stats_dashboard() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut bare = make_test_issue("bsv-a.2", "Bare", "open");
+        bare.description = None;
+        let mut done = make_test_issue("bsv-a.1", "Done", "closed");
+        done.closed_at = Some("2026-10-15T09:00:00Z".to_string());
+        let mut epic = make_test_issue("bsv-a", "Epic", "open");
+        epic.issue_type = "epic".to_string();
+        let issues = vec![epic, done, bare];
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), crate::HierarchyMode::IdBased);
+        tree.now = activity::parse_timestamp("2026-10-16T12:00:00Z").unwrap();
+        let stats = ProjectStats::new(&tree);
+
+        terminal.draw(|frame| {
+            render_stats(frame, &tree, &stats);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Project statistics"));
+        assert!(output.contains(" 3 issues, 2 open"));
+        assert!(output.contains(" 2 ready / 0 blocked (100% ready)"));
+        assert!(output.contains(" 1 epics, 0 complete"));
+        assert!(output.contains("│open   2"));
+        assert!(output.contains("│P2 3"));
+        assert!(output.contains("Closed per week: 1 in 12 weeks"));
+        assert!(output.contains("1/2 █████░░░░░ bsv-a Epic"));
+        assert!(output.contains("No description (1)"));
+        assert!(output.contains(" bsv-a.2"));
+    }
+
+    #[test]
+    fn test_board_columns_and_cards() {
+        let backend = TestBackend::new(100, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut started = make_test_issue("bsv-b", "Started", "in_progress");
+        started.priority = 0;
+        let issues = vec![make_test_issue("bsv-a", "Waiting", "open"), started, make_test_issue("bsv-c", "Done", "closed")];
+        let tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), crate::HierarchyMode::IdBased);
+        let board = crate::Board { column: 1, ..Default::default() };
+
+        terminal.draw(|frame| {
+            render_board(frame, &tree, &board, frame.area(), Some("bsv-b is now in_progress"));
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("open (1)"));
+        assert!(output.contains("in progress (1)"));
+        assert!(output.contains("blocked (0)"));
+        assert!(output.contains("closed (0)"));
+        assert!(output.contains("c shows closed issues"));
+        assert!(output.contains("bsv-b P0"));
+        assert!(output.contains("Started"));
+        assert!(output.contains("bsv-b is now in_progress"));
+
+        // The selected card is highlighted and bordered in its priority color
+        let (y, line) = output.lines().enumerate().find(|(_, l)| l.contains("bsv-b P0")).unwrap();
+        let x = line[..line.find("bsv-b").unwrap()].chars().count() as u16;
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(x, y as u16)].bg, Color::DarkGray);
+        assert_eq!(buffer[(x - 1, y as u16)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_dep_ref_rows_are_marked() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(60, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut multi = make_test_issue("bsv-m", "Shared", "open");
+        multi.dependencies = Some(["bsv-a", "bsv-b"].iter().map(|id| crate::bd::Dependency {
+            id: id.to_string(),
+            title: String::new(),
+            dependency_type: Some("blocks".to_string()),
+        }).collect());
+        let issues = vec![make_test_issue("bsv-a", "Alpha", "open"), make_test_issue("bsv-b", "Beta", "open"), multi];
+        let expanded: HashSet<String> = ["bsv-a", "bsv-b"].iter().map(|s| s.to_string()).collect();
+        let mut tree = IssueTree::from_issues(issues, HashSet::new(), expanded, HierarchyMode::DependencyBased);
+        tree.toggle_dep_refs();
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("  bsv-m Shared"));
+        assert!(output.contains("  ↪ bsv-m Shared"));
+    }
+
+    #[test]
+    fn test_dependency_cycles_badge_and_list() {
+        use crate::HierarchyMode;
+
+        let backend = TestBackend::new(80, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let blocks = |id: &str| Some(vec![crate::bd::Dependency {
+            id: id.to_string(),
+            title: String::new(),
+            dependency_type: Some("blocks".to_string()),
+        }]);
+        let mut a = make_test_issue("bsv-a", "Alpha", "open");
+        a.dependencies = blocks("bsv-b");
+        let mut b = make_test_issue("bsv-b", "Beta", "open");
+        b.dependencies = blocks("bsv-a");
+        let tree = IssueTree::from_issues(vec![a, b], HashSet::new(), HashSet::new(), HierarchyMode::DependencyBased);
+
+        terminal.draw(|frame| {
+            render_tree_panel(frame, &tree, frame.area(), true, 0, None, false);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⟳ cycles: 1 (C)"));
+        assert!(output.contains("bsv-a Alpha"));
+
+        let mut list = crate::CycleList::new(&tree);
+        list.move_down();
+        terminal.draw(|frame| {
+            render_cycle_list(frame, &list);
+        }).unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Dependency cycles (1)"));
+        assert!(output.contains("Cycle 1: bsv-a, bsv-b"));
+        assert!(output.contains("bsv-a → bsv-b (blocks)"));
+        assert!(output.contains("bsv-b → bsv-a (blocks)"));
+        assert_eq!(list.current(), Some("bsv-b"));
+    }
+
     #[test]
     fn test_help_overlay_snapshot() {
         let backend = TestBackend::new(60, 35);
@@ -1065,6 +2556,23 @@ mod tests {
         assert!(output.contains("Quit"));
     }
 
+    #[test]
+    fn test_help_overlay_two_columns_when_short() {
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal.draw(|frame| {
+            render_help_overlay(frame);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+
+        // Everything fits, from the first section to the last
+        assert!(output.contains("Tree Panel"));
+        assert!(output.contains("Mouse"));
+        assert!(output.contains("Multi-parent"));
+    }
+
     #[test]
     fn test_detail_panel_rich_issue() {
         let backend = TestBackend::new(70, 30);
@@ -1074,7 +2582,7 @@ mod tests {
         let ready_ids: HashSet<String> = HashSet::new();
 
         terminal.draw(|frame| {
-            render_detail_panel(frame, Some(&issue), &ready_ids, frame.area(), true, 0, None);
+            render_detail_panel(frame, Some(&issue), &ready_ids, &DetailExtras::default(), frame.area(), true, 0, None);
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -1092,6 +2600,29 @@ mod tests {
         assert!(output.contains("bsv-child1"));
     }
 
+    #[test]
+    fn test_detail_panel_long_form_fields() {
+        let backend = TestBackend::new(70, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut issue = make_test_issue("bsv-a", "Spec'd issue", "open");
+        issue.design = Some("Use a **trie**".to_string());
+        issue.acceptance_criteria = Some("- Lookups under 1ms".to_string());
+        issue.notes = Some(String::new());
+
+        terminal.draw(|frame| {
+            render_detail_panel(frame, Some(&issue), &HashSet::new(), &DetailExtras::default(), frame.area(), true, 0, None);
+        }).unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Design:"));
+        assert!(output.contains("Use a trie")); // Markdown rendered
+        assert!(output.contains("Acceptance Criteria:"));
+        assert!(output.contains("Lookups under 1ms"));
+        // Empty fields are left out
+        assert!(!output.contains("Notes:"));
+    }
+
     #[test]
     fn test_detail_panel_priorities() {
         // Test different priority colors are rendered
@@ -1104,7 +2635,7 @@ mod tests {
             let ready_ids: HashSet<String> = HashSet::new();
 
             terminal.draw(|frame| {
-                render_detail_panel(frame, Some(&issue), &ready_ids, frame.area(), true, 0, None);
+                render_detail_panel(frame, Some(&issue), &ready_ids, &DetailExtras::default(), frame.area(), true, 0, None);
             }).unwrap();
 
             let output = buffer_to_string(terminal.backend().buffer());
@@ -1133,14 +2664,12 @@ mod tests {
             make_test_issue("bsv-b", "Second Issue", "open"),
         ];
         let expanded = HashSet::new();
-        let mut ready_ids = HashSet::new();
-        ready_ids.insert("bsv-a".to_string());
 
-        let tree = IssueTree::from_issues(issues, expanded, HashSet::new(), ready_ids, HierarchyMode::IdBased);
+        let tree = IssueTree::from_issues(issues, expanded, HashSet::new(), HierarchyMode::IdBased);
         let selected = make_test_issue("bsv-a", "First Issue", "open");
 
         terminal.draw(|frame| {
-            render(frame, &tree, Some(&selected), false, crate::Focus::Tree, 0, None, 0.4, 0, false, false);
+            render(frame, &tree, Some(&selected), None, false, crate::Focus::Tree, 0, None, 0.4, 0, None, false, Overlays::default());
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
@@ -1160,10 +2689,10 @@ mod tests {
         let mut terminal = Terminal::new(backend).unwrap();
 
         let issues = vec![make_test_issue("bsv-a", "Test", "open")];
-        let tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
+        let tree = IssueTree::from_issues(issues, HashSet::new(), HashSet::new(), HierarchyMode::IdBased);
 
         terminal.draw(|frame| {
-            render(frame, &tree, None, true, crate::Focus::Tree, 0, None, 0.4, 0, false, false); // show_help = true
+            render(frame, &tree, None, None, true, crate::Focus::Tree, 0, None, 0.4, 0, None, false, Overlays::default()); // show_help = true
         }).unwrap();
 
         let output = buffer_to_string(terminal.backend().buffer());
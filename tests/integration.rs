@@ -3,62 +3,107 @@
 //! These tests spawn bsv in a tmux session, send keystrokes, and verify output.
 //! Requires tmux to be installed.
 //!
+//! bsv runs against the fake bd from `tests/support/fake_bd.rs`, serving a copy of
+//! `tests/fixtures/issues.json` from a scratch directory, so results don't depend on
+//! any real beads database. The scratch directory is also used as HOME so saved
+//! state doesn't leak between tests.
+//!
 //! Note: Tests must run serially to avoid tmux session conflicts.
 
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 static TEST_COUNTER: AtomicUsize = AtomicUsize::new(0);
 const BSV_PATH: &str = env!("CARGO_BIN_EXE_bsv");
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/issues.json");
 
 fn get_session_name() -> String {
     let id = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
     format!("bsv-test-{}-{}", std::process::id(), id)
 }
 
+/// The fake bd is built as an example next to the bsv binary
+fn fake_bd_path() -> PathBuf {
+    Path::new(BSV_PATH)
+        .parent()
+        .expect("bsv binary has a parent directory")
+        .join("examples")
+        .join("fake-bd")
+}
+
 /// Test harness that manages a tmux session
 struct TmuxTest {
     session_name: String,
+    /// Scratch directory holding the fixture copy, bd call log and HOME
+    dir: PathBuf,
 }
 
 impl TmuxTest {
     fn new() -> Option<Self> {
+        Self::launch(&[], &[])
+    }
+
+    /// Start bsv with extra environment variables (e.g. `FAKE_BD_FAIL`)
+    fn with_env(extra_env: &[(&str, &str)]) -> Option<Self> {
+        Self::launch(extra_env, &[])
+    }
+
+    /// Start bsv with extra environment variables and command-line arguments
+    fn launch(extra_env: &[(&str, &str)], bsv_args: &[&str]) -> Option<Self> {
         if !Self::tmux_available() {
             return None;
         }
 
         let session_name = get_session_name();
 
+        let dir = std::env::temp_dir().join(&session_name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("Failed to create scratch dir");
+        std::fs::copy(FIXTURE, dir.join("issues.json")).expect("Failed to copy fixture");
+
         // Kill any existing session with this name
         let _ = Command::new("tmux")
             .args(["kill-session", "-t", &session_name])
             .output();
 
+        let mut env = vec![
+            format!("HOME={}", dir.display()),
+            // The one variable selecting bd; it replaced BSV_BD_PATH
+            format!("BSV_BD={}", fake_bd_path().display()),
+            format!("FAKE_BD_FIXTURE={}", dir.join("issues.json").display()),
+            format!("FAKE_BD_LOG={}", dir.join("bd.log").display()),
+        ];
+        env.extend(extra_env.iter().map(|(k, v)| format!("{}={}", k, v)));
+
         // Start new session with bsv
-        let result = Command::new("tmux")
-            .args([
-                "new-session",
-                "-d",
-                "-s",
-                &session_name,
-                "-x",
-                "100",
-                "-y",
-                "30",
-                BSV_PATH,
-            ])
-            .output();
+        let mut args: Vec<String> = [
+            "new-session", "-d", "-s", &session_name, "-x", "100", "-y", "30", "-c",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        args.push(dir.display().to_string());
+        args.push("env".to_string());
+        args.extend(env);
+        args.push(BSV_PATH.to_string());
+        args.extend(bsv_args.iter().map(|s| s.to_string()));
+
+        let result = Command::new("tmux").args(&args).output();
 
         if result.is_err() {
             return None;
         }
 
-        // Wait for bsv to start
-        sleep(Duration::from_millis(800));
+        let test = TmuxTest { session_name, dir };
 
-        Some(TmuxTest { session_name })
+        // Wait for bsv to start and load the fixture
+        test.wait_for("Issues");
+        sleep(Duration::from_millis(300));
+
+        Some(test)
     }
 
     fn tmux_available() -> bool {
@@ -76,6 +121,14 @@ impl TmuxTest {
         sleep(Duration::from_millis(250));
     }
 
+    /// Type text literally (no key name lookup)
+    fn send_text(&self, text: &str) {
+        let _ = Command::new("tmux")
+            .args(["send-keys", "-t", &self.session_name, "-l", "--", text])
+            .output();
+        sleep(Duration::from_millis(250));
+    }
+
     fn capture_pane(&self) -> String {
         let output = Command::new("tmux")
             .args([
@@ -92,6 +145,37 @@ impl TmuxTest {
             .expect("Failed to capture pane");
         String::from_utf8_lossy(&output.stdout).to_string()
     }
+
+    /// Poll the pane until it contains `text` (up to 5 seconds) and return it
+    fn wait_for(&self, text: &str) -> String {
+        let start = Instant::now();
+        loop {
+            let pane = self.capture_pane();
+            if pane.contains(text) || start.elapsed() > Duration::from_secs(5) {
+                return pane;
+            }
+            sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// Arguments of every bd invocation so far
+    fn bd_calls(&self) -> Vec<Vec<String>> {
+        std::fs::read_to_string(self.dir.join("bd.log"))
+            .unwrap_or_default()
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("bd.log line is a JSON array"))
+            .collect()
+    }
+
+    /// Tree rows (left panel text) in display order, trimmed
+    fn tree_rows(&self) -> Vec<String> {
+        self.capture_pane()
+            .lines()
+            .filter_map(|line| line.strip_prefix('│'))
+            .map(|line| line.split('│').next().unwrap_or("").trim().to_string())
+            .filter(|row| !row.is_empty())
+            .collect()
+    }
 }
 
 impl Drop for TmuxTest {
@@ -106,6 +190,8 @@ impl Drop for TmuxTest {
         let _ = Command::new("tmux")
             .args(["kill-session", "-t", &self.session_name])
             .output();
+
+        let _ = std::fs::remove_dir_all(&self.dir);
     }
 }
 
@@ -263,24 +349,22 @@ fn test_space_toggle_expand() {
         }
     };
 
-    // Navigate to an expandable node (the Test Epic)
-    test.send_keys("j");
-    test.send_keys("j");
+    // Navigate to an expandable node (the bsv-search epic)
     test.send_keys("j");
     test.send_keys("j");
-    sleep(Duration::from_millis(200));
+    test.wait_for("Title: Search overhaul");
 
-    let before_space = test.capture_pane();
+    let before_space = test.tree_rows();
 
     // Press space to toggle
     test.send_keys("Space");
-    sleep(Duration::from_millis(200));
 
-    let after_space = test.capture_pane();
+    let after_space = test.tree_rows();
 
-    // Space should toggle the expand state
-    assert!(before_space.contains("Issues"));
-    assert!(after_space.contains("Issues"));
+    // Space expands the epic, showing its open children
+    assert!(before_space[2].starts_with("▶ bsv-search"), "{:?}", before_space);
+    assert!(after_space[2].starts_with("▼ bsv-search"), "{:?}", after_space);
+    assert_eq!(after_space.len(), before_space.len() + 3);
 }
 
 #[test]
@@ -621,3 +705,1291 @@ fn test_responsive_ui_with_async_loading() {
         after_load.chars().take(500).collect::<String>()
     );
 }
+
+#[test]
+fn test_fixture_root_order() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("bsv-rel");
+
+    // Roots are sorted by priority; the closed bsv-old is hidden by default
+    let ids: Vec<String> = test.tree_rows()
+        .iter()
+        .filter_map(|row| row.split_whitespace().find(|w| w.starts_with("bsv-")))
+        .map(|id| id.to_string())
+        .collect();
+    assert_eq!(
+        ids,
+        vec!["bsv-crash", "bsv-perf", "bsv-search", "bsv-ui", "bsv-docs", "bsv-rel"]
+    );
+
+    // Showing closed issues brings it back
+    test.send_keys("c");
+    assert!(test.capture_pane().contains("bsv-old"));
+}
+
+#[test]
+fn test_expand_hides_closed_children() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.send_keys("j");
+    test.send_keys("j");
+    test.send_keys("Space");
+    let pane = test.wait_for("bsv-search.4");
+
+    assert!(pane.contains("bsv-search.1 Tokenize queries"));
+    assert!(pane.contains("bsv-search.2 Rank results"));
+    assert!(pane.contains("bsv-search.4 Highlight matches"));
+    assert!(!pane.contains("bsv-search.3"), "closed child should be hidden");
+}
+
+#[test]
+fn test_edit_title_calls_bd_update() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+
+    // Focus details, edit the title, append text and save
+    test.send_keys("Enter");
+    test.send_keys("i");
+    test.send_text(" again");
+    test.send_keys("C-s");
+
+    let pane = test.wait_for("Title: Crash on empty input again");
+    assert!(pane.contains("Title: Crash on empty input again"));
+
+    let updates: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "update"))
+        .collect();
+    assert_eq!(
+        updates,
+        vec![vec!["update", "bsv-crash", "--title", "Crash on empty input again"]]
+    );
+}
+
+#[test]
+fn test_bd_list_failure_is_reported() {
+    let test = match TmuxTest::with_env(&[("FAKE_BD_FAIL", "list")]) {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    let pane = test.wait_for("bd list failed");
+    assert!(pane.contains("bd list failed"), "got: {}", pane);
+}
+
+#[test]
+fn test_save_failure_is_reported() {
+    let test = match TmuxTest::with_env(&[("FAKE_BD_FAIL", "update")]) {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+
+    test.send_keys("Enter");
+    test.send_keys("i");
+    test.send_text("!");
+    test.send_keys("C-s");
+
+    let pane = test.wait_for("Save failed");
+    assert!(pane.contains("Save failed"), "got: {}", pane);
+
+    // The edit stays open after a failed save; cancelling leaves the title unchanged
+    test.send_keys("Escape");
+    let pane = test.wait_for("Title: Crash on empty input");
+    assert!(pane.contains("Title: Crash on empty input"));
+    assert!(!pane.contains("input!"));
+}
+
+#[test]
+fn test_bd_option_overrides_env() {
+    let fake_bd = fake_bd_path().display().to_string();
+    let test = match TmuxTest::launch(&[("BSV_BD", "/nonexistent/bd")], &["--bd", &fake_bd]) {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    let pane = test.wait_for("bsv-crash");
+    assert!(pane.contains("bsv-crash Crash on empty input"), "got: {}", pane);
+}
+
+#[test]
+fn test_hanging_bd_info_doesnt_block_startup() {
+    let test = match TmuxTest::with_env(&[("FAKE_BD_HANG", "info")]) {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    let pane = test.wait_for("bsv-crash");
+    assert!(pane.contains("bsv-crash Crash on empty input"), "got: {}", pane);
+}
+
+#[test]
+fn test_dir_option_sets_bd_working_directory() {
+    // A second project whose fixture differs from the one in bsv's own cwd
+    let project = std::env::temp_dir().join(format!("bsv-test-project-{}", std::process::id()));
+    std::fs::create_dir_all(&project).unwrap();
+    let fixture = std::fs::read_to_string(FIXTURE).unwrap()
+        .replace("Crash on empty input", "Crash in other project");
+    std::fs::write(project.join("issues.json"), fixture).unwrap();
+
+    // The relative fixture path only resolves to the other project if bd runs there
+    let dir = project.display().to_string();
+    let test = TmuxTest::launch(&[("FAKE_BD_FIXTURE", "issues.json")], &["--dir", &dir]);
+    let pane = test.as_ref().map(|t| t.wait_for("bsv-crash"));
+    drop(test);
+    let _ = std::fs::remove_dir_all(&project);
+
+    let pane = match pane {
+        Some(p) => p,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+    assert!(pane.contains("bsv-crash Crash in other project"), "got: {}", pane);
+}
+
+#[test]
+fn test_create_child_issue() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // Select the bsv-search epic and open the new child form
+    test.send_keys("j");
+    test.send_keys("j");
+    test.wait_for("Title: Search overhaul");
+    test.send_keys("A");
+    test.wait_for("New child of bsv-search");
+
+    // Title, then Tab to priority and replace the default
+    test.send_text("Cache results");
+    test.send_keys("Tab");
+    test.send_keys("Tab");
+    test.send_keys("BSpace");
+    test.send_text("1");
+    test.send_keys("C-s");
+
+    // The new child is selected, with its parent expanded to reveal it
+    let pane = test.wait_for("Title: Cache results");
+    assert!(pane.contains("Created bsv-search.5"), "got: {}", pane);
+    assert!(pane.contains("bsv-search.5 Cache results"), "got: {}", pane);
+    assert!(pane.contains("Priority: P1"), "got: {}", pane);
+
+    let creates: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "create"))
+        .collect();
+    assert_eq!(
+        creates,
+        vec![vec![
+            "create", "--title", "Cache results", "--type", "task", "--priority", "1",
+            "--parent", "bsv-search", "--json",
+        ]]
+    );
+}
+
+#[test]
+fn test_create_issue_with_dash_leading_title() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("a");
+    test.wait_for("New issue");
+    test.send_text("--help output wrong");
+    test.send_keys("C-s");
+
+    let pane = test.wait_for("Title: --help output wrong");
+    assert!(pane.contains("Created bsv-new"), "got: {}", pane);
+    let creates: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "create"))
+        .collect();
+    assert_eq!(creates.len(), 1);
+    assert_eq!(creates[0][1..3], ["--title", "--help output wrong"]);
+}
+
+#[test]
+fn test_create_requires_title() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.send_keys("a");
+    test.wait_for("New issue");
+    test.send_keys("C-s");
+
+    let pane = test.wait_for("Title is required");
+    assert!(pane.contains("Title is required"), "got: {}", pane);
+    assert!(!test.bd_calls().iter().any(|args| args[0] == "create"));
+}
+
+#[test]
+fn test_close_issue_with_reason() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("x");
+    test.wait_for("Close bsv-crash");
+    test.send_text("Fixed");
+    test.send_keys("Enter");
+
+    // The closed issue disappears right away (closed issues are hidden by default)
+    let pane = test.wait_for("Closed bsv-crash");
+    assert!(pane.contains("Closed bsv-crash"), "got: {}", pane);
+    assert!(!test.tree_rows().iter().any(|row| row.contains("bsv-crash")));
+
+    assert!(test.bd_calls().contains(&vec![
+        "close".to_string(), "bsv-crash".to_string(), "--reason".to_string(), "Fixed".to_string(),
+    ]));
+
+    // It is still there with closed issues shown
+    test.send_keys("c");
+    assert!(test.tree_rows().iter().any(|row| row.contains("bsv-crash")));
+}
+
+#[test]
+fn test_kanban_board() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // The board opens on the selected issue's card
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("b");
+    let pane = test.wait_for("in progress (");
+    assert!(pane.contains("bsv-crash P0"), "got: {}", pane);
+    assert!(pane.contains("blocked (0)"), "got: {}", pane);
+    assert!(pane.contains("c shows closed issues"), "got: {}", pane);
+
+    // L moves the card one column right through bd
+    test.send_keys("L");
+    test.wait_for("bsv-crash is now in_progress");
+    assert!(test.bd_calls().contains(&vec![
+        "update".to_string(), "bsv-crash".to_string(), "--status".to_string(), "in_progress".to_string(),
+    ]));
+
+    // Moving into the closed column asks for a reason, as x does
+    test.send_keys("L");
+    test.wait_for("bsv-crash is now blocked");
+    test.send_keys("L");
+    test.wait_for("Close bsv-crash");
+    test.send_keys("Escape");
+
+    // Enter leaves the board with the card selected in the tree
+    test.send_keys("H");
+    test.wait_for("bsv-crash is now in_progress");
+    test.send_keys("Enter");
+    let pane = test.wait_for("Issues (Epics)");
+    assert!(pane.contains("Status: in_progress"), "got: {}", pane);
+    assert!(pane.contains("ID: bsv-crash"), "got: {}", pane);
+}
+
+#[test]
+fn test_mark_in_progress_and_reopen() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("w");
+    let pane = test.wait_for("Status: in_progress");
+    assert!(pane.contains("Status: in_progress"), "got: {}", pane);
+
+    test.send_keys("o");
+    let pane = test.wait_for("Reopened bsv-crash");
+    assert!(pane.contains("Status: open"), "got: {}", pane);
+
+    let status_calls: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "update"))
+        .collect();
+    assert_eq!(status_calls, vec![
+        vec!["update", "bsv-crash", "--status", "in_progress"],
+        vec!["update", "bsv-crash", "--status", "open"],
+    ]);
+}
+
+#[test]
+fn test_priority_picker_resorts_tree() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // bsv-rel (P4) is the last root; raising it to P0 moves it up next to bsv-crash
+    test.send_keys("G");
+    test.wait_for("Title: Release checklist");
+    test.send_keys("P");
+    test.wait_for("Priority for bsv-rel");
+    test.send_keys("0");
+
+    let pane = test.wait_for("Priority: P0");
+    assert!(pane.contains("Priority: P0"), "got: {}", pane);
+    let rows = test.tree_rows();
+    assert!(rows[0].contains("bsv-crash"), "{:?}", rows);
+    assert!(rows[1].contains("bsv-rel"), "{:?}", rows);
+    assert!(test.bd_calls().contains(&vec![
+        "update".to_string(), "bsv-rel".to_string(), "--priority".to_string(), "0".to_string(),
+    ]));
+}
+
+#[test]
+fn test_type_picker() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // bsv-crash is a bug; the next type in the list is feature
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("t");
+    test.wait_for("Type for bsv-crash");
+    test.send_keys("j");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("Type: feature");
+    assert!(pane.contains("Type: feature"), "got: {}", pane);
+    assert!(test.bd_calls().contains(&vec![
+        "update".to_string(), "bsv-crash".to_string(), "--type".to_string(), "feature".to_string(),
+    ]));
+}
+
+#[test]
+fn test_label_editor_adds_and_removes() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("L");
+    let pane = test.wait_for("Labels for bsv-crash");
+
+    // Labels from other issues are offered for autocompletion
+    assert!(pane.contains("[ ] backend"), "got: {}", pane);
+
+    // Add a brand new label, and check an existing one found by filtering
+    test.send_text("regression");
+    test.send_keys("Enter");
+    test.send_text("back");
+    test.send_keys("Tab");
+    test.send_keys("Enter");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("Labels: ");
+    assert!(!pane.contains("Labels for bsv-crash"), "editor should close: {}", pane);
+
+    let label_calls: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "label"))
+        .collect();
+    assert_eq!(label_calls, vec![
+        vec!["label", "add", "bsv-crash", "backend"],
+        vec!["label", "add", "bsv-crash", "regression"],
+    ]);
+}
+
+#[test]
+fn test_add_dependency_by_search() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("D");
+    test.wait_for("No dependencies");
+    test.send_keys("a");
+    test.send_text("release");
+    test.wait_for("bsv-rel Release checklist");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("blocks       bsv-rel Release checklist");
+    assert!(pane.contains("Dependencies of bsv-crash"), "got: {}", pane);
+    assert!(pane.contains("Added dependency on bsv-rel"), "got: {}", pane);
+    assert!(test.bd_calls().contains(&vec![
+        "dep".to_string(), "add".to_string(), "bsv-crash".to_string(),
+        "bsv-rel".to_string(), "--type".to_string(), "blocks".to_string(),
+    ]));
+
+    // The details panel picks up the new blocker
+    test.send_keys("Escape");
+    let pane = test.wait_for("bsv-rel (blocks) - Release checklist");
+    assert!(pane.contains("BLOCKED"), "got: {}", pane);
+}
+
+#[test]
+fn test_add_dependency_refuses_cycle() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // bsv-docs depends on bsv-search, so bsv-search can't depend on bsv-docs
+    test.send_keys("j");
+    test.send_keys("j");
+    test.wait_for("Title: Search overhaul");
+    test.send_keys("D");
+    test.send_keys("a");
+    test.send_text("user guide");
+    test.wait_for("bsv-docs Write user guide");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("Would create a cycle");
+    assert!(pane.contains("bsv-search → bsv-docs → bsv-search"), "got: {}", pane);
+    assert!(!test.bd_calls().iter().any(|args| args.first().is_some_and(|a| a == "dep")));
+
+    // A related link doesn't form a hierarchy, so it's allowed
+    test.send_keys("Tab");
+    test.wait_for("Add (related)");
+    test.send_keys("Enter");
+    test.wait_for("related      bsv-docs Write user guide");
+}
+
+#[test]
+fn test_remove_dependency() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.send_keys("G");
+    test.send_keys("k");
+    test.wait_for("Title: Write user guide");
+    test.send_keys("D");
+    test.wait_for("blocks       bsv-search Search overhaul");
+    test.send_keys("x");
+
+    let pane = test.wait_for("No dependencies");
+    assert!(pane.contains("Removed dependency on bsv-search"), "got: {}", pane);
+    assert!(test.bd_calls().contains(&vec![
+        "dep".to_string(), "remove".to_string(), "bsv-docs".to_string(), "bsv-search".to_string(),
+    ]));
+}
+
+#[test]
+fn test_tab_cycles_through_long_form_fields() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+
+    // Title -> Description -> Design
+    test.send_keys("Enter");
+    test.send_keys("i");
+    test.send_keys("Tab");
+    test.wait_for("Editing Description");
+    test.send_keys("Tab");
+    test.wait_for("Editing Design");
+    test.send_text("Guard the parser");
+    test.send_keys("Enter");
+    test.send_text("with a length check");
+
+    // Tab saves and moves on to acceptance criteria
+    test.send_keys("Tab");
+    test.wait_for("Editing Acceptance Criteria");
+    test.send_text("No panic on empty input");
+
+    // Shift+Tab saves and goes back to design, keeping the saved text
+    test.send_keys("BTab");
+    let pane = test.wait_for("Editing Design");
+    assert!(pane.contains("with a length check"), "got: {}", pane);
+    test.send_keys("Escape");
+
+    let pane = test.wait_for("Acceptance Criteria:");
+    assert!(pane.contains("Design:"), "got: {}", pane);
+    assert!(pane.contains("No panic on empty input"), "got: {}", pane);
+
+    let updates: Vec<Vec<String>> = test.bd_calls()
+        .into_iter()
+        .filter(|args| args.first().is_some_and(|a| a == "update"))
+        .collect();
+    assert_eq!(updates, vec![
+        vec!["update", "bsv-crash", "--design", "Guard the parser\nwith a length check"],
+        vec!["update", "bsv-crash", "--acceptance", "No panic on empty input"],
+    ]);
+}
+
+#[test]
+fn test_search_reveals_nested_match() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("/");
+    test.wait_for("Search /");
+    test.send_text("highlight");
+
+    // Found as you type, inside the collapsed bsv-search epic
+    let pane = test.wait_for("Title: Highlight matches");
+    assert!(pane.contains("/highlight [1]"), "got: {}", pane);
+    assert!(test.tree_rows().iter().any(|r| r.contains("bsv-search.4 Highlight matches")));
+
+    // Enter keeps the search, even across a refresh; Esc clears it
+    test.send_keys("Enter");
+    test.send_keys("r");
+    sleep(Duration::from_millis(500));
+    let pane = test.capture_pane();
+    assert!(pane.contains("/highlight [1]"), "got: {}", pane);
+    test.send_keys("Escape");
+    sleep(Duration::from_millis(300));
+    let pane = test.capture_pane();
+    assert!(!pane.contains("/highlight"), "got: {}", pane);
+    assert!(pane.contains("Title: Highlight matches"), "got: {}", pane);
+}
+
+#[test]
+fn test_search_next_and_previous() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("/");
+    test.send_text("bsv-search.");
+    test.send_keys("Enter");
+    let first = test.wait_for("/bsv-search. [3]");
+    let title = |pane: &str| pane.lines()
+        .find_map(|l| l.split("Title: ").nth(1).map(|t| t.trim_end_matches(['│', ' ']).to_string()))
+        .unwrap();
+    let first_title = title(&first);
+
+    test.send_keys("n");
+    sleep(Duration::from_millis(300));
+    let second_title = title(&test.capture_pane());
+    assert_ne!(first_title, second_title);
+
+    test.send_keys("N");
+    sleep(Duration::from_millis(300));
+    assert_eq!(title(&test.capture_pane()), first_title);
+
+    // n/N don't open the new issue form, with or without a search
+    assert!(!test.capture_pane().contains("New issue"));
+    test.send_keys("Escape");
+    sleep(Duration::from_millis(300));
+    test.send_keys("n");
+    let pane = test.wait_for("No active search");
+    assert!(!pane.contains("New issue"), "got: {}", pane);
+}
+
+#[test]
+fn test_search_cancel_restores_selection() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("/");
+    test.send_text("user guide");
+    test.wait_for("Title: Write user guide");
+    test.send_keys("Escape");
+
+    let pane = test.wait_for("Title: Crash on empty input");
+    assert!(!pane.contains("Search /"), "got: {}", pane);
+}
+
+#[test]
+fn test_filter_narrows_tree_and_is_remembered() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("f");
+    test.wait_for("Filter (");
+    test.send_text("priority:3 -label:frontend");
+    test.send_keys("Enter");
+    test.wait_for("[filter: priority:3 -label:frontend]");
+
+    // The bsv-docs match, plus nothing else: bsv-search.4 is frontend
+    let rows = test.tree_rows();
+    assert_eq!(rows.len(), 1, "got: {:?}", rows);
+    assert!(rows[0].contains("bsv-docs"), "got: {:?}", rows);
+
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("priority:3 -label:frontend"), "got: {}", state);
+
+    // Matches keep their ancestors, and the filter survives a refresh
+    test.send_keys("f");
+    for _ in 0.."priority:3 -label:frontend".len() {
+        test.send_keys("BSpace");
+    }
+    test.send_text("status:closed");
+    test.send_keys("Enter");
+    test.wait_for("[filter: status:closed]");
+    test.send_keys("r");
+    sleep(Duration::from_millis(500));
+    let rows = test.tree_rows();
+    assert!(rows.iter().any(|r| r.contains("bsv-search Search")), "got: {:?}", rows);
+    assert!(rows.iter().any(|r| r.contains("bsv-search.3")), "got: {:?}", rows);
+    assert!(rows.iter().any(|r| r.contains("bsv-old")), "got: {:?}", rows);
+    assert!(!rows.iter().any(|r| r.contains("bsv-crash")), "got: {:?}", rows);
+}
+
+#[test]
+fn test_invalid_filter_keeps_prompt_open() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("f");
+    test.send_text("colour:red");
+    test.send_keys("Enter");
+    let pane = test.wait_for("Unknown filter field 'colour'");
+    assert!(pane.contains("Filter ("), "got: {}", pane);
+
+    test.send_keys("Escape");
+    sleep(Duration::from_millis(300));
+    let pane = test.capture_pane();
+    assert!(!pane.contains("[filter:"), "got: {}", pane);
+    assert!(test.tree_rows().len() > 1);
+}
+
+#[test]
+fn test_save_and_use_named_filter() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("f");
+    test.send_text("type:bug");
+    test.send_keys("Enter");
+    test.wait_for("[filter: type:bug]");
+
+    test.send_keys("F");
+    test.wait_for("Save filter as");
+    test.send_text("bugs");
+    test.send_keys("Enter");
+    test.wait_for("Saved filter @bugs");
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("\"bugs\""), "got: {}", state);
+
+    // Clear the filter, then bring it back by name
+    test.send_keys("f");
+    for _ in 0.."type:bug".len() {
+        test.send_keys("BSpace");
+    }
+    test.send_keys("Enter");
+    sleep(Duration::from_millis(300));
+    assert!(!test.capture_pane().contains("[filter:"));
+    assert!(test.tree_rows().len() > 1);
+
+    test.send_keys("f");
+    test.wait_for("saved: @bugs");
+    test.send_text("@bugs");
+    test.send_keys("Enter");
+    test.wait_for("[filter: @bugs]");
+    let rows = test.tree_rows();
+    assert_eq!(rows.len(), 1, "got: {:?}", rows);
+    assert!(rows[0].contains("bsv-crash"), "got: {:?}", rows);
+}
+
+#[test]
+fn test_finder_expands_path_to_issue() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    assert!(!test.tree_rows().iter().any(|r| r.contains("bsv-search.1")));
+
+    test.send_keys("C-p");
+    test.wait_for("Go to issue");
+    test.send_text("tokenize");
+    test.wait_for("bsv-search.1 Tokenize queries");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("Title: Tokenize queries");
+    assert!(!pane.contains("Go to issue"), "got: {}", pane);
+    let rows = test.tree_rows();
+    assert!(rows.iter().any(|r| r.contains("▼ bsv-search Search overhaul")), "got: {:?}", rows);
+    assert!(rows.iter().any(|r| r.contains("bsv-search.1 Tokenize queries")), "got: {:?}", rows);
+}
+
+#[test]
+fn test_finder_reveals_closed_issue() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("C-p");
+    test.send_text("legacy");
+    test.wait_for("bsv-old Remove legacy importer");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("Title: Remove legacy importer");
+    assert!(pane.contains("Revealed bsv-old: closed shown"), "got: {}", pane);
+    assert!(test.tree_rows().iter().any(|r| r.contains("bsv-old")));
+}
+
+#[test]
+fn test_finder_clears_filter_without_saving() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("f");
+    test.wait_for("Filter (");
+    test.send_text("priority:3");
+    test.send_keys("Enter");
+    test.wait_for("[filter: priority:3]");
+
+    test.send_keys("C-p");
+    test.send_text("crash");
+    test.wait_for("bsv-crash Crash on empty input");
+    test.send_keys("Enter");
+
+    let pane = test.wait_for("filter off");
+    assert!(pane.contains("Revealed bsv-crash: filter off"), "got: {}", pane);
+    assert!(!pane.contains("[filter:"), "got: {}", pane);
+
+    // The saved filter comes back next time
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("priority:3"), "got: {}", state);
+}
+
+#[test]
+fn test_sort_cycle_and_reverse() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("bsv-rel");
+    let root_ids = || -> Vec<String> {
+        test.tree_rows()
+            .iter()
+            .filter_map(|row| row.split_whitespace().find(|w| w.starts_with("bsv-")))
+            .map(|id| id.to_string())
+            .collect()
+    };
+
+    // priority -> updated -> created -> id
+    for _ in 0..3 {
+        test.send_keys("s");
+    }
+    test.wait_for("[by id]");
+    assert_eq!(root_ids(), vec!["bsv-crash", "bsv-docs", "bsv-perf", "bsv-rel", "bsv-search", "bsv-ui"]);
+
+    test.send_keys("S");
+    test.wait_for("[by id ↑]");
+    assert_eq!(root_ids(), vec!["bsv-ui", "bsv-search", "bsv-rel", "bsv-perf", "bsv-docs", "bsv-crash"]);
+
+    // Children use the same order (the selection stays on bsv-crash, now at the bottom)
+    test.send_keys("g");
+    test.send_keys("Space");
+    sleep(Duration::from_millis(300));
+    let ids = root_ids();
+    assert_eq!(&ids[1..4], ["bsv-ui.20", "bsv-ui.19", "bsv-ui.18"], "got: {:?}", ids);
+
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("\"sort_mode\": \"Id\""), "got: {}", state);
+    assert!(state.contains("\"sort_reverse\": true"), "got: {}", state);
+}
+
+#[test]
+fn test_group_by_views() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("v");
+    test.wait_for("Issues (by status)");
+    let rows = test.tree_rows();
+    assert!(rows[0].contains("▶ in_progress (1)"), "got: {:?}", rows);
+    assert!(rows[1].contains("▶ open ("), "got: {:?}", rows);
+    assert_eq!(rows.len(), 2, "got: {:?}", rows);
+
+    // Headers expand like epics, and aren't issues to act on
+    test.send_keys("Space");
+    sleep(Duration::from_millis(300));
+    let rows = test.tree_rows();
+    assert!(rows[0].contains("▼ in_progress (1)"), "got: {:?}", rows);
+    assert!(rows[1].contains("bsv-perf"), "got: {:?}", rows);
+    test.send_keys("x");
+    sleep(Duration::from_millis(300));
+    assert!(!test.capture_pane().contains("Close reason"));
+
+    // status -> type -> label -> priority -> assignee
+    for _ in 0..4 {
+        test.send_keys("v");
+    }
+    test.wait_for("Issues (by assignee)");
+    let rows = test.tree_rows();
+    let headers: Vec<&str> = rows.iter().map(|r| r.rsplit_once(" (").unwrap().0.trim()).collect();
+    assert_eq!(headers, ["▶ alice", "▶ bob", "▶ (unassigned)"], "got: {:?}", rows);
+
+    // Back to the Epics view
+    test.send_keys("v");
+    test.wait_for("Issues (Epics)");
+}
+
+#[test]
+fn test_parent_child_dependency_reparents_issue() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // Make bsv-crash a child of bsv-ui, which its ID says nothing about
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("D");
+    test.send_keys("a");
+    test.send_text("support");
+    test.wait_for("bsv-ui Theme support");
+    test.send_keys("Tab");
+    test.send_keys("Tab");
+    test.wait_for("Add (parent-child)");
+    test.send_keys("Enter");
+    test.wait_for("parent-child bsv-ui Theme support");
+    test.send_keys("Escape");
+
+    // It moves out of the roots and under the epic
+    let start = std::time::Instant::now();
+    while test.tree_rows().iter().any(|r| r.contains("bsv-crash")) && start.elapsed() < Duration::from_secs(5) {
+        sleep(Duration::from_millis(100));
+    }
+    assert!(!test.tree_rows().iter().any(|r| r.contains("bsv-crash")), "got: {:?}", test.tree_rows());
+    test.send_keys("C-p");
+    test.send_text("crash");
+    test.wait_for("bsv-crash Crash on empty input");
+    test.send_keys("Enter");
+    sleep(Duration::from_millis(300));
+    let rows = test.tree_rows();
+    let epic = rows.iter().position(|r| r.contains("bsv-ui Theme support")).expect("epic shown");
+    assert!(rows[epic + 1].contains("bsv-crash"), "got: {:?}", rows);
+}
+
+#[test]
+fn test_epic_rollups() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // The closed spike counts towards progress even though it's hidden.
+    // Rows are cut off at the panel edge.
+    let pane = test.wait_for("bsv-search Search overhaul [1/4");
+    assert!(pane.contains("bsv-search Search overhaul [1/4 ✓ 1"), "got: {}", pane);
+    assert!(pane.contains("bsv-ui Theme support [0/20 ✓ 14"), "got: {}", pane);
+    assert!(pane.contains("  bsv-crash Crash on empty input "), "got: {}", pane);
+
+    test.send_keys("j");
+    test.send_keys("j");
+    let pane = test.wait_for("Progress:");
+    assert!(pane.contains("1/4 (25%)"), "got: {}", pane);
+    assert!(pane.contains("open 3 · closed 1"), "got: {}", pane);
+}
+
+#[test]
+fn test_critical_path_and_impact_list() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // bsv-search.1 holds up .2, which holds up .4
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("I");
+    let pane = test.wait_for("Unblocks most work");
+    let first = pane.lines().find(|l| l.contains("bsv-search.1 Tokenize queries")).unwrap_or_default();
+    assert!(first.contains(" 2  bsv-search.1"), "got: {}", pane);
+    test.send_keys("Enter");
+    test.wait_for("Title: Tokenize queries");
+
+    // In the Deps view the details panel shows the chain of blockers
+    test.send_keys("d");
+    test.send_keys("C-p");
+    test.send_text("highlight");
+    test.wait_for("bsv-search.4 Highlight matches");
+    test.send_keys("Enter");
+    let pane = test.wait_for("Critical path (2 open blockers):");
+    assert!(pane.contains("→ bsv-search.2 Rank results"), "got: {}", pane);
+    assert!(pane.contains("→ bsv-search.1 Tokenize queries"), "got: {}", pane);
+
+    // It follows the view without the selection changing
+    test.send_keys("d");
+    test.wait_for("Issues (Epics)");
+    assert!(!test.capture_pane().contains("Critical path"));
+    test.send_keys("d");
+    test.wait_for("Critical path (2 open blockers):");
+}
+
+#[test]
+fn test_dependency_graph_view() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // bsv-search.1 blocks .2, which blocks .4
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("C-p");
+    test.send_text("rank");
+    test.wait_for("bsv-search.2 Rank results");
+    test.send_keys("Enter");
+    test.wait_for("Title: Rank results");
+
+    test.send_keys("V");
+    let pane = test.wait_for("Dependency graph: bsv-search.2 (radius 2)");
+    assert!(pane.contains("║ bsv-search.2 Rank"), "got: {}", pane);
+    assert!(pane.contains("│ bsv-search.1 Tokenize"), "got: {}", pane);
+    assert!(pane.contains("│ bsv-search.4 Highlight"), "got: {}", pane);
+    let tokenize = pane.lines().position(|l| l.contains("bsv-search.1 Tokenize")).unwrap();
+    let rank = pane.lines().position(|l| l.contains("bsv-search.2 Rank")).unwrap();
+    assert!(tokenize < rank, "blocker should be drawn above: {}", pane);
+
+    // k follows the edge up to the blocker, and the radius can shrink
+    test.send_keys("k");
+    test.wait_for("Dependency graph: bsv-search.1 (radius 2)");
+    test.send_keys("-");
+    let pane = test.wait_for("Dependency graph: bsv-search.1 (radius 1)");
+    assert!(!pane.contains("bsv-search.4 Highlight"), "got: {}", pane);
+
+    // Enter selects the centre issue in the tree
+    test.send_keys("Enter");
+    let pane = test.wait_for("Title: Tokenize queries");
+    assert!(!pane.contains("Dependency graph"), "got: {}", pane);
+}
+
+#[test]
+fn test_dep_reference_rows() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // Make bsv-docs wait on bsv-crash as well as bsv-search
+    let path = test.dir.join("issues.json");
+    let mut issues: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let docs = issues.as_array_mut().unwrap().iter_mut()
+        .find(|i| i["id"] == "bsv-docs")
+        .unwrap();
+    docs["dependencies"].as_array_mut().unwrap().push(serde_json::json!(
+        { "id": "bsv-crash", "title": "Crash on empty input", "dependency_type": "blocks" }
+    ));
+    std::fs::write(&path, serde_json::to_string_pretty(&issues).unwrap()).unwrap();
+    test.send_keys("r");
+    test.send_keys("d");
+    test.wait_for("Issues (Deps)");
+    test.send_keys("Tab");
+
+    // Without reference rows bsv-docs is shown once
+    let count = |rows: &[String]| rows.iter().filter(|r| r.contains("bsv-docs")).count();
+    assert_eq!(count(&test.tree_rows()), 1);
+
+    test.send_keys("m");
+    test.wait_for("↪ bsv-docs");
+    let rows = test.tree_rows();
+    assert_eq!(count(&rows), 2, "got: {:?}", rows);
+    let primary = rows.iter().position(|r| r.contains("bsv-docs") && !r.contains('↪')).unwrap();
+    let reference = rows.iter().position(|r| r.contains("↪ bsv-docs")).unwrap();
+
+    // Enter on the reference row moves to the first occurrence: the next row down
+    // is then the one after the primary
+    let id_of = |row: &str| row.split_whitespace().find(|w| w.starts_with("bsv-")).unwrap().to_string();
+    let after_primary = id_of(&rows[primary + 1]);
+    test.send_keys("g");
+    for _ in 0..reference {
+        test.send_keys("j");
+    }
+    test.wait_for("ID: bsv-docs");
+    test.send_keys("Enter");
+    test.send_keys("j");
+    let pane = test.wait_for(&format!("ID: {}", after_primary));
+    assert!(pane.contains(&format!("ID: {}", after_primary)), "got: {}", pane);
+
+    // The setting is remembered
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("\"show_dep_refs\": true"), "got: {}", state);
+}
+
+#[test]
+fn test_dependency_cycle_reported() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // Close the loop bsv-search.4 → .2 → .1 behind bsv's back, then refresh
+    let path = test.dir.join("issues.json");
+    let mut issues: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let tokenize = issues.as_array_mut().unwrap().iter_mut()
+        .find(|i| i["id"] == "bsv-search.1")
+        .unwrap();
+    tokenize["dependencies"] = serde_json::json!([
+        { "id": "bsv-search.4", "title": "Highlight matches", "dependency_type": "blocks" }
+    ]);
+    std::fs::write(&path, serde_json::to_string_pretty(&issues).unwrap()).unwrap();
+    test.send_keys("r");
+    test.wait_for("⟳ cycles: 1");
+
+    // The cycle is still shown in the Deps view, from its first member
+    test.send_keys("d");
+    let pane = test.wait_for("Issues (Deps)");
+    assert!(pane.contains("bsv-search.1 Tokenize queries"), "got: {}", pane);
+
+    test.send_keys("C");
+    let pane = test.wait_for("Dependency cycles (1)");
+    assert!(pane.contains("Cycle 1: bsv-search.1, bsv-search.2, bsv-search.4"), "got: {}", pane);
+    assert!(pane.contains("bsv-search.1 → bsv-search.4 (blocks)"), "got: {}", pane);
+    assert!(pane.contains("bsv-search.2 → bsv-search.1 (blocks)"), "got: {}", pane);
+    test.send_keys("j");
+    test.send_keys("Enter");
+    test.wait_for("Title: Rank results");
+}
+
+#[test]
+fn test_ready_queue() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("R");
+    test.wait_for("Issues (Ready)");
+    let rows = test.tree_rows();
+    assert!(rows[0].starts_with("bsv-crash"), "got: {:?}", rows);
+    // Among the P1s, the one that unblocks the most comes first (rows are cut off at the panel edge)
+    assert!(rows[1].starts_with("bsv-search.1 Tokenize queries [unblo"), "got: {:?}", rows);
+    assert!(rows[2].starts_with("bsv-search Search overhaul [unblocks"), "got: {:?}", rows);
+    assert!(rows[3].starts_with("bsv-perf"), "got: {:?}", rows);
+    assert!(!rows.iter().any(|r| r.contains("bsv-search.2")), "blocked issues aren't queued: {:?}", rows);
+
+    // Actions work as in the tree: closing the first issue takes it off the queue
+    test.send_keys("x");
+    test.wait_for("Close reason");
+    test.send_keys("Enter");
+    let start = std::time::Instant::now();
+    while test.tree_rows()[0].starts_with("bsv-crash") && start.elapsed() < Duration::from_secs(5) {
+        sleep(Duration::from_millis(100));
+    }
+    assert!(test.tree_rows()[0].starts_with("bsv-search.1"), "got: {:?}", test.tree_rows());
+
+    test.send_keys("R");
+    test.wait_for("Issues (Epics)");
+}
+
+#[test]
+fn test_activity_timeline() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    // Everything in the fixture happened on one day; give bsv-docs a later update
+    let path = test.dir.join("issues.json");
+    let mut issues: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let docs = issues.as_array_mut().unwrap().iter_mut()
+        .find(|i| i["id"] == "bsv-docs")
+        .unwrap();
+    docs["updated_at"] = serde_json::json!("2025-03-01T10:00:00Z");
+    std::fs::write(&path, serde_json::to_string_pretty(&issues).unwrap()).unwrap();
+    test.send_keys("r");
+    test.wait_for("Title: Crash on empty input");
+
+    // Newest day first; days before yesterday start collapsed
+    test.send_keys("T");
+    test.wait_for("Issues (Timeline)");
+    let rows = test.tree_rows();
+    assert_eq!(rows[0], "▶ Sat 1 Mar 2025 (1)", "got: {:?}", rows);
+    assert!(rows[1].starts_with("▶ Mon 6 Jan 2025 ("), "got: {:?}", rows);
+
+    test.send_keys("Space");
+    test.wait_for("· updated");
+    let rows = test.tree_rows();
+    // Rows are cut off at the panel edge
+    assert!(rows[1].starts_with("bsv-docs Write user guide · upd"), "got: {:?}", rows);
+
+    // The expanded days are remembered
+    let state = std::fs::read_to_string(test.dir.join(".config/bsv/state.json")).unwrap();
+    assert!(state.contains("group:day:2025-03-01"), "got: {}", state);
+
+    test.send_keys("T");
+    test.wait_for("Issues (Epics)");
+}
+
+#[test]
+fn test_stats_dashboard() {
+    let test = match TmuxTest::new() {
+        Some(t) => t,
+        None => {
+            eprintln!("Skipping test: tmux not available");
+            return;
+        }
+    };
+
+    test.wait_for("Title: Crash on empty input");
+    test.send_keys("#");
+    let pane = test.wait_for("Project statistics");
+    assert!(pane.contains("31 issues, 29 open"), "got: {}", pane);
+    assert!(pane.contains("2 epics, 0 complete"), "got: {}", pane);
+    assert!(pane.contains("bsv-search Search overhaul"), "got: {}", pane);
+    assert!(pane.contains("No description (0)"), "got: {}", pane);
+
+    // Keys don't reach the tree while it's open
+    test.send_keys("j");
+    test.send_keys("Escape");
+    let pane = test.capture_pane();
+    assert!(!pane.contains("Project statistics"), "got: {}", pane);
+    assert!(pane.contains("Title: Crash on empty input"), "got: {}", pane);
+}
@@ -0,0 +1,228 @@
+//! Fake `bd` executable for integration tests.
+//!
+//! Serves issues from a JSON fixture (an array in `bd show --json` format) and
+//! records every invocation, so tests don't depend on a real beads database.
+//! Point bsv at it with `BSV_BD` or `--bd` (`BSV_BD_PATH` was folded into `BSV_BD`).
+//!
+//! Environment:
+//! - `FAKE_BD_FIXTURE`: path to the fixture file, relative to bd's working directory. Updates are written back to it,
+//!   so tests should pass a copy.
+//! - `FAKE_BD_LOG`: optional file; each invocation is appended as a JSON array of args.
+//! - `FAKE_BD_FAIL`: optional subcommand name (e.g. `list`, `update`) that should fail.
+//! - `FAKE_BD_HANG`: optional subcommand name that should hang for a minute before answering.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Ok(log_path) = std::env::var("FAKE_BD_LOG") {
+        if let Ok(mut log) = fs::OpenOptions::new().create(true).append(true).open(log_path) {
+            let _ = writeln!(log, "{}", json!(args));
+        }
+    }
+
+    let command = args.first().map(|s| s.as_str()).unwrap_or("");
+    if std::env::var("FAKE_BD_FAIL").is_ok_and(|c| c == command) {
+        eprintln!("Error: fake bd {} failure", command);
+        return ExitCode::FAILURE;
+    }
+
+    if std::env::var("FAKE_BD_HANG").is_ok_and(|c| c == command) {
+        std::thread::sleep(std::time::Duration::from_secs(60));
+    }
+
+    let fixture = std::env::var("FAKE_BD_FIXTURE").expect("FAKE_BD_FIXTURE must be set");
+    let mut issues: Vec<Value> = serde_json::from_str(
+        &fs::read_to_string(&fixture).expect("failed to read fixture"),
+    )
+    .expect("fixture must be a JSON array");
+
+    let before = issues.clone();
+    match run(command, &args[1..], &mut issues, &fixture) {
+        Ok(output) => {
+            if issues != before {
+                let json = serde_json::to_string_pretty(&issues).unwrap();
+                fs::write(&fixture, json).expect("failed to write fixture");
+            }
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a command, returning its output. Changes to `issues` are saved afterwards.
+fn run(command: &str, args: &[String], issues: &mut Vec<Value>, fixture: &str) -> Result<String, String> {
+    let positional: Vec<&str> = args.iter()
+        .map(|s| s.as_str())
+        .filter(|s| !s.starts_with("--"))
+        .collect();
+
+    match command {
+        "list" => Ok(json!(issues).to_string()),
+        "show" => {
+            // Skip values of flags like --limit
+            let ids: Vec<&str> = positional.iter().copied().filter(|s| s.parse::<u32>().is_err()).collect();
+            let found: Vec<&Value> = issues.iter().filter(|i| ids.contains(&id_of(i))).collect();
+            if found.is_empty() {
+                return Err(format!("no issue found matching {:?}", ids));
+            }
+            Ok(json!(found).to_string())
+        }
+        "ready" => Ok(json!(ready_issues(issues)).to_string()),
+        "info" => Ok(json!({ "database_path": fixture }).to_string()),
+        "update" => {
+            let id = positional.first().ok_or("update requires an issue ID")?;
+            let issue = find_mut(issues, id)?;
+            for pair in args.windows(2) {
+                let field = match pair[0].as_str() {
+                    "--title" => "title",
+                    "--description" => "description",
+                    "--notes" => "notes",
+                    "--design" => "design",
+                    "--acceptance" => "acceptance_criteria",
+                    "--status" => "status",
+                    "--type" => "issue_type",
+                    "--priority" => {
+                        let priority: i32 = pair[1].parse().map_err(|_| "invalid priority")?;
+                        issue["priority"] = json!(priority);
+                        continue;
+                    }
+                    _ => continue,
+                };
+                issue[field] = json!(pair[1]);
+            }
+            Ok(String::new())
+        }
+        "label" => {
+            let (action, id, label) = match positional.as_slice() {
+                [action, id, label] => (*action, *id, *label),
+                _ => return Err("usage: label add|remove <id> <label>".to_string()),
+            };
+            let issue = find_mut(issues, id)?;
+            let mut labels: Vec<Value> = issue["labels"].as_array().cloned().unwrap_or_default();
+            match action {
+                "add" if !labels.contains(&json!(label)) => labels.push(json!(label)),
+                "add" => {}
+                "remove" => labels.retain(|l| l != label),
+                _ => return Err(format!("unknown label action {:?}", action)),
+            }
+            issue["labels"] = json!(labels);
+            Ok(String::new())
+        }
+        "dep" => {
+            let (action, id, target) = match positional.as_slice() {
+                [action, id, target, ..] => (*action, *id, *target),
+                _ => return Err("usage: dep add|remove <id> <depends-on>".to_string()),
+            };
+            let dep_type = args.windows(2).find(|p| p[0] == "--type").map(|p| p[1].clone())
+                .unwrap_or_else(|| "blocks".to_string());
+            let edge = |issue: &Value| json!({
+                "id": id_of(issue),
+                "title": issue["title"],
+                "dependency_type": dep_type,
+            });
+            let source = edge(find_mut(issues, id)?);
+            let dest = edge(find_mut(issues, target)?);
+            // Keep both sides of the edge in sync, like `bd show`
+            let mut edit = |issue_id: &str, field: &str, other: &str, entry: &Value| -> Result<(), String> {
+                let issue = find_mut(issues, issue_id)?;
+                let mut list: Vec<Value> = issue[field].as_array().cloned().unwrap_or_default();
+                list.retain(|d| id_of(d) != other);
+                if action == "add" {
+                    list.push(entry.clone());
+                }
+                issue[field] = json!(list);
+                Ok(())
+            };
+            match action {
+                "add" | "remove" => {
+                    edit(id, "dependencies", target, &dest)?;
+                    edit(target, "dependents", id, &source)?;
+                    Ok(String::new())
+                }
+                _ => Err(format!("unknown dep action {:?}", action)),
+            }
+        }
+        "close" => {
+            let id = positional.first().ok_or("close requires an issue ID")?;
+            let reason = args.windows(2).find(|p| p[0] == "--reason").map(|p| p[1].clone());
+            let issue = find_mut(issues, id)?;
+            issue["status"] = json!("closed");
+            if let Some(reason) = reason {
+                issue["close_reason"] = json!(reason);
+            }
+            Ok(String::new())
+        }
+        "create" => {
+            let flag = |name: &str| args.windows(2).find(|p| p[0] == name).map(|p| p[1].clone());
+            // Like bd, a positional title starting with a dash would be read as a flag
+            let title = flag("--title").ok_or("create requires --title")?;
+            // Children get the next dotted ID under their parent, like bd
+            let id = match flag("--parent") {
+                Some(parent) => {
+                    find_mut(issues, &parent)?;
+                    let prefix = format!("{}.", parent);
+                    let last = issues.iter()
+                        .filter_map(|i| id_of(i).strip_prefix(&prefix)?.parse::<u32>().ok())
+                        .max()
+                        .unwrap_or(0);
+                    format!("{}{}", prefix, last + 1)
+                }
+                None => format!("bsv-new{}", issues.len() + 1),
+            };
+            let labels: Vec<String> = flag("--labels")
+                .map(|l| l.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default();
+            let issue = json!({
+                "id": id,
+                "title": title,
+                "description": flag("--description").unwrap_or_default(),
+                "status": "open",
+                "priority": flag("--priority").and_then(|p| p.parse::<i32>().ok()).unwrap_or(2),
+                "issue_type": flag("--type").unwrap_or_else(|| "task".to_string()),
+                "created_at": "2025-01-06T09:00:00Z",
+                "updated_at": "2025-01-06T09:00:00Z",
+                "labels": labels,
+            });
+            issues.push(issue.clone());
+            Ok(issue.to_string())
+        }
+        _ => Err(format!("unknown command {:?}", command)),
+    }
+}
+
+fn id_of(issue: &Value) -> &str {
+    issue["id"].as_str().unwrap_or("")
+}
+
+fn find_mut<'a>(issues: &'a mut [Value], id: &str) -> Result<&'a mut Value, String> {
+    issues.iter_mut()
+        .find(|i| id_of(i) == id)
+        .ok_or_else(|| format!("no issue found matching {:?}", id))
+}
+
+/// Open issues whose `blocks` dependencies are all closed
+fn ready_issues(issues: &[Value]) -> Vec<&Value> {
+    let status: HashMap<&str, &str> = issues.iter()
+        .map(|i| (id_of(i), i["status"].as_str().unwrap_or("")))
+        .collect();
+    issues.iter()
+        .filter(|i| i["status"] != "closed")
+        .filter(|i| {
+            i["dependencies"].as_array().into_iter().flatten()
+                .filter(|d| d["dependency_type"] == "blocks")
+                .all(|d| status.get(id_of(d)).is_none_or(|s| *s == "closed"))
+        })
+        .collect()
+}